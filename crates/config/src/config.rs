@@ -8,6 +8,7 @@ use serde::{Deserialize, Deserializer, Serialize};
 use std::{
     ffi::OsStr,
     fs,
+    net::IpAddr,
     path::{Path, PathBuf},
     time::Duration,
 };
@@ -31,6 +32,8 @@ pub struct Config {
     pub peers: PeersConfig,
     /// Configuration for peer sessions.
     pub sessions: SessionsConfig,
+    /// Configuration for the RPC servers.
+    pub rpc: RpcConfig,
 }
 
 impl Config {
@@ -431,6 +434,82 @@ impl PruneConfig {
     }
 }
 
+/// RPC server configuration.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
+pub struct RpcConfig {
+    /// Rate limits of the RPC methods.
+    pub rate_limits: RpcRateLimitConfig,
+}
+
+/// Rate limits of the RPC methods of the http and ws servers.
+///
+/// ```toml
+/// [rpc.rate_limits.global]
+/// rate = 1000
+/// burst = 2000
+///
+/// [[rpc.rate_limits.methods]]
+/// method = "debug_trace*"
+/// rate = 2
+/// burst = 5
+/// ```
+///
+/// The limits of each client apply to the address of its connection. If the server is behind a
+/// reverse proxy, the proxy has to be listed in `trusted_proxies` so the client is identified by
+/// the `X-Forwarded-For` or `X-Real-IP` header of the proxy instead.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
+pub struct RpcRateLimitConfig {
+    /// Limit of all calls, over all clients and methods.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub global: Option<RateLimit>,
+    /// Limits of the calls of each client to the matching methods.
+    ///
+    /// If multiple entries match a method, the first one applies.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub methods: Vec<MethodRateLimit>,
+    /// Addresses of the reverse proxies whose forwarding headers identify the client.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub trusted_proxies: Vec<IpAddr>,
+}
+
+impl RpcRateLimitConfig {
+    /// Returns `true` if no limits are configured.
+    pub fn is_empty(&self) -> bool {
+        self.global.is_none() && self.methods.is_empty()
+    }
+}
+
+/// A token bucket rate limit.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Serialize)]
+pub struct RateLimit {
+    /// Sustained number of calls per second.
+    pub rate: u32,
+    /// Maximum number of calls that can be made at once after a period of inactivity.
+    pub burst: u32,
+}
+
+/// A rate limit of the calls to the matching RPC methods.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq, Serialize)]
+pub struct MethodRateLimit {
+    /// The method name, or a prefix of method names followed by `*`, e.g. `debug_*`.
+    pub method: String,
+    /// The limit of each client.
+    #[serde(flatten)]
+    pub limit: RateLimit,
+}
+
+impl MethodRateLimit {
+    /// Returns `true` if this limit applies to the given method.
+    pub fn matches(&self, method: &str) -> bool {
+        match self.method.strip_suffix('*') {
+            Some(prefix) => method.starts_with(prefix),
+            None => method == self.method,
+        }
+    }
+}
+
 /// Helper type to support older versions of Duration deserialization.
 fn deserialize_duration<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
 where
@@ -451,7 +530,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::{Config, EXTENSION};
+    use super::{Config, RateLimit, EXTENSION};
     use crate::PruneConfig;
     use alloy_primitives::Address;
     use reth_network_peers::TrustedPeer;
@@ -1019,4 +1098,42 @@ connect_trusted_nodes_only = true
             assert!(conf.peers.trusted_nodes.contains(&node));
         }
     }
+
+    #[test]
+    fn test_rpc_rate_limits() {
+        let reth_toml = r#"
+[rpc.rate_limits]
+trusted_proxies = ["127.0.0.1"]
+
+[rpc.rate_limits.global]
+rate = 1000
+burst = 2000
+
+[[rpc.rate_limits.methods]]
+method = "eth_getLogs"
+rate = 10
+burst = 20
+
+[[rpc.rate_limits.methods]]
+method = "debug_trace*"
+rate = 2
+burst = 5
+"#;
+
+        let conf: Config = toml::from_str(reth_toml).unwrap();
+        let limits = &conf.rpc.rate_limits;
+        assert_eq!(limits.global, Some(RateLimit { rate: 1000, burst: 2000 }));
+        assert_eq!(limits.methods.len(), 2);
+        assert_eq!(limits.methods[0].limit, RateLimit { rate: 10, burst: 20 });
+        assert!(limits.methods[0].matches("eth_getLogs"));
+        assert!(!limits.methods[0].matches("eth_getLogsV2"));
+        assert!(limits.methods[1].matches("debug_traceTransaction"));
+        assert!(!limits.methods[1].matches("debug_getRawBlock"));
+        assert_eq!(limits.trusted_proxies, [std::net::Ipv4Addr::LOCALHOST]);
+
+        // round trips through the config file
+        let conf: Config = toml::from_str(&toml::to_string(&conf).unwrap()).unwrap();
+        assert_eq!(&conf.rpc.rate_limits, limits);
+        assert!(Config::default().rpc.rate_limits.is_empty());
+    }
 }
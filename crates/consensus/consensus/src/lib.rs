@@ -12,11 +12,11 @@
 extern crate alloc;
 
 use alloc::{fmt::Debug, vec::Vec};
-use alloy_eips::eip7685::Requests;
+use alloy_eips::{eip4895::Withdrawal, eip7685::Requests};
 use alloy_primitives::{BlockHash, BlockNumber, Bloom, B256, U256};
 use reth_primitives::{
     constants::MINIMUM_GAS_LIMIT, BlockWithSenders, GotExpected, GotExpectedBoxed, Header,
-    InvalidTransactionError, Receipt, SealedBlock, SealedHeader,
+    InvalidTransactionError, Receipt, SealedBlock, SealedHeader, TransactionSigned,
 };
 
 /// A consensus implementation that does nothing.
@@ -26,6 +26,12 @@ pub mod noop;
 /// test helpers for mocking consensus
 pub mod test_utils;
 
+/// The maximum number of validators, and therefore the upper bound of a withdrawal's validator
+/// index.
+///
+/// This is `VALIDATOR_REGISTRY_LIMIT` of the consensus specs.
+pub const VALIDATOR_REGISTRY_LIMIT: u64 = 1 << 40;
+
 /// Post execution input passed to [`Consensus::validate_block_post_execution`].
 #[derive(Debug)]
 pub struct PostExecutionInput<'a> {
@@ -109,6 +115,76 @@ pub trait Consensus: Debug + Send + Sync {
     /// Note: validating blocks does not include other validations of the Consensus
     fn validate_block_pre_execution(&self, block: &SealedBlock) -> Result<(), ConsensusError>;
 
+    /// Validates the aggregate [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844) blob gas usage
+    /// of the block's transactions against the header.
+    ///
+    /// This does nothing by default, consensus engines with blob transactions should check that
+    /// the blob gas used by all transactions matches the header's `blob_gas_used` and stays within
+    /// the per-block maximum.
+    ///
+    /// The header's `excess_blob_gas` depends on the parent, so it is checked in
+    /// [`Consensus::validate_header_against_parent`] instead.
+    ///
+    /// **This should only be called for blocks after the Cancun hardfork**.
+    fn validate_blob_gas_usage(
+        &self,
+        _header: &SealedHeader,
+        _transactions: &[TransactionSigned],
+    ) -> Result<(), ConsensusError> {
+        Ok(())
+    }
+
+    /// Validates the [EIP-4895](https://eips.ethereum.org/EIPS/eip-4895) withdrawals of the block.
+    ///
+    /// This checks that the header's `withdrawals_root` matches the withdrawals, that the total
+    /// withdrawn amount in gwei doesn't overflow, that every validator index is below the
+    /// [`VALIDATOR_REGISTRY_LIMIT`], and that the withdrawal indices are consecutive. If the index
+    /// of the last withdrawal before this block is known, the first withdrawal must follow it.
+    ///
+    /// **This should only be called for blocks after the Shanghai hardfork**.
+    fn validate_withdrawals(
+        &self,
+        header: &SealedHeader,
+        withdrawals: &[Withdrawal],
+        last_withdrawal_index: Option<u64>,
+    ) -> Result<(), ConsensusError> {
+        let withdrawals_root = reth_primitives::proofs::calculate_withdrawals_root(withdrawals);
+        let header_withdrawals_root =
+            header.withdrawals_root.ok_or(ConsensusError::WithdrawalsRootMissing)?;
+        if withdrawals_root != header_withdrawals_root {
+            return Err(ConsensusError::BodyWithdrawalsRootDiff(
+                GotExpected { got: withdrawals_root, expected: header_withdrawals_root }.into(),
+            ))
+        }
+
+        withdrawals
+            .iter()
+            .try_fold(0u64, |total, withdrawal| total.checked_add(withdrawal.amount))
+            .ok_or(ConsensusError::WithdrawalAmountOverflow)?;
+
+        let mut expected_index = last_withdrawal_index.map(|index| index.wrapping_add(1));
+        for withdrawal in withdrawals {
+            if let Some(expected) = expected_index {
+                if withdrawal.index != expected {
+                    return Err(ConsensusError::WithdrawalIndexDiff(GotExpected {
+                        got: withdrawal.index,
+                        expected,
+                    }))
+                }
+            }
+            expected_index = Some(withdrawal.index.wrapping_add(1));
+
+            if withdrawal.validator_index >= VALIDATOR_REGISTRY_LIMIT {
+                return Err(ConsensusError::WithdrawalValidatorIndexOutOfRange {
+                    validator_index: withdrawal.validator_index,
+                    limit: VALIDATOR_REGISTRY_LIMIT,
+                })
+            }
+        }
+
+        Ok(())
+    }
+
     /// Validate a block considering world state, i.e. things that can not be checked before
     /// execution.
     ///
@@ -265,6 +341,24 @@ pub enum ConsensusError {
     #[display("missing withdrawals")]
     BodyWithdrawalsMissing,
 
+    /// Error when the validator index of a withdrawal is not below the
+    /// [`VALIDATOR_REGISTRY_LIMIT`].
+    #[display("withdrawal validator index {validator_index} exceeds the registry limit {limit}")]
+    WithdrawalValidatorIndexOutOfRange {
+        /// The validator index of the withdrawal.
+        validator_index: u64,
+        /// The maximum number of validators.
+        limit: u64,
+    },
+
+    /// Error when the total amount of the withdrawals in a block overflows.
+    #[display("total withdrawal amount overflows")]
+    WithdrawalAmountOverflow,
+
+    /// Error when the index of a withdrawal doesn't follow the previous withdrawal.
+    #[display("unexpected withdrawal index: {_0}")]
+    WithdrawalIndexDiff(GotExpected<u64>),
+
     /// Error when requests are missing.
     #[display("missing requests")]
     BodyRequestsMissing,
@@ -408,3 +502,97 @@ impl From<InvalidTransactionError> for ConsensusError {
 #[derive(derive_more::Display, derive_more::Error, Debug)]
 #[display("Consensus error: {_0}, Invalid header: {_1:?}")]
 pub struct HeaderConsensusError(ConsensusError, SealedHeader);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::noop::NoopConsensus;
+    use reth_primitives::proofs;
+
+    fn header_with_withdrawals(withdrawals: &[Withdrawal]) -> SealedHeader {
+        let header = Header {
+            withdrawals_root: Some(proofs::calculate_withdrawals_root(withdrawals)),
+            ..Default::default()
+        };
+        SealedHeader::new(header, B256::ZERO)
+    }
+
+    #[test]
+    fn test_validate_withdrawals() {
+        // the noop consensus uses the default implementation
+        let consensus = NoopConsensus;
+        let withdrawals_with_indices = |indices: &[u64]| {
+            indices
+                .iter()
+                .map(|index| Withdrawal { index: *index, amount: 1, ..Default::default() })
+                .collect::<Vec<_>>()
+        };
+
+        let withdrawals = withdrawals_with_indices(&[3, 4, 5]);
+        let header = header_with_withdrawals(&withdrawals);
+        assert_eq!(consensus.validate_withdrawals(&header, &withdrawals, Some(2)), Ok(()));
+        assert_eq!(consensus.validate_withdrawals(&header, &withdrawals, None), Ok(()));
+        assert_eq!(
+            consensus.validate_withdrawals(&header_with_withdrawals(&[]), &[], Some(2)),
+            Ok(())
+        );
+
+        // the first index doesn't follow the last withdrawal of the parent
+        assert_eq!(
+            consensus.validate_withdrawals(&header, &withdrawals, Some(3)),
+            Err(ConsensusError::WithdrawalIndexDiff(GotExpected { got: 3, expected: 4 }))
+        );
+
+        // duplicate and unordered indices
+        for (indices, got, expected) in [([3u64, 3], 3, 4), ([3, 5], 5, 4), ([4, 3], 3, 5)] {
+            let withdrawals = withdrawals_with_indices(&indices);
+            let header = header_with_withdrawals(&withdrawals);
+            assert_eq!(
+                consensus.validate_withdrawals(&header, &withdrawals, None),
+                Err(ConsensusError::WithdrawalIndexDiff(GotExpected { got, expected }))
+            );
+        }
+
+        // the header's withdrawals root doesn't match
+        assert!(matches!(
+            consensus.validate_withdrawals(&header_with_withdrawals(&[]), &withdrawals, Some(2)),
+            Err(ConsensusError::BodyWithdrawalsRootDiff(_))
+        ));
+
+        // the total amount overflows
+        let mut withdrawals = withdrawals_with_indices(&[0, 1]);
+        withdrawals[0].amount = u64::MAX;
+        assert_eq!(
+            consensus.validate_withdrawals(
+                &header_with_withdrawals(&withdrawals),
+                &withdrawals,
+                None
+            ),
+            Err(ConsensusError::WithdrawalAmountOverflow)
+        );
+
+        // the validator index is out of range
+        let mut withdrawals = withdrawals_with_indices(&[0]);
+        withdrawals[0].validator_index = VALIDATOR_REGISTRY_LIMIT;
+        assert_eq!(
+            consensus.validate_withdrawals(
+                &header_with_withdrawals(&withdrawals),
+                &withdrawals,
+                None
+            ),
+            Err(ConsensusError::WithdrawalValidatorIndexOutOfRange {
+                validator_index: VALIDATOR_REGISTRY_LIMIT,
+                limit: VALIDATOR_REGISTRY_LIMIT
+            })
+        );
+        withdrawals[0].validator_index = VALIDATOR_REGISTRY_LIMIT - 1;
+        assert_eq!(
+            consensus.validate_withdrawals(
+                &header_with_withdrawals(&withdrawals),
+                &withdrawals,
+                None
+            ),
+            Ok(())
+        );
+    }
+}
@@ -16,15 +16,15 @@ use std::{
 
 use crate::miner::{LocalMiner, MiningMode};
 use futures_util::{Stream, StreamExt};
-use reth_beacon_consensus::{BeaconConsensusEngineEvent, BeaconEngineMessage, EngineNodeTypes};
+use reth_beacon_consensus::{BeaconEngineMessage, EngineNodeTypes};
 use reth_chainspec::EthChainSpec;
 use reth_consensus::Consensus;
 use reth_engine_service::service::EngineMessageStream;
 use reth_engine_tree::{
     chain::{ChainEvent, HandlerEvent},
     engine::{
-        EngineApiKind, EngineApiRequest, EngineApiRequestHandler, EngineRequestHandler, FromEngine,
-        RequestHandlerEvent,
+        EngineApiKind, EngineApiRequest, EngineApiRequestHandler, EngineApiRequestHandlerEvent,
+        EngineRequestHandler, FromEngine, RequestHandlerEvent,
     },
     persistence::PersistenceHandle,
     tree::{EngineApiTreeHandler, InvalidBlockHook, TreeConfig},
@@ -121,7 +121,7 @@ impl<N> Stream for LocalEngineService<N>
 where
     N: EngineNodeTypes,
 {
-    type Item = ChainEvent<BeaconConsensusEngineEvent>;
+    type Item = ChainEvent<EngineApiRequestHandlerEvent>;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let this = self.get_mut();
@@ -135,11 +135,16 @@ where
                     }
                     HandlerEvent::Event(ev) => Poll::Ready(Some(ChainEvent::Handler(ev))),
                     HandlerEvent::FatalError => Poll::Ready(Some(ChainEvent::FatalError)),
+                    HandlerEvent::Shutdown => Poll::Ready(Some(ChainEvent::Shutdown)),
                 },
                 RequestHandlerEvent::Download(_) => {
                     error!(target: "engine::local", "received download request in local engine");
                     Poll::Ready(Some(ChainEvent::FatalError))
                 }
+                RequestHandlerEvent::Error(err) => {
+                    error!(target: "engine::local", %err, "engine request handler failed");
+                    Poll::Ready(Some(ChainEvent::FatalError))
+                }
             }
         }
 
@@ -61,6 +61,14 @@ where
         &mut self.handler
     }
 
+    /// Initiates the graceful shutdown of the handler.
+    ///
+    /// The handler finishes the requests that it already accepted and emits
+    /// [`ChainEvent::Shutdown`] once it's done.
+    pub fn shutdown(&mut self) {
+        self.handler.on_event(FromOrchestrator::Shutdown);
+    }
+
     /// Triggers a backfill sync for the __valid__ given target.
     ///
     /// CAUTION: This function should be used with care and with a valid target.
@@ -127,6 +135,10 @@ where
                             error!(target: "engine::tree", "Fatal error");
                             return Poll::Ready(ChainEvent::FatalError)
                         }
+                        HandlerEvent::Shutdown => {
+                            debug!(target: "engine::tree", "Handler shut down");
+                            return Poll::Ready(ChainEvent::Shutdown)
+                        }
                     }
                 }
                 Poll::Pending => {
@@ -163,6 +175,8 @@ pub enum ChainEvent<T> {
     BackfillSyncFinished,
     /// Fatal error
     FatalError,
+    /// The handler finished its graceful shutdown
+    Shutdown,
     /// Event emitted by the handler
     Handler(T),
 }
@@ -179,6 +193,9 @@ impl<T: Display> Display for ChainEvent<T> {
             Self::FatalError => {
                 write!(f, "FatalError")
             }
+            Self::Shutdown => {
+                write!(f, "Shutdown")
+            }
             Self::Handler(event) => {
                 write!(f, "Handler({event})")
             }
@@ -217,6 +234,9 @@ pub enum HandlerEvent<T> {
     Event(T),
     /// Fatal error
     FatalError,
+    /// The handler finished its graceful shutdown, all requests it accepted before the shutdown
+    /// was initiated are processed.
+    Shutdown,
 }
 
 /// Internal events issued by the [`ChainOrchestrator`].
@@ -226,4 +246,7 @@ pub enum FromOrchestrator {
     BackfillSyncFinished(ControlFlow),
     /// Invoked when backfill sync started
     BackfillSyncStarted,
+    /// Invoked when the node shuts down, the handler is expected to finish the requests it
+    /// already accepted and emit [`HandlerEvent::Shutdown`]
+    Shutdown,
 }
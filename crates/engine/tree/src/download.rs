@@ -1,21 +1,38 @@
 //! Handler that can download blocks on demand (e.g. from the network).
 
-use crate::{engine::DownloadRequest, metrics::BlockDownloaderMetrics};
+use crate::{
+    engine::{DownloadDirection, DownloadPriority, DownloadRequest},
+    metrics::BlockDownloaderMetrics,
+};
+use alloy_eips::BlockNumHash;
 use alloy_primitives::B256;
 use futures::FutureExt;
 use reth_consensus::Consensus;
 use reth_network_p2p::{
     full_block::{FetchFullBlockFuture, FetchFullBlockRangeFuture, FullBlockClient},
+    priority::Priority,
     BlockClient,
 };
-use reth_primitives::{SealedBlock, SealedBlockWithSenders};
+use reth_primitives::{SealedBlock, SealedBlockWithSenders, SealedHeader};
 use std::{
     cmp::{Ordering, Reverse},
     collections::{binary_heap::PeekMut, BinaryHeap, HashSet, VecDeque},
     sync::Arc,
     task::{Context, Poll},
+    time::{Duration, Instant},
 };
-use tracing::trace;
+use tracing::{trace, warn};
+
+/// The default number of consecutive failed downloads after which the
+/// [`DownloadCircuitBreaker`] opens.
+pub const DEFAULT_DOWNLOAD_FAILURE_THRESHOLD: usize = 10;
+
+/// The default window in which consecutive failed downloads are counted by the
+/// [`DownloadCircuitBreaker`].
+pub const DEFAULT_DOWNLOAD_FAILURE_WINDOW: Duration = Duration::from_secs(60);
+
+/// The default duration for which the [`DownloadCircuitBreaker`] stays open.
+pub const DEFAULT_DOWNLOAD_BACKOFF: Duration = Duration::from_secs(30);
 
 /// A trait that can download blocks on demand.
 pub trait BlockDownloader: Send + Sync {
@@ -47,6 +64,42 @@ pub enum DownloadOutcome {
         /// The hash of the highest block of this download.
         target: B256,
     },
+    /// Downloading blocks failed.
+    Error(DownloadError),
+}
+
+/// Error of a block download.
+#[derive(Debug, thiserror::Error)]
+pub enum DownloadError {
+    /// The senders of a downloaded block could not be recovered, the block was discarded.
+    #[error("failed to recover senders of downloaded block {block:?}")]
+    SenderRecovery {
+        /// The number and hash of the block.
+        block: BlockNumHash,
+    },
+    /// A request of a download failed, e.g. it timed out, or returned an invalid response. The
+    /// request is retried.
+    #[error("request for block {hash} failed")]
+    RequestFailed {
+        /// The hash of the block, or the start of the block range, that is downloaded.
+        hash: B256,
+    },
+}
+
+impl DownloadError {
+    /// Returns the hash of the block, or the start of the block range, that failed to download.
+    pub const fn hash(&self) -> B256 {
+        match self {
+            Self::SenderRecovery { block } => block.hash,
+            Self::RequestFailed { hash } => *hash,
+        }
+    }
+
+    /// Returns `true` if the download is still in progress, because the failed request is
+    /// retried.
+    pub const fn is_retried(&self) -> bool {
+        matches!(self, Self::RequestFailed { .. })
+    }
 }
 
 /// Basic [`BlockDownloader`].
@@ -95,33 +148,104 @@ where
     }
 
     /// Processes a download request.
+    ///
+    /// Urgent requests are sent to the network with [`Priority::High`], so they are queued before
+    /// background requests.
     fn download(&mut self, request: DownloadRequest) {
         match request {
-            DownloadRequest::BlockSet(hashes) => self.download_block_set(hashes),
-            DownloadRequest::BlockRange(hash, count) => self.download_block_range(hash, count),
+            DownloadRequest::BlockSet(hashes, priority) => {
+                self.download_block_set(hashes, priority)
+            }
+            DownloadRequest::BlockRange(hash, count, direction, priority) => {
+                self.download_block_range(hash, count, direction, priority)
+            }
+            DownloadRequest::Bodies(headers, priority) => self.download_bodies(headers, priority),
         }
     }
 
+    /// Processes a bodies download request for blocks with known headers.
+    ///
+    /// Headers of blocks that are already requested are skipped.
+    fn download_bodies(&mut self, mut headers: Vec<SealedHeader>, priority: DownloadPriority) {
+        headers.retain(|header| {
+            !self.is_inflight_request(header.hash(), priority) &&
+                !self.is_inflight_range_request(
+                    header.hash(),
+                    1,
+                    DownloadDirection::Descending,
+                    priority,
+                )
+        });
+        if headers.is_empty() {
+            return
+        }
+
+        trace!(
+            target: "consensus::engine",
+            count = headers.len(),
+            "start downloading block bodies"
+        );
+
+        let request = self.full_block_client.get_block_bodies_for_headers(headers, priority.into());
+        self.push_pending_event(DownloadOutcome::NewDownloadStarted {
+            remaining_blocks: request.count(),
+            target: request.start_hash(),
+        });
+        self.inflight_block_range_requests.push(request);
+        self.update_block_download_metrics();
+    }
+
     /// Processes a block set download request.
-    fn download_block_set(&mut self, hashes: HashSet<B256>) {
+    fn download_block_set(&mut self, hashes: HashSet<B256>, priority: DownloadPriority) {
         for hash in hashes {
-            self.download_full_block(hash);
+            self.download_full_block(hash, priority);
         }
     }
 
     /// Processes a block range download request.
-    fn download_block_range(&mut self, hash: B256, count: u64) {
+    ///
+    /// Requests that are already covered by an in-flight range request are dropped. Covered
+    /// background requests are cancelled and restarted if the new request is urgent.
+    fn download_block_range(
+        &mut self,
+        hash: B256,
+        count: u64,
+        direction: DownloadDirection,
+        priority: DownloadPriority,
+    ) {
         if count == 1 {
-            self.download_full_block(hash);
+            self.download_full_block(hash, priority);
+        } else if self.is_inflight_range_request(hash, count, direction, priority) {
+            trace!(
+                target: "consensus::engine",
+                ?hash,
+                ?count,
+                ?direction,
+                "skipping full block range download, already in flight"
+            );
         } else {
             trace!(
                 target: "consensus::engine",
                 ?hash,
                 ?count,
+                ?direction,
                 "start downloading full block range."
             );
 
-            let request = self.full_block_client.get_full_block_range(hash, count);
+            // cancel background requests that are covered by this urgent request
+            self.inflight_block_range_requests.retain(|req| {
+                req.start_hash() != hash ||
+                    req.count() > count ||
+                    req.direction() != direction.into() ||
+                    satisfies_priority(req.priority(), priority)
+            });
+
+            let request = self.full_block_client.get_full_block_range_in_direction(
+                hash,
+                count,
+                direction.into(),
+                priority.into(),
+            );
             self.push_pending_event(DownloadOutcome::NewDownloadStarted {
                 remaining_blocks: request.count(),
                 target: request.start_hash(),
@@ -133,11 +257,14 @@ where
     /// Starts requesting a full block from the network.
     ///
     /// Returns `true` if the request was started, `false` if there's already a request for the
-    /// given hash.
-    fn download_full_block(&mut self, hash: B256) -> bool {
-        if self.is_inflight_request(hash) {
+    /// given hash. A background request for the hash is cancelled and restarted if the new request
+    /// is urgent.
+    fn download_full_block(&mut self, hash: B256, priority: DownloadPriority) -> bool {
+        if self.is_inflight_request(hash, priority) {
             return false
         }
+        self.inflight_full_block_requests.retain(|req| *req.hash() != hash);
+
         self.push_pending_event(DownloadOutcome::NewDownloadStarted {
             remaining_blocks: 1,
             target: hash,
@@ -149,7 +276,7 @@ where
             "Start downloading full block"
         );
 
-        let request = self.full_block_client.get_full_block(hash);
+        let request = self.full_block_client.get_full_block_with_priority(hash, priority.into());
         self.inflight_full_block_requests.push(request);
 
         self.update_block_download_metrics();
@@ -157,9 +284,28 @@ where
         true
     }
 
-    /// Returns true if there's already a request for the given hash.
-    fn is_inflight_request(&self, hash: B256) -> bool {
-        self.inflight_full_block_requests.iter().any(|req| *req.hash() == hash)
+    /// Returns true if there's already a request for the given hash with a sufficient priority.
+    fn is_inflight_request(&self, hash: B256, priority: DownloadPriority) -> bool {
+        self.inflight_full_block_requests
+            .iter()
+            .any(|req| *req.hash() == hash && satisfies_priority(req.priority(), priority))
+    }
+
+    /// Returns true if there's already a range request starting at the given hash that covers at
+    /// least `count` blocks in the given direction with a sufficient priority.
+    fn is_inflight_range_request(
+        &self,
+        hash: B256,
+        count: u64,
+        direction: DownloadDirection,
+        priority: DownloadPriority,
+    ) -> bool {
+        self.inflight_block_range_requests.iter().any(|req| {
+            req.start_hash() == hash &&
+                req.count() >= count &&
+                req.direction() == direction.into() &&
+                satisfies_priority(req.priority(), priority)
+        })
     }
 
     /// Sets the metrics for the active downloads
@@ -178,6 +324,33 @@ where
     fn pop_pending_event(&mut self) -> Option<DownloadOutcome> {
         self.pending_events.pop_front()
     }
+
+    /// Queues a [`DownloadError::RequestFailed`] for every failed request of the download of the
+    /// given hash.
+    fn on_failed_requests(&mut self, hash: B256, failed_requests: usize) {
+        for _ in 0..failed_requests {
+            trace!(target: "consensus::engine", ?hash, "Block download request failed, retrying");
+            self.push_pending_event(DownloadOutcome::Error(DownloadError::RequestFailed { hash }));
+        }
+    }
+
+    /// Recovers the senders of a downloaded block and buffers it.
+    ///
+    /// If the senders can't be recovered, the block is discarded and a
+    /// [`DownloadError::SenderRecovery`] is queued.
+    fn buffer_block(&mut self, block: SealedBlock) {
+        match block.senders() {
+            Some(senders) => self.set_buffered_blocks.push(Reverse(OrderedSealedBlockWithSenders(
+                SealedBlockWithSenders { block, senders },
+            ))),
+            None => {
+                trace!(target: "consensus::engine", block=?block.num_hash(), "Failed to recover senders of downloaded block");
+                self.push_pending_event(DownloadOutcome::Error(DownloadError::SenderRecovery {
+                    block: block.num_hash(),
+                }));
+            }
+        }
+    }
 }
 
 impl<Client> BlockDownloader for BasicBlockDownloader<Client>
@@ -201,9 +374,11 @@ where
         // advance all full block requests
         for idx in (0..self.inflight_full_block_requests.len()).rev() {
             let mut request = self.inflight_full_block_requests.swap_remove(idx);
-            if let Poll::Ready(block) = request.poll_unpin(cx) {
+            let poll = request.poll_unpin(cx);
+            self.on_failed_requests(*request.hash(), request.take_failed_requests());
+            if let Poll::Ready(block) = poll {
                 trace!(target: "consensus::engine", block=?block.num_hash(), "Received single full block, buffering");
-                self.set_buffered_blocks.push(Reverse(block.into()));
+                self.buffer_block(block);
             } else {
                 // still pending
                 self.inflight_full_block_requests.push(request);
@@ -213,20 +388,13 @@ where
         // advance all full block range requests
         for idx in (0..self.inflight_block_range_requests.len()).rev() {
             let mut request = self.inflight_block_range_requests.swap_remove(idx);
-            if let Poll::Ready(blocks) = request.poll_unpin(cx) {
+            let poll = request.poll_unpin(cx);
+            self.on_failed_requests(request.start_hash(), request.take_failed_requests());
+            if let Poll::Ready(blocks) = poll {
                 trace!(target: "consensus::engine", len=?blocks.len(), first=?blocks.first().map(|b| b.num_hash()), last=?blocks.last().map(|b| b.num_hash()), "Received full block range, buffering");
-                self.set_buffered_blocks.extend(
-                    blocks
-                        .into_iter()
-                        .map(|b| {
-                            let senders = b.senders().unwrap_or_default();
-                            OrderedSealedBlockWithSenders(SealedBlockWithSenders {
-                                block: b,
-                                senders,
-                            })
-                        })
-                        .map(Reverse),
-                );
+                for block in blocks {
+                    self.buffer_block(block);
+                }
             } else {
                 // still pending
                 self.inflight_block_range_requests.push(request);
@@ -236,7 +404,8 @@ where
         self.update_block_download_metrics();
 
         if self.set_buffered_blocks.is_empty() {
-            return Poll::Pending;
+            // report errors of the finished downloads, if any
+            return self.pop_pending_event().map_or(Poll::Pending, Poll::Ready)
         }
 
         // drain all unique element of the block buffer if there are any
@@ -257,6 +426,12 @@ where
     }
 }
 
+/// Returns true if an in-flight request with the given network [`Priority`] satisfies a request
+/// with the given [`DownloadPriority`].
+const fn satisfies_priority(inflight: Priority, requested: DownloadPriority) -> bool {
+    inflight.is_high() || !requested.is_urgent()
+}
+
 /// A wrapper type around [`SealedBlockWithSenders`] that implements the [Ord]
 /// trait by block number.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -274,13 +449,6 @@ impl Ord for OrderedSealedBlockWithSenders {
     }
 }
 
-impl From<SealedBlock> for OrderedSealedBlockWithSenders {
-    fn from(block: SealedBlock) -> Self {
-        let senders = block.senders().unwrap_or_default();
-        Self(SealedBlockWithSenders { block, senders })
-    }
-}
-
 impl From<OrderedSealedBlockWithSenders> for SealedBlockWithSenders {
     fn from(value: OrderedSealedBlockWithSenders) -> Self {
         let senders = value.0.senders;
@@ -301,6 +469,136 @@ impl BlockDownloader for NoopBlockDownloader {
     }
 }
 
+/// The state of a [`DownloadCircuitBreaker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Downloads are allowed.
+    Closed = 0,
+    /// The backoff elapsed and downloads are allowed again, the next outcome decides whether the
+    /// circuit closes or opens again.
+    HalfOpen = 1,
+    /// Too many downloads failed, no new downloads are started.
+    Open = 2,
+}
+
+/// Stops new block downloads after repeated failures, e.g. if all peers disconnected or keep
+/// returning invalid blocks.
+///
+/// After `failure_threshold` consecutive failed downloads within `failure_window` the circuit opens
+/// and [`DownloadCircuitBreaker::allows_downloads`] returns `false`. Once `backoff` elapsed, the
+/// circuit is half-open and downloads are allowed again: a successful download closes the circuit,
+/// another failure opens it again.
+#[derive(Debug, Clone)]
+pub struct DownloadCircuitBreaker {
+    failure_threshold: usize,
+    failure_window: Duration,
+    backoff: Duration,
+    state: CircuitState,
+    /// The number of consecutive failures and the time of the first one.
+    failures: usize,
+    first_failure_at: Option<Instant>,
+    /// When the circuit opened.
+    opened_at: Option<Instant>,
+}
+
+impl DownloadCircuitBreaker {
+    /// Creates a new closed circuit breaker. The threshold is at least one failure.
+    pub fn new(failure_threshold: usize, failure_window: Duration, backoff: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            failure_window,
+            backoff,
+            state: CircuitState::Closed,
+            failures: 0,
+            first_failure_at: None,
+            opened_at: None,
+        }
+    }
+
+    /// Returns the current state of the circuit.
+    pub const fn state(&self) -> CircuitState {
+        self.state
+    }
+
+    /// Returns `true` if new downloads can be started.
+    ///
+    /// This moves an open circuit to half-open if the backoff elapsed.
+    pub fn allows_downloads(&mut self) -> bool {
+        self.allows_downloads_at(Instant::now())
+    }
+
+    /// Records a successful download, which closes the circuit.
+    pub fn on_success(&mut self) {
+        self.failures = 0;
+        self.first_failure_at = None;
+        self.opened_at = None;
+        if self.state != CircuitState::Closed {
+            warn!(target: "engine::tree", "Block download succeeded, closing download circuit");
+            self.state = CircuitState::Closed;
+        }
+    }
+
+    /// Records a failed download.
+    pub fn on_failure(&mut self) {
+        self.on_failure_at(Instant::now())
+    }
+
+    fn allows_downloads_at(&mut self, now: Instant) -> bool {
+        if self.state == CircuitState::Open &&
+            self.opened_at.is_some_and(|opened_at| now >= opened_at + self.backoff)
+        {
+            warn!(target: "engine::tree", "Download backoff elapsed, circuit is half-open");
+            self.state = CircuitState::HalfOpen;
+        }
+        self.state != CircuitState::Open
+    }
+
+    fn on_failure_at(&mut self, now: Instant) {
+        match self.state {
+            // failures of downloads that were started before the circuit opened
+            CircuitState::Open => {}
+            CircuitState::HalfOpen => self.open(now),
+            CircuitState::Closed => {
+                // only failures within the window count as consecutive
+                if self
+                    .first_failure_at
+                    .is_none_or(|first| now.duration_since(first) > self.failure_window)
+                {
+                    self.failures = 0;
+                    self.first_failure_at = Some(now);
+                }
+                self.failures += 1;
+                if self.failures >= self.failure_threshold {
+                    self.open(now);
+                }
+            }
+        }
+    }
+
+    fn open(&mut self, now: Instant) {
+        warn!(
+            target: "engine::tree",
+            failures = self.failures,
+            backoff = ?self.backoff,
+            "Too many failed block downloads, opening download circuit"
+        );
+        self.state = CircuitState::Open;
+        self.opened_at = Some(now);
+        self.failures = 0;
+        self.first_failure_at = None;
+    }
+}
+
+impl Default for DownloadCircuitBreaker {
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_DOWNLOAD_FAILURE_THRESHOLD,
+            DEFAULT_DOWNLOAD_FAILURE_WINDOW,
+            DEFAULT_DOWNLOAD_BACKOFF,
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -309,7 +607,10 @@ mod tests {
     use assert_matches::assert_matches;
     use reth_beacon_consensus::EthBeaconConsensus;
     use reth_chainspec::{ChainSpecBuilder, MAINNET};
-    use reth_network_p2p::test_utils::TestFullBlockClient;
+    use reth_network_p2p::{
+        headers::client::{HeadersClient, HeadersDirection},
+        test_utils::TestFullBlockClient,
+    };
     use reth_primitives::{Header, SealedHeader};
     use std::{future::poll_fn, sync::Arc};
 
@@ -353,9 +654,10 @@ mod tests {
         let tip = client.highest_block().expect("there should be blocks here");
 
         // send block range download request
-        block_downloader.on_action(DownloadAction::Download(DownloadRequest::BlockRange(
+        block_downloader.on_action(DownloadAction::Download(DownloadRequest::descending_range(
             tip.hash(),
             tip.number,
+            DownloadPriority::Background,
         )));
 
         // ensure we have one in flight range request
@@ -388,6 +690,52 @@ mod tests {
         });
     }
 
+    #[tokio::test]
+    async fn block_downloader_range_request_directions() {
+        const TOTAL_BLOCKS: usize = 10;
+        let TestHarness { mut block_downloader, client } = TestHarness::new(TOTAL_BLOCKS);
+        let tip = client.highest_block().expect("there should be blocks here");
+        let first = client.get_header(1u64.into()).await.unwrap().into_data().unwrap();
+        let first_hash = first.hash_slow();
+
+        // download the same blocks upwards from the first block and downwards from the tip
+        block_downloader.on_action(DownloadAction::Download(DownloadRequest::BlockRange(
+            first_hash,
+            TOTAL_BLOCKS as u64,
+            DownloadDirection::Ascending,
+            DownloadPriority::Background,
+        )));
+        block_downloader.on_action(DownloadAction::Download(DownloadRequest::descending_range(
+            tip.hash(),
+            TOTAL_BLOCKS as u64,
+            DownloadPriority::Background,
+        )));
+        assert_eq!(block_downloader.inflight_block_range_requests.len(), 2);
+        let requests = &block_downloader.inflight_block_range_requests;
+        assert_eq!(requests[0].direction(), HeadersDirection::Rising);
+        assert_eq!(requests[0].start_hash(), first_hash);
+        assert_eq!(requests[1].direction(), HeadersDirection::Falling);
+        assert_eq!(requests[1].start_hash(), tip.hash());
+
+        for _ in 0..2 {
+            let next_ready = poll_fn(|cx| block_downloader.poll(cx)).await;
+            assert_matches!(next_ready, DownloadOutcome::NewDownloadStarted { remaining_blocks, .. } => {
+                assert_eq!(remaining_blocks, TOTAL_BLOCKS as u64);
+            });
+        }
+
+        // both ranges cover the same blocks, which are yielded once in ascending order
+        let next_ready = poll_fn(|cx| block_downloader.poll(cx)).await;
+        assert_matches!(next_ready, DownloadOutcome::Blocks(blocks) => {
+            assert_eq!(blocks.len(), TOTAL_BLOCKS);
+            for num in 1..=TOTAL_BLOCKS {
+                assert_eq!(blocks[num - 1].number, num as u64);
+            }
+            assert_eq!(blocks[0].hash(), first_hash);
+            assert_eq!(blocks[TOTAL_BLOCKS - 1].hash(), tip.hash());
+        });
+    }
+
     #[tokio::test]
     async fn block_downloader_set_request() {
         const TOTAL_BLOCKS: usize = 2;
@@ -398,6 +746,7 @@ mod tests {
         // send block set download request
         block_downloader.on_action(DownloadAction::Download(DownloadRequest::BlockSet(
             HashSet::from([tip.hash(), tip.parent_hash]),
+            DownloadPriority::Background,
         )));
 
         // ensure we have TOTAL_BLOCKS in flight full block request
@@ -426,6 +775,126 @@ mod tests {
         });
     }
 
+    #[tokio::test]
+    async fn block_downloader_dedup_requests() {
+        const TOTAL_BLOCKS: usize = 10;
+        let TestHarness { mut block_downloader, client } = TestHarness::new(TOTAL_BLOCKS);
+
+        let tip = client.highest_block().expect("there should be blocks here");
+
+        // send the same requests twice
+        for _ in 0..2 {
+            block_downloader.on_action(DownloadAction::Download(
+                DownloadRequest::descending_range(
+                    tip.hash(),
+                    tip.number,
+                    DownloadPriority::Background,
+                ),
+            ));
+            block_downloader.on_action(DownloadAction::Download(DownloadRequest::BlockSet(
+                HashSet::from([tip.hash(), tip.parent_hash]),
+                DownloadPriority::Background,
+            )));
+        }
+
+        // a shorter range from the same start is covered by the in flight request
+        block_downloader.on_action(DownloadAction::Download(DownloadRequest::descending_range(
+            tip.hash(),
+            tip.number - 1,
+            DownloadPriority::Background,
+        )));
+
+        // ensure only one request per range and hash is in flight
+        assert_eq!(block_downloader.inflight_block_range_requests.len(), 1);
+        assert_eq!(block_downloader.inflight_full_block_requests.len(), 2);
+        assert_eq!(block_downloader.pending_events.len(), 3);
+
+        // a longer range from the same start is not covered
+        block_downloader.on_action(DownloadAction::Download(DownloadRequest::descending_range(
+            tip.hash(),
+            tip.number + 1,
+            DownloadPriority::Background,
+        )));
+        assert_eq!(block_downloader.inflight_block_range_requests.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn block_downloader_urgent_requests() {
+        const TOTAL_BLOCKS: usize = 10;
+        let TestHarness { mut block_downloader, client } = TestHarness::new(TOTAL_BLOCKS);
+
+        let tip = client.highest_block().expect("there should be blocks here");
+
+        block_downloader.on_action(DownloadAction::Download(DownloadRequest::single_block(
+            tip.hash(),
+            DownloadPriority::Background,
+        )));
+        block_downloader.on_action(DownloadAction::Download(DownloadRequest::descending_range(
+            tip.hash(),
+            tip.number,
+            DownloadPriority::Background,
+        )));
+
+        // urgent requests replace in flight background requests
+        block_downloader.on_action(DownloadAction::Download(DownloadRequest::single_block(
+            tip.hash(),
+            DownloadPriority::Urgent,
+        )));
+        block_downloader.on_action(DownloadAction::Download(DownloadRequest::descending_range(
+            tip.hash(),
+            tip.number,
+            DownloadPriority::Urgent,
+        )));
+        assert_eq!(block_downloader.inflight_full_block_requests.len(), 1);
+        assert_eq!(block_downloader.inflight_block_range_requests.len(), 1);
+        assert!(block_downloader.inflight_full_block_requests[0].priority().is_high());
+        assert!(block_downloader.inflight_block_range_requests[0].priority().is_high());
+
+        // background requests are covered by in flight urgent requests
+        block_downloader.on_action(DownloadAction::Download(DownloadRequest::single_block(
+            tip.hash(),
+            DownloadPriority::Background,
+        )));
+        block_downloader.on_action(DownloadAction::Download(DownloadRequest::descending_range(
+            tip.hash(),
+            tip.number,
+            DownloadPriority::Background,
+        )));
+        assert_eq!(block_downloader.inflight_full_block_requests.len(), 1);
+        assert_eq!(block_downloader.inflight_block_range_requests.len(), 1);
+        assert!(block_downloader.inflight_full_block_requests[0].priority().is_high());
+        assert!(block_downloader.inflight_block_range_requests[0].priority().is_high());
+    }
+
+    #[tokio::test]
+    async fn block_downloader_bodies_request() {
+        const TOTAL_BLOCKS: usize = 10;
+        let TestHarness { mut block_downloader, client } = TestHarness::new(TOTAL_BLOCKS);
+
+        let tip = client.highest_block().expect("there should be blocks here");
+
+        block_downloader.on_action(DownloadAction::Download(DownloadRequest::Bodies(
+            vec![tip.header.clone()],
+            DownloadPriority::Background,
+        )));
+
+        // bodies are downloaded with a range request
+        assert_eq!(block_downloader.inflight_block_range_requests.len(), 1);
+        assert_eq!(block_downloader.inflight_full_block_requests.len(), 0);
+
+        let next_ready = poll_fn(|cx| block_downloader.poll(cx)).await;
+        assert_matches!(next_ready, DownloadOutcome::NewDownloadStarted { remaining_blocks, target } => {
+            assert_eq!(remaining_blocks, 1);
+            assert_eq!(target, tip.hash());
+        });
+
+        let next_ready = poll_fn(|cx| block_downloader.poll(cx)).await;
+        assert_matches!(next_ready, DownloadOutcome::Blocks(blocks) => {
+            assert_eq!(blocks.len(), 1);
+            assert_eq!(blocks[0].hash(), tip.hash());
+        });
+    }
+
     #[tokio::test]
     async fn block_downloader_clear_request() {
         const TOTAL_BLOCKS: usize = 10;
@@ -434,15 +903,18 @@ mod tests {
         let tip = client.highest_block().expect("there should be blocks here");
 
         // send block range download request
-        block_downloader.on_action(DownloadAction::Download(DownloadRequest::BlockRange(
+        block_downloader.on_action(DownloadAction::Download(DownloadRequest::descending_range(
             tip.hash(),
             tip.number,
+            DownloadPriority::Background,
         )));
 
         // send block set download request
         let download_set = HashSet::from([tip.hash(), tip.parent_hash]);
-        block_downloader
-            .on_action(DownloadAction::Download(DownloadRequest::BlockSet(download_set.clone())));
+        block_downloader.on_action(DownloadAction::Download(DownloadRequest::BlockSet(
+            download_set.clone(),
+            DownloadPriority::Background,
+        )));
 
         // ensure we have one in flight range request
         assert_eq!(block_downloader.inflight_block_range_requests.len(), 1);
@@ -464,4 +936,72 @@ mod tests {
         // ensure we have no in flight full block request
         assert_eq!(block_downloader.inflight_full_block_requests.len(), 0);
     }
+
+    #[test]
+    fn download_circuit_breaker() {
+        let window = Duration::from_secs(10);
+        let backoff = Duration::from_secs(5);
+        let mut breaker = DownloadCircuitBreaker::new(2, window, backoff);
+        let now = Instant::now();
+
+        // failures outside of the window are not consecutive
+        breaker.on_failure_at(now);
+        breaker.on_failure_at(now + window + Duration::from_secs(1));
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        // a success resets the failures
+        breaker.on_success();
+        breaker.on_failure_at(now);
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        breaker.on_failure_at(now + Duration::from_secs(1));
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(!breaker.allows_downloads_at(now + Duration::from_secs(2)));
+
+        // half-open after the backoff, another failure opens the circuit again
+        let half_open_at = now + Duration::from_secs(1) + backoff;
+        assert!(breaker.allows_downloads_at(half_open_at));
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        breaker.on_failure_at(half_open_at);
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(!breaker.allows_downloads_at(half_open_at));
+
+        // a successful download closes the circuit
+        assert!(breaker.allows_downloads_at(half_open_at + backoff));
+        breaker.on_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn failed_requests_open_download_circuit() {
+        let TestHarness { mut block_downloader, client } = TestHarness::new(0);
+        let mut breaker =
+            DownloadCircuitBreaker::new(3, Duration::from_secs(60), Duration::from_secs(60));
+
+        // the body of the block never matches its header
+        let (header, seal) =
+            Header { number: 1, transactions_root: B256::random(), ..Default::default() }
+                .seal_slow()
+                .into_parts();
+        let header = SealedHeader::new(header, seal);
+        client.insert(header.clone(), Default::default());
+
+        block_downloader.on_action(DownloadAction::Download(DownloadRequest::single_block(
+            header.hash(),
+            DownloadPriority::Urgent,
+        )));
+        let next_ready = poll_fn(|cx| block_downloader.poll(cx)).await;
+        assert_matches!(next_ready, DownloadOutcome::NewDownloadStarted { .. });
+
+        // every failed request is reported while the request is retried
+        while breaker.allows_downloads() {
+            let next_ready = poll_fn(|cx| block_downloader.poll(cx)).await;
+            assert_matches!(next_ready, DownloadOutcome::Error(err) => {
+                assert_eq!(err.hash(), header.hash());
+                assert!(err.is_retried());
+                breaker.on_failure();
+            });
+        }
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert_eq!(block_downloader.inflight_full_block_requests.len(), 1);
+    }
 }
@@ -1,23 +1,43 @@
 //! An engine API handler for the chain.
 
 use crate::{
-    backfill::BackfillAction,
+    backfill::{BackfillAction, BackfillSyncState},
     chain::{ChainHandler, FromOrchestrator, HandlerEvent},
-    download::{BlockDownloader, DownloadAction, DownloadOutcome},
+    download::{
+        BlockDownloader, CircuitState, DownloadAction, DownloadCircuitBreaker, DownloadOutcome,
+    },
+    metrics::{EngineApiRequestHandlerMetrics, EngineHandlerMetrics},
 };
+use alloy_eips::BlockNumHash;
 use alloy_primitives::B256;
-use futures::{Stream, StreamExt};
-use reth_beacon_consensus::{BeaconConsensusEngineEvent, BeaconEngineMessage};
+use alloy_rpc_types_engine::{ForkchoiceState, PayloadStatus, PayloadStatusEnum};
+use futures::{stream::FuturesUnordered, Stream, StreamExt};
+use reth_beacon_consensus::{
+    BeaconConsensusEngineEvent, BeaconEngineMessage, ForkchoiceStatus, OnForkChoiceUpdated,
+};
 use reth_chain_state::ExecutedBlock;
 use reth_engine_primitives::EngineTypes;
-use reth_primitives::SealedBlockWithSenders;
+use reth_network_p2p::{headers::client::HeadersDirection, priority::Priority};
+use reth_primitives::{SealedBlockWithSenders, SealedHeader};
 use std::{
-    collections::HashSet,
-    fmt::Display,
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::{self, Display},
+    future::Future,
+    pin::Pin,
     sync::mpsc::Sender,
     task::{ready, Context, Poll},
+    time::{Duration, Instant},
 };
-use tokio::sync::mpsc::UnboundedReceiver;
+use tokio::sync::{mpsc::UnboundedReceiver, oneshot};
+use tracing::{debug, error, warn};
+
+/// The default number of incoming requests the [`EngineHandler`] queues before it answers requests
+/// with `SYNCING`.
+pub const DEFAULT_MAX_QUEUED_REQUESTS: usize = 128;
+
+/// The default number of requests the [`EngineHandler`] delegates to the handler that are not
+/// answered yet, before it stops accepting incoming requests.
+pub const DEFAULT_MAX_PENDING_REQUESTS: usize = 16;
 
 /// A [`ChainHandler`] that advances the chain based on incoming requests (CL engine API).
 ///
@@ -33,52 +53,242 @@ use tokio::sync::mpsc::UnboundedReceiver;
 /// - Delegating incoming requests to the [`EngineRequestHandler`].
 /// - Advancing the [`EngineRequestHandler`] by polling it and emitting events.
 /// - Downloading blocks on demand from the network if requested by the [`EngineApiRequestHandler`].
+///   Blocks that are already being downloaded with a sufficient priority are not requested again.
+///   After repeated failed downloads, no new downloads are started for a while, see
+///   [`DownloadCircuitBreaker`].
 ///
 /// The core logic is part of the [`EngineRequestHandler`], which is responsible for processing the
 /// incoming requests.
+///
+/// ## Queued requests
+///
+/// Incoming requests are queued before they are delegated to the [`EngineRequestHandler`]. The
+/// queue is bounded, see [`EngineHandler::with_max_queued_requests`]. If the queue is full, new
+/// payloads are answered with `SYNCING` right away. A forkchoice update replaces the oldest queued
+/// forkchoice update, or the newest queued payload if there is none, and the replaced request is
+/// answered with `SYNCING`. No request is dropped without a response.
+///
+/// Queued forkchoice updates are delegated before queued payloads, because a timely forkchoice
+/// update matters more than executing an older payload, e.g. during a reorg. A forkchoice update
+/// is never delegated before an earlier payload of a block it references, so that the block is
+/// known when the forkchoice update is processed. This only applies while the handler can execute
+/// forkchoice updates, see [`EngineRequestHandler::can_commit`], otherwise requests are delegated
+/// in the order they were received.
+///
+/// ## Backpressure
+///
+/// The [`EngineHandler`] tracks the delegated requests that the handler hasn't answered yet, see
+/// [`EngineRequest::track_response`]. Once the limit set with
+/// [`EngineHandler::with_request_backpressure`] is reached, incoming requests are not polled until
+/// the handler answers one of them, and queued requests are not delegated either. This way a slow
+/// handler isn't flooded with requests and the queue stays bounded. Once incoming requests are
+/// polled again, the requests that arrived in the meantime are queued as usual, so a full queue
+/// answers them with `SYNCING`.
+///
+/// ## Draining
+///
+/// [`EngineHandler::begin_drain`] stops accepting new incoming requests and cancels all pending
+/// downloads. The requests that were already accepted are still delegated to the handler, which is
+/// then asked to shut down, so that every accepted payload and forkchoice update is answered.
+/// Once the handler finished, [`HandlerEvent::Shutdown`] is emitted and the [`EngineHandler`] is
+/// idle. Requests that arrive after the drain began are answered with `SYNCING`.
 #[derive(Debug)]
-pub struct EngineHandler<T, S, D> {
+pub struct EngineHandler<T, S: Stream, D> {
     /// Processes requests.
     ///
     /// This type is responsible for processing incoming requests.
     handler: T,
     /// Receiver for incoming requests (from the engine API endpoint) that need to be processed.
     incoming_requests: S,
+    /// Incoming requests that are not yet delegated to the handler.
+    queued_requests: RequestQueue<S::Item>,
+    /// The responses of the delegated requests that the handler hasn't answered yet.
+    pending_responses: FuturesUnordered<PendingResponse>,
+    /// The number of pending responses at which no more incoming requests are accepted.
+    max_pending_requests: usize,
     /// A downloader to download blocks on demand.
     downloader: D,
+    /// The blocks that are requested from the downloader but not downloaded yet, with the priority
+    /// they were requested with.
+    inflight_downloads: HashMap<B256, DownloadPriority>,
+    /// Stops new downloads after repeated failures.
+    download_circuit_breaker: DownloadCircuitBreaker,
+    /// The progress of the drain, see [`EngineHandler::begin_drain`].
+    drain: DrainState,
+    /// Metrics for the handler.
+    metrics: EngineHandlerMetrics,
 }
 
-impl<T, S, D> EngineHandler<T, S, D> {
+impl<T, S: Stream, D> EngineHandler<T, S, D> {
     /// Creates a new [`EngineHandler`] with the given handler and downloader and incoming stream of
     /// requests.
-    pub const fn new(handler: T, downloader: D, incoming_requests: S) -> Self
+    pub fn new(handler: T, downloader: D, incoming_requests: S) -> Self
     where
         T: EngineRequestHandler,
     {
-        Self { handler, incoming_requests, downloader }
+        Self {
+            handler,
+            incoming_requests,
+            queued_requests: RequestQueue::new(DEFAULT_MAX_QUEUED_REQUESTS),
+            pending_responses: FuturesUnordered::new(),
+            max_pending_requests: DEFAULT_MAX_PENDING_REQUESTS,
+            downloader,
+            inflight_downloads: HashMap::default(),
+            download_circuit_breaker: DownloadCircuitBreaker::default(),
+            drain: DrainState::Running,
+            metrics: EngineHandlerMetrics::default(),
+        }
+    }
+
+    /// Sets the maximum number of queued incoming requests.
+    ///
+    /// Defaults to [`DEFAULT_MAX_QUEUED_REQUESTS`]. At least one request is always queued.
+    pub fn with_max_queued_requests(mut self, max_queued_requests: usize) -> Self {
+        self.queued_requests.capacity = max_queued_requests.max(1);
+        self
+    }
+
+    /// Sets the maximum number of delegated requests that the handler hasn't answered yet.
+    ///
+    /// Once there are this many, incoming requests are not polled and queued requests are not
+    /// delegated until the handler answers one of them. Defaults to
+    /// [`DEFAULT_MAX_PENDING_REQUESTS`]. At least one request is always delegated.
+    pub fn with_request_backpressure(mut self, max_pending_requests: usize) -> Self {
+        self.max_pending_requests = max_pending_requests.max(1);
+        self
+    }
+
+    /// Sets the [`DownloadCircuitBreaker`] that decides whether new downloads are started.
+    pub fn with_download_circuit_breaker(mut self, breaker: DownloadCircuitBreaker) -> Self {
+        self.download_circuit_breaker = breaker;
+        self
     }
 
     /// Returns a mutable reference to the request handler.
     pub fn handler_mut(&mut self) -> &mut T {
         &mut self.handler
     }
+
+    /// Returns `true` if the drain finished and the handler is idle, see [`Self::begin_drain`].
+    pub const fn is_drained(&self) -> bool {
+        matches!(self.drain, DrainState::Drained)
+    }
+
+    /// Removes the blocks from the request that are already being downloaded with a sufficient
+    /// priority and marks the remaining blocks as in flight.
+    ///
+    /// Range requests are forwarded as is, because the blocks of the range are not known yet.
+    /// Returns `None` if all requested blocks are already being downloaded.
+    fn dedup_download_request(&mut self, request: DownloadRequest) -> Option<DownloadRequest> {
+        let priority = request.priority();
+        let inflight = &mut self.inflight_downloads;
+        let mut request_block = |hash: B256| match inflight.get(&hash) {
+            // urgent requests restart background downloads with a higher priority
+            Some(inflight) if inflight.is_urgent() || !priority.is_urgent() => false,
+            _ => {
+                inflight.insert(hash, priority);
+                true
+            }
+        };
+
+        match request {
+            DownloadRequest::BlockSet(mut hashes, priority) => {
+                hashes.retain(|hash| request_block(*hash));
+                (!hashes.is_empty()).then_some(DownloadRequest::BlockSet(hashes, priority))
+            }
+            DownloadRequest::Bodies(mut headers, priority) => {
+                headers.retain(|header| request_block(header.hash()));
+                (!headers.is_empty()).then_some(DownloadRequest::Bodies(headers, priority))
+            }
+            range @ DownloadRequest::BlockRange(..) => Some(range),
+        }
+    }
+
+    /// Returns `true` if the handler has as many unanswered requests as it's allowed to, see
+    /// [`Self::with_request_backpressure`].
+    fn is_backpressured(&self) -> bool {
+        self.pending_responses.len() >= self.max_pending_requests
+    }
+
+    /// Forwards the responses of the requests that the handler answered.
+    fn poll_pending_responses(&mut self, cx: &mut Context<'_>) {
+        while let Poll::Ready(Some(())) = self.pending_responses.poll_next_unpin(cx) {}
+        self.metrics.pending_requests.set(self.pending_responses.len() as f64);
+    }
+
+    /// Records the state of the download circuit breaker.
+    fn record_download_circuit_state(&self) {
+        self.metrics.download_circuit_state.set(self.download_circuit_breaker.state() as u8 as f64);
+    }
+}
+
+impl<T, S: Stream, D: BlockDownloader> EngineHandler<T, S, D> {
+    /// Begins draining the handler, e.g. on shutdown.
+    ///
+    /// No new incoming requests are accepted after this and pending downloads are cancelled. The
+    /// requests that are already queued are still delegated to the handler, afterwards the handler
+    /// is shut down via [`FromOrchestrator::Shutdown`]. [`ChainHandler::poll`] returns
+    /// [`HandlerEvent::Shutdown`] once the handler processed all requests.
+    ///
+    /// Requests that arrive after this are answered with `SYNCING`, also after the drain finished,
+    /// as long as the handler is polled.
+    pub fn begin_drain(&mut self) {
+        if self.drain.is_draining() {
+            return
+        }
+        debug!(
+            target: "engine::tree",
+            queued = self.queued_requests.len(),
+            "Draining engine handler"
+        );
+        self.drain = DrainState::Draining;
+        self.downloader.on_action(DownloadAction::Clear);
+        self.inflight_downloads.clear();
+    }
+}
+
+impl<T, S, D> EngineHandler<T, S, D>
+where
+    S: Stream + Unpin,
+    S::Item: EngineRequest,
+{
+    /// Answers all ready incoming requests with `SYNCING`, because the handler is draining.
+    fn respond_syncing_to_incoming(&mut self, cx: &mut Context<'_>) {
+        while let Poll::Ready(Some(req)) = self.incoming_requests.poll_next_unpin(cx) {
+            self.metrics.incoming_requests.increment(1);
+            self.metrics.dropped_requests.increment(1);
+            req.respond_syncing();
+        }
+    }
 }
 
 impl<T, S, D> ChainHandler for EngineHandler<T, S, D>
 where
     T: EngineRequestHandler,
     S: Stream + Send + Sync + Unpin + 'static,
-    <S as Stream>::Item: Into<T::Request>,
+    <S as Stream>::Item: EngineRequest + Into<T::Request>,
     D: BlockDownloader,
 {
     type Event = T::Event;
 
     fn on_event(&mut self, event: FromOrchestrator) {
+        if matches!(event, FromOrchestrator::Shutdown) {
+            // blocks that are downloaded during the shutdown are not processed anymore
+            self.downloader.on_action(DownloadAction::Clear);
+            self.inflight_downloads.clear();
+        }
         // delegate event to the handler
         self.handler.on_event(event.into());
     }
 
     fn poll(&mut self, cx: &mut Context<'_>) -> Poll<HandlerEvent<Self::Event>> {
+        if self.is_drained() {
+            // the handler shut down, but the consensus client may still send requests
+            self.poll_pending_responses(cx);
+            self.respond_syncing_to_incoming(cx);
+            return Poll::Pending
+        }
+
         loop {
             // drain the handler first
             while let Poll::Ready(ev) = self.handler.poll(cx) {
@@ -88,6 +298,7 @@ where
                             HandlerEvent::BackfillAction(target) => {
                                 // bubble up backfill sync request request
                                 self.downloader.on_action(DownloadAction::Clear);
+                                self.inflight_downloads.clear();
                                 Poll::Ready(HandlerEvent::BackfillAction(target))
                             }
                             HandlerEvent::Event(ev) => {
@@ -95,28 +306,137 @@ where
                                 Poll::Ready(HandlerEvent::Event(ev))
                             }
                             HandlerEvent::FatalError => Poll::Ready(HandlerEvent::FatalError),
+                            HandlerEvent::Shutdown => {
+                                if self.drain.is_draining() {
+                                    debug!(target: "engine::tree", "Engine handler drained");
+                                    self.drain = DrainState::Drained;
+                                    // the handler shut down before all queued requests were
+                                    // delegated
+                                    while let Some((req, _)) = self.queued_requests.pop() {
+                                        req.respond_syncing();
+                                    }
+                                }
+                                Poll::Ready(HandlerEvent::Shutdown)
+                            }
                         }
                     }
+                    RequestHandlerEvent::Download(_) if self.drain.is_draining() => {
+                        // no new downloads are started while draining
+                        continue
+                    }
                     RequestHandlerEvent::Download(req) => {
-                        // delegate download request to the downloader
-                        self.downloader.on_action(DownloadAction::Download(req));
+                        let allowed = self.download_circuit_breaker.allows_downloads();
+                        self.record_download_circuit_state();
+                        if !allowed {
+                            // the handler requests the blocks again if they're still missing
+                            debug!(
+                                target: "engine::tree",
+                                "Download circuit is open, skipping download"
+                            );
+                            self.metrics.skipped_download_requests.increment(1);
+                            continue
+                        }
+
+                        // delegate download request to the downloader, unless all blocks are
+                        // already being downloaded
+                        if let Some(req) = self.dedup_download_request(req) {
+                            self.metrics.download_requests.increment(1);
+                            self.downloader.on_action(DownloadAction::Download(req));
+                        }
+                    }
+                    RequestHandlerEvent::Error(err) => {
+                        // the handler can't make progress anymore, bubble up as fatal error
+                        error!(target: "engine::tree", %err, "Engine request handler failed");
+                        return Poll::Ready(HandlerEvent::FatalError)
                     }
                 }
             }
 
-            // pop the next incoming request
-            if let Poll::Ready(Some(req)) = self.incoming_requests.poll_next_unpin(cx) {
+            self.poll_pending_responses(cx);
+
+            // queue all ready incoming requests, unless the handler is draining or has too many
+            // unanswered requests
+            if self.drain.is_draining() {
+                self.respond_syncing_to_incoming(cx);
+            } else if !self.is_backpressured() {
+                while let Poll::Ready(Some(req)) = self.incoming_requests.poll_next_unpin(cx) {
+                    self.metrics.incoming_requests.increment(1);
+                    if self.queued_requests.push(req) {
+                        debug!(
+                            target: "engine::tree",
+                            "Request queue is full, answered with SYNCING"
+                        );
+                        self.metrics.dropped_requests.increment(1);
+                    }
+                }
+            }
+
+            // pop the next queued request, unless the handler has too many unanswered requests,
+            // forkchoice updates take priority over payloads unless the handler can't execute them
+            // right now
+            let next = if self.is_backpressured() {
+                None
+            } else if self.handler.can_commit() {
+                self.queued_requests.pop_forkchoice_update().or_else(|| self.queued_requests.pop())
+            } else {
+                self.queued_requests.pop()
+            };
+            if let Some((mut req, waited)) = next {
                 // and delegate the request to the handler
+                self.metrics.queued_requests.set(self.queued_requests.len() as f64);
+                self.metrics.request_queue_wait_duration.record(waited);
+                if let Some(response) = req.track_response() {
+                    self.pending_responses.push(response);
+                }
                 self.handler.on_event(FromEngine::Request(req.into()));
                 // skip downloading in this iteration to allow the handler to process the request
                 continue
             }
 
+            if self.drain.is_draining() {
+                if matches!(self.drain, DrainState::Draining) && self.queued_requests.is_empty() {
+                    // all accepted requests are delegated, the handler answers them before it
+                    // shuts down
+                    self.drain = DrainState::ShuttingDown;
+                    self.handler.on_event(FromOrchestrator::Shutdown.into());
+                    continue
+                }
+                // pending downloads are cancelled, wait for the handler to answer the delegated
+                // requests or to shut down
+                return Poll::Pending
+            }
+
             // advance the downloader
             if let Poll::Ready(outcome) = self.downloader.poll(cx) {
-                if let DownloadOutcome::Blocks(blocks) = outcome {
-                    // delegate the downloaded blocks to the handler
-                    self.handler.on_event(FromEngine::DownloadedBlocks(blocks));
+                match outcome {
+                    DownloadOutcome::Blocks(blocks) => {
+                        // delegate the downloaded blocks to the handler
+                        self.metrics.downloaded_block_batches.increment(1);
+                        for block in &blocks {
+                            self.inflight_downloads.remove(&block.hash());
+                        }
+                        self.download_circuit_breaker.on_success();
+                        self.record_download_circuit_state();
+                        self.handler.on_event(FromEngine::DownloadedBlocks(blocks));
+                    }
+                    DownloadOutcome::Error(err) => {
+                        self.metrics.failed_downloads.increment(1);
+                        if !err.is_retried() {
+                            // the handler requests the blocks again if they're still missing
+                            self.inflight_downloads.remove(&err.hash());
+                        }
+                        let was_open = self.download_circuit_breaker.state() == CircuitState::Open;
+                        self.download_circuit_breaker.on_failure();
+                        self.record_download_circuit_state();
+                        warn!(target: "engine::tree", %err, "Block download failed");
+                        if !was_open && self.download_circuit_breaker.state() == CircuitState::Open
+                        {
+                            // stop the in-flight downloads, which keep retrying failed requests
+                            self.downloader.on_action(DownloadAction::Clear);
+                            self.inflight_downloads.clear();
+                        }
+                    }
+                    DownloadOutcome::NewDownloadStarted { .. } => {}
                 }
                 continue
             }
@@ -126,6 +446,190 @@ where
     }
 }
 
+/// The progress of draining the [`EngineHandler`], see [`EngineHandler::begin_drain`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DrainState {
+    /// The handler accepts incoming requests.
+    Running,
+    /// The handler delegates the queued requests.
+    Draining,
+    /// All queued requests are delegated and the handler is shutting down.
+    ShuttingDown,
+    /// The handler shut down, no requests are processed anymore.
+    Drained,
+}
+
+impl DrainState {
+    /// Returns `true` if incoming requests are not accepted anymore.
+    const fn is_draining(&self) -> bool {
+        !matches!(self, Self::Running)
+    }
+}
+
+/// An incoming request that is queued by the [`EngineHandler`].
+pub trait EngineRequest {
+    /// Returns `true` if the request is a forkchoice update.
+    fn is_forkchoice_update(&self) -> bool;
+
+    /// Returns the hash of the block if the request is a new payload.
+    fn payload_block_hash(&self) -> Option<B256>;
+
+    /// Returns `true` if the request is a forkchoice update that references the given block as
+    /// head, safe or finalized block.
+    fn references_block(&self, block_hash: B256) -> bool;
+
+    /// Answers the request with `SYNCING` without processing it.
+    fn respond_syncing(self);
+
+    /// Redirects the response of the request through the returned future, which forwards the
+    /// response once the request is answered.
+    ///
+    /// Returns `None` if the request isn't answered.
+    fn track_response(&mut self) -> Option<PendingResponse>;
+}
+
+/// The response of a delegated request, see [`EngineRequest::track_response`].
+pub type PendingResponse = Pin<Box<dyn Future<Output = ()> + Send + Sync>>;
+
+/// Replaces the response channel with a new one and returns the future that forwards the response
+/// sent on the new channel to the replaced one.
+fn forward_response<T: Send + 'static>(tx: &mut oneshot::Sender<T>) -> PendingResponse {
+    let (forward_tx, forward_rx) = oneshot::channel();
+    let tx = std::mem::replace(tx, forward_tx);
+    Box::pin(async move {
+        if let Ok(response) = forward_rx.await {
+            let _ = tx.send(response);
+        }
+    })
+}
+
+impl<T: EngineTypes> EngineRequest for BeaconEngineMessage<T> {
+    fn is_forkchoice_update(&self) -> bool {
+        matches!(self, Self::ForkchoiceUpdated { .. })
+    }
+
+    fn payload_block_hash(&self) -> Option<B256> {
+        match self {
+            Self::NewPayload { payload, .. } => Some(payload.block_hash()),
+            _ => None,
+        }
+    }
+
+    fn references_block(&self, block_hash: B256) -> bool {
+        match self {
+            Self::ForkchoiceUpdated { state, .. } => {
+                state.head_block_hash == block_hash ||
+                    state.safe_block_hash == block_hash ||
+                    state.finalized_block_hash == block_hash
+            }
+            _ => false,
+        }
+    }
+
+    fn respond_syncing(self) {
+        match self {
+            Self::NewPayload { tx, .. } => {
+                let _ = tx.send(Ok(PayloadStatus::from_status(PayloadStatusEnum::Syncing)));
+            }
+            Self::ForkchoiceUpdated { tx, .. } => {
+                let _ = tx.send(Ok(OnForkChoiceUpdated::syncing()));
+            }
+            Self::TransitionConfigurationExchanged => {}
+        }
+    }
+
+    fn track_response(&mut self) -> Option<PendingResponse> {
+        match self {
+            Self::NewPayload { tx, .. } => Some(forward_response(tx)),
+            Self::ForkchoiceUpdated { tx, .. } => Some(forward_response(tx)),
+            Self::TransitionConfigurationExchanged => None,
+        }
+    }
+}
+
+/// A bounded FIFO queue of incoming requests, see [`EngineHandler`].
+struct RequestQueue<R> {
+    /// The queued requests with the time they were queued at.
+    requests: VecDeque<(Instant, R)>,
+    capacity: usize,
+}
+
+impl<R> RequestQueue<R> {
+    const fn new(capacity: usize) -> Self {
+        Self { requests: VecDeque::new(), capacity }
+    }
+
+    fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /// Removes the oldest request from the queue and returns it with the time it was queued for.
+    fn pop(&mut self) -> Option<(R, Duration)> {
+        self.requests.pop_front().map(|(queued_at, request)| (request, queued_at.elapsed()))
+    }
+}
+
+impl<R: EngineRequest> RequestQueue<R> {
+    /// Queues the request.
+    ///
+    /// If the queue is full, a request is answered with `SYNCING` instead, see [`EngineHandler`].
+    /// Returns `true` if a request was answered.
+    fn push(&mut self, request: R) -> bool {
+        if self.requests.len() < self.capacity {
+            self.requests.push_back((Instant::now(), request));
+            return false
+        }
+
+        if !request.is_forkchoice_update() {
+            request.respond_syncing();
+            return true
+        }
+
+        // a forkchoice update supersedes the oldest queued forkchoice update, otherwise it replaces
+        // the newest payload
+        let idx = self
+            .requests
+            .iter()
+            .position(|(_, request)| request.is_forkchoice_update())
+            .unwrap_or(self.requests.len() - 1);
+        if let Some((_, replaced)) = self.requests.remove(idx) {
+            replaced.respond_syncing();
+        }
+        self.requests.push_back((Instant::now(), request));
+        true
+    }
+
+    /// Removes the oldest forkchoice update from the queue and returns it with the time it was
+    /// queued for.
+    ///
+    /// Returns `None` if the forkchoice update references the block of an earlier queued payload,
+    /// in which case the payload has to be delegated first.
+    fn pop_forkchoice_update(&mut self) -> Option<(R, Duration)> {
+        let idx = self.requests.iter().position(|(_, request)| request.is_forkchoice_update())?;
+        let update = &self.requests[idx].1;
+        let references_earlier_payload = self.requests.range(..idx).any(|(_, request)| {
+            request.payload_block_hash().is_some_and(|hash| update.references_block(hash))
+        });
+        if references_earlier_payload {
+            return None
+        }
+        self.requests.remove(idx).map(|(queued_at, request)| (request, queued_at.elapsed()))
+    }
+}
+
+impl<R> fmt::Debug for RequestQueue<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestQueue")
+            .field("requests", &self.requests.len())
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
+
 /// A type that processes incoming requests (e.g. requests from the consensus layer, engine API,
 /// such as newPayload).
 ///
@@ -145,6 +649,15 @@ pub trait EngineRequestHandler: Send + Sync {
 
     /// Advances the handler.
     fn poll(&mut self, cx: &mut Context<'_>) -> Poll<RequestHandlerEvent<Self::Event>>;
+
+    /// Returns `true` if the handler currently executes forkchoice updates instead of answering
+    /// them with `SYNCING`.
+    ///
+    /// While this is `true`, the [`EngineHandler`] delegates queued forkchoice updates before
+    /// queued payloads.
+    fn can_commit(&self) -> bool {
+        true
+    }
 }
 
 /// An [`EngineRequestHandler`] that processes engine API requests by delegating to an execution
@@ -166,21 +679,55 @@ pub trait EngineRequestHandler: Send + Sync {
 ///
 /// In case required blocks are missing, the handler will request them from the network, by emitting
 /// a download request upstream.
+///
+/// While backfill sync is pending or running, the tree can't commit to the database and answers
+/// forkchoice updates with `SYNCING`, see [`EngineApiRequestHandler::can_commit`].
+///
+/// On [`FromOrchestrator::Shutdown`], the tree finishes processing the requests that were received
+/// before, answers all later payloads and forkchoice updates with `SYNCING` and the handler emits
+/// [`HandlerEvent::Shutdown`].
 #[derive(Debug)]
 pub struct EngineApiRequestHandler<Request> {
     /// channel to send messages to the tree to execute the payload.
     to_tree: Sender<FromEngine<Request>>,
     /// channel to receive messages from the tree.
     from_tree: UnboundedReceiver<EngineApiEvent>,
+    /// The backfill sync state, tracked from the events exchanged with the tree.
+    backfill_sync_state: BackfillSyncState,
+    /// Metrics for the request handler.
+    metrics: EngineApiRequestHandlerMetrics,
 }
 
 impl<Request> EngineApiRequestHandler<Request> {
     /// Creates a new `EngineApiRequestHandler`.
-    pub const fn new(
+    pub fn new(
         to_tree: Sender<FromEngine<Request>>,
         from_tree: UnboundedReceiver<EngineApiEvent>,
     ) -> Self {
-        Self { to_tree, from_tree }
+        Self {
+            to_tree,
+            from_tree,
+            backfill_sync_state: BackfillSyncState::Idle,
+            metrics: EngineApiRequestHandlerMetrics::default(),
+        }
+    }
+
+    /// Records the duration the tree reports for committing the canonical chain.
+    ///
+    /// The execution of payloads is already recorded by the tree itself.
+    fn record_event_metrics(&self, event: &BeaconConsensusEngineEvent) {
+        if let BeaconConsensusEngineEvent::CanonicalChainCommitted(_, elapsed) = event {
+            self.metrics.forkchoice_commit_duration.record(*elapsed);
+        }
+    }
+
+    /// Returns `true` if the tree currently has write access to the database and executes
+    /// forkchoice updates.
+    ///
+    /// This is `false` from the moment the tree requests a backfill sync until the backfill sync
+    /// finished. In the meantime, forkchoice updates are answered with `SYNCING`.
+    pub const fn can_commit(&self) -> bool {
+        self.backfill_sync_state.is_idle()
     }
 }
 
@@ -188,10 +735,19 @@ impl<Request> EngineRequestHandler for EngineApiRequestHandler<Request>
 where
     Request: Send,
 {
-    type Event = BeaconConsensusEngineEvent;
+    type Event = EngineApiRequestHandlerEvent;
     type Request = Request;
 
     fn on_event(&mut self, event: FromEngine<Self::Request>) {
+        match &event {
+            FromEngine::Event(FromOrchestrator::BackfillSyncStarted) => {
+                self.backfill_sync_state = BackfillSyncState::Active;
+            }
+            FromEngine::Event(FromOrchestrator::BackfillSyncFinished(_)) => {
+                self.backfill_sync_state = BackfillSyncState::Idle;
+            }
+            _ => {}
+        }
         // delegate to the tree
         let _ = self.to_tree.send(event);
     }
@@ -203,15 +759,101 @@ where
 
         let ev = match ev {
             EngineApiEvent::BeaconConsensus(ev) => {
-                RequestHandlerEvent::HandlerEvent(HandlerEvent::Event(ev))
+                self.record_event_metrics(&ev);
+                RequestHandlerEvent::HandlerEvent(HandlerEvent::Event(ev.into()))
+            }
+            EngineApiEvent::PayloadBuffered { block, missing_ancestor } => {
+                RequestHandlerEvent::HandlerEvent(HandlerEvent::Event(
+                    EngineApiRequestHandlerEvent::PayloadBuffered { block, missing_ancestor },
+                ))
             }
+            EngineApiEvent::PayloadResolved(block) => RequestHandlerEvent::HandlerEvent(
+                HandlerEvent::Event(EngineApiRequestHandlerEvent::PayloadResolved(block)),
+            ),
             EngineApiEvent::BackfillAction(action) => {
+                // the tree stops committing once it requested the backfill sync
+                self.backfill_sync_state = BackfillSyncState::Pending;
                 RequestHandlerEvent::HandlerEvent(HandlerEvent::BackfillAction(action))
             }
             EngineApiEvent::Download(action) => RequestHandlerEvent::Download(action),
+            EngineApiEvent::Error(err) => RequestHandlerEvent::Error(err),
+            EngineApiEvent::Shutdown => RequestHandlerEvent::HandlerEvent(HandlerEvent::Shutdown),
         };
         Poll::Ready(ev)
     }
+
+    fn can_commit(&self) -> bool {
+        Self::can_commit(self)
+    }
+}
+
+/// Events emitted by the [`EngineApiRequestHandler`].
+#[derive(Clone, Debug)]
+pub enum EngineApiRequestHandlerEvent {
+    /// Event from the consensus engine, other than a processed forkchoice update.
+    BeaconConsensus(BeaconConsensusEngineEvent),
+    /// A new payload was buffered because its parent is missing, the payload is inserted once the
+    /// missing ancestors are downloaded.
+    PayloadBuffered {
+        /// The buffered payload.
+        block: BlockNumHash,
+        /// The lowest ancestor of the payload that is missing.
+        missing_ancestor: BlockNumHash,
+    },
+    /// A buffered block was inserted into the tree after its missing parent was inserted.
+    PayloadResolved(BlockNumHash),
+    /// The tree processed a forkchoice update, and the resulting forkchoice status.
+    ForkchoiceProcessed(ForkchoiceState, ForkchoiceStatus),
+}
+
+impl EngineApiRequestHandlerEvent {
+    /// Returns the canonical header if the event is a
+    /// [`BeaconConsensusEngineEvent::CanonicalChainCommitted`].
+    pub const fn canonical_header(&self) -> Option<&SealedHeader> {
+        match self {
+            Self::BeaconConsensus(event) => event.canonical_header(),
+            _ => None,
+        }
+    }
+
+    /// Converts the event into the corresponding [`BeaconConsensusEngineEvent`], if there is one.
+    ///
+    /// Returns `None` for [`Self::PayloadBuffered`] and [`Self::PayloadResolved`].
+    pub fn into_beacon_consensus_event(self) -> Option<BeaconConsensusEngineEvent> {
+        match self {
+            Self::BeaconConsensus(event) => Some(event),
+            Self::ForkchoiceProcessed(state, status) => {
+                Some(BeaconConsensusEngineEvent::ForkchoiceUpdated(state, status))
+            }
+            Self::PayloadBuffered { .. } | Self::PayloadResolved(_) => None,
+        }
+    }
+}
+
+impl From<BeaconConsensusEngineEvent> for EngineApiRequestHandlerEvent {
+    fn from(event: BeaconConsensusEngineEvent) -> Self {
+        match event {
+            BeaconConsensusEngineEvent::ForkchoiceUpdated(state, status) => {
+                Self::ForkchoiceProcessed(state, status)
+            }
+            event => Self::BeaconConsensus(event),
+        }
+    }
+}
+
+impl Display for EngineApiRequestHandlerEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BeaconConsensus(event) => event.fmt(f),
+            Self::PayloadBuffered { block, missing_ancestor } => {
+                write!(f, "PayloadBuffered({block:?}, missing_ancestor: {missing_ancestor:?})")
+            }
+            Self::PayloadResolved(block) => write!(f, "PayloadResolved({block:?})"),
+            Self::ForkchoiceProcessed(state, status) => {
+                write!(f, "ForkchoiceProcessed({state:?}, {status:?})")
+            }
+        }
+    }
 }
 
 /// The type for specifying the kind of engine api.
@@ -278,6 +920,20 @@ pub enum EngineApiEvent {
     BackfillAction(BackfillAction),
     /// Block download is needed.
     Download(DownloadRequest),
+    /// A new payload was buffered because its parent is missing.
+    PayloadBuffered {
+        /// The buffered payload.
+        block: BlockNumHash,
+        /// The lowest ancestor of the payload that is missing.
+        missing_ancestor: BlockNumHash,
+    },
+    /// A buffered block was inserted into the tree after its missing parent was inserted.
+    PayloadResolved(BlockNumHash),
+    /// An unrecoverable error occurred while processing requests.
+    Error(Box<dyn std::error::Error + Send + Sync>),
+    /// All requests that were received before the shutdown was initiated are processed, see
+    /// [`FromOrchestrator::Shutdown`].
+    Shutdown,
 }
 
 impl EngineApiEvent {
@@ -329,20 +985,941 @@ pub enum RequestHandlerEvent<T> {
     HandlerEvent(HandlerEvent<T>),
     /// Request to download blocks.
     Download(DownloadRequest),
+    /// An unrecoverable error occurred in the handler.
+    ///
+    /// The [`EngineHandler`] treats this as a [`HandlerEvent::FatalError`].
+    Error(Box<dyn std::error::Error + Send + Sync>),
 }
 
 /// A request to download blocks from the network.
+///
+/// Consumers should prefer [`DownloadRequest::Bodies`] if the headers of the blocks are already
+/// known, because it doesn't request the headers again. Otherwise, a contiguous chain of blocks
+/// should be requested with [`DownloadRequest::BlockRange`], and individual blocks with
+/// [`DownloadRequest::BlockSet`].
 #[derive(Debug)]
 pub enum DownloadRequest {
     /// Download the given set of blocks.
-    BlockSet(HashSet<B256>),
-    /// Download the given range of blocks.
-    BlockRange(B256, u64),
+    BlockSet(HashSet<B256>, DownloadPriority),
+    /// Download `count` blocks starting at the given hash in the given direction.
+    BlockRange(B256, u64, DownloadDirection, DownloadPriority),
+    /// Download only the bodies of the blocks with the given, already validated, headers.
+    Bodies(Vec<SealedHeader>, DownloadPriority),
 }
 
 impl DownloadRequest {
     /// Returns a [`DownloadRequest`] for a single block.
-    pub fn single_block(hash: B256) -> Self {
-        Self::BlockSet(HashSet::from([hash]))
+    pub fn single_block(hash: B256, priority: DownloadPriority) -> Self {
+        Self::BlockSet(HashSet::from([hash]), priority)
+    }
+
+    /// Returns a [`DownloadRequest`] for `count` blocks, walking the parents of the block with the
+    /// given hash, e.g. to fill the gap below a block.
+    pub const fn descending_range(hash: B256, count: u64, priority: DownloadPriority) -> Self {
+        Self::BlockRange(hash, count, DownloadDirection::Descending, priority)
+    }
+
+    /// Returns the priority of the request.
+    pub const fn priority(&self) -> DownloadPriority {
+        match self {
+            Self::BlockSet(_, priority) |
+            Self::BlockRange(_, _, _, priority) |
+            Self::Bodies(_, priority) => *priority,
+        }
+    }
+}
+
+/// The direction of a [`DownloadRequest::BlockRange`], starting at the requested hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DownloadDirection {
+    /// Download the block with the given hash and its descendants, e.g. to extend a chain above a
+    /// known block.
+    Ascending,
+    /// Download the block with the given hash and its ancestors, e.g. to fill a gap below a block.
+    Descending,
+}
+
+impl From<DownloadDirection> for HeadersDirection {
+    fn from(direction: DownloadDirection) -> Self {
+        match direction {
+            DownloadDirection::Ascending => Self::Rising,
+            DownloadDirection::Descending => Self::Falling,
+        }
+    }
+}
+
+/// The priority of a [`DownloadRequest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DownloadPriority {
+    /// The node can't make progress until the blocks are downloaded, e.g. the head of a forkchoice
+    /// update.
+    Urgent,
+    /// The blocks are not needed right away, e.g. missing ancestors of a buffered block.
+    Background,
+}
+
+impl DownloadPriority {
+    /// Returns `true` if this is [`DownloadPriority::Urgent`].
+    pub const fn is_urgent(&self) -> bool {
+        matches!(self, Self::Urgent)
+    }
+}
+
+impl From<DownloadPriority> for Priority {
+    fn from(priority: DownloadPriority) -> Self {
+        match priority {
+            DownloadPriority::Urgent => Self::High,
+            DownloadPriority::Background => Self::Normal,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::download::DownloadError;
+    use alloy_rpc_types_engine::ExecutionPayloadSidecar;
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder, Snapshotter};
+    use reth_beacon_consensus::BeaconOnNewPayloadError;
+    use reth_engine_primitives::EngineApiMessageVersion;
+    use reth_errors::RethResult;
+    use reth_ethereum_engine_primitives::EthEngineTypes;
+    use reth_primitives::SealedBlock;
+    use reth_rpc_types_compat::engine::block_to_payload_v1;
+    use reth_stages_api::{ControlFlow, PipelineTarget};
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            mpsc::Receiver,
+            Arc,
+        },
+        task::{Wake, Waker},
+    };
+    use tokio::sync::{
+        mpsc::UnboundedSender,
+        oneshot::{self, error::TryRecvError},
+    };
+
+    type TestRequest = BeaconEngineMessage<EthEngineTypes>;
+
+    /// A request handler that records the requests and downloaded blocks and emits the given
+    /// events, it shuts down right away on [`FromOrchestrator::Shutdown`].
+    #[derive(Default)]
+    struct TestRequestHandler {
+        requests: Vec<TestRequest>,
+        downloaded_blocks: Vec<Vec<SealedBlockWithSenders>>,
+        events: VecDeque<RequestHandlerEvent<()>>,
+        /// Whether the handler answers forkchoice updates with `SYNCING`.
+        syncing: bool,
+    }
+
+    impl EngineRequestHandler for TestRequestHandler {
+        type Event = ();
+        type Request = TestRequest;
+
+        fn on_event(&mut self, event: FromEngine<Self::Request>) {
+            match event {
+                FromEngine::Request(request) => self.requests.push(request),
+                FromEngine::DownloadedBlocks(blocks) => self.downloaded_blocks.push(blocks),
+                FromEngine::Event(FromOrchestrator::Shutdown) => {
+                    self.events.push_back(RequestHandlerEvent::HandlerEvent(HandlerEvent::Shutdown))
+                }
+                FromEngine::Event(_) => {}
+            }
+        }
+
+        fn poll(&mut self, _cx: &mut Context<'_>) -> Poll<RequestHandlerEvent<Self::Event>> {
+            self.events.pop_front().map_or(Poll::Pending, Poll::Ready)
+        }
+
+        fn can_commit(&self) -> bool {
+            !self.syncing
+        }
+    }
+
+    /// A downloader that returns the given outcomes and records the requested block sets.
+    #[derive(Default)]
+    struct TestDownloader {
+        outcomes: VecDeque<DownloadOutcome>,
+        requested: Vec<HashSet<B256>>,
+        clears: usize,
+    }
+
+    impl BlockDownloader for TestDownloader {
+        fn on_action(&mut self, action: DownloadAction) {
+            match action {
+                DownloadAction::Clear => self.clears += 1,
+                DownloadAction::Download(DownloadRequest::BlockSet(hashes, _)) => {
+                    self.requested.push(hashes)
+                }
+                DownloadAction::Download(request) => unimplemented!("{request:?}"),
+            }
+        }
+
+        fn poll(&mut self, _cx: &mut Context<'_>) -> Poll<DownloadOutcome> {
+            self.outcomes.pop_front().map_or(Poll::Pending, Poll::Ready)
+        }
+    }
+
+    /// A stand-in for the [`EngineApiTreeHandler`](crate::tree::EngineApiTreeHandler) on the
+    /// other end of the channels of an [`EngineApiRequestHandler`].
+    ///
+    /// The mock records the messages it receives and responds to each of them with the next
+    /// configured event.
+    struct MockEngineApiTreeHandler {
+        from_handler: Receiver<FromEngine<TestRequest>>,
+        to_handler: UnboundedSender<EngineApiEvent>,
+        /// The messages received from the handler, in order.
+        received: VecDeque<FromEngine<TestRequest>>,
+        /// The events that are sent in response to the next received messages.
+        responses: VecDeque<EngineApiEvent>,
+        /// Whether responses are held back until [`Self::release`] is called.
+        slow: bool,
+        /// The responses that are held back.
+        delayed: VecDeque<EngineApiEvent>,
+    }
+
+    impl MockEngineApiTreeHandler {
+        /// Creates the mock and the request handler that is connected to it.
+        fn new() -> (Self, EngineApiRequestHandler<TestRequest>) {
+            let (to_tree, from_handler) = std::sync::mpsc::channel();
+            let (to_handler, from_tree) = tokio::sync::mpsc::unbounded_channel();
+            let tree = Self {
+                from_handler,
+                to_handler,
+                received: VecDeque::new(),
+                responses: VecDeque::new(),
+                slow: false,
+                delayed: VecDeque::new(),
+            };
+            (tree, EngineApiRequestHandler::new(to_tree, from_tree))
+        }
+
+        /// Receives all messages the handler sent and responds to them.
+        fn process(&mut self) {
+            while let Ok(msg) = self.from_handler.try_recv() {
+                self.received.push_back(msg);
+                let Some(response) = self.responses.pop_front() else { continue };
+                if self.slow {
+                    self.delayed.push_back(response);
+                } else {
+                    self.to_handler.send(response).unwrap();
+                }
+            }
+        }
+
+        /// Sends the held back responses, this wakes the handler if it's waiting for events.
+        fn release(&mut self) {
+            for response in self.delayed.drain(..) {
+                self.to_handler.send(response).unwrap();
+            }
+        }
+    }
+
+    /// A waker that counts how often it's woken.
+    #[derive(Default)]
+    struct CountingWaker(AtomicUsize);
+
+    impl CountingWaker {
+        fn count(&self) -> usize {
+            self.0.load(Ordering::SeqCst)
+        }
+    }
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.wake_by_ref()
+        }
+
+        fn wake_by_ref(self: &Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn download_errors_are_skipped() {
+        let block = SealedBlockWithSenders::default();
+        let downloader = TestDownloader {
+            outcomes: VecDeque::from([
+                DownloadOutcome::Error(DownloadError::SenderRecovery {
+                    block: BlockNumHash::default(),
+                }),
+                DownloadOutcome::Blocks(vec![block.clone()]),
+            ]),
+            ..Default::default()
+        };
+        let mut handler = EngineHandler::new(
+            TestRequestHandler::default(),
+            downloader,
+            futures::stream::pending::<TestRequest>(),
+        );
+
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+        assert!(handler.poll(&mut cx).is_pending());
+
+        // the failed download doesn't stop the handler from processing the next outcome
+        assert_eq!(handler.handler_mut().downloaded_blocks, vec![vec![block]]);
+        assert!(handler.downloader.outcomes.is_empty());
+    }
+
+    #[test]
+    fn download_requests_are_deduplicated() {
+        let block = SealedBlockWithSenders::default();
+        let (a, b) = (block.hash(), B256::random());
+        let download = |hash, priority| {
+            RequestHandlerEvent::Download(DownloadRequest::single_block(hash, priority))
+        };
+        let mut handler = EngineHandler::new(
+            TestRequestHandler::default(),
+            TestDownloader::default(),
+            futures::stream::pending::<TestRequest>(),
+        );
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        handler.handler_mut().events.extend([
+            download(a, DownloadPriority::Background),
+            RequestHandlerEvent::Download(DownloadRequest::BlockSet(
+                HashSet::from([a, b]),
+                DownloadPriority::Background,
+            )),
+            download(b, DownloadPriority::Background),
+            // an urgent request restarts the background download
+            download(b, DownloadPriority::Urgent),
+            download(b, DownloadPriority::Urgent),
+        ]);
+        assert!(handler.poll(&mut cx).is_pending());
+        assert_eq!(
+            handler.downloader.requested,
+            [HashSet::from([a]), HashSet::from([b]), HashSet::from([b])]
+        );
+
+        // downloaded blocks are requested again
+        handler.downloader.outcomes.push_back(DownloadOutcome::Blocks(vec![block]));
+        assert!(handler.poll(&mut cx).is_pending());
+        handler.handler_mut().events.push_back(download(a, DownloadPriority::Background));
+        assert!(handler.poll(&mut cx).is_pending());
+        assert_eq!(handler.downloader.requested.len(), 4);
+
+        // cleared downloads are requested again
+        handler.handler_mut().events.extend([
+            RequestHandlerEvent::HandlerEvent(HandlerEvent::BackfillAction(BackfillAction::Start(
+                PipelineTarget::Sync(b),
+            ))),
+            download(b, DownloadPriority::Urgent),
+        ]);
+        assert!(handler.poll(&mut cx).is_ready());
+        assert!(handler.poll(&mut cx).is_pending());
+        assert_eq!(handler.downloader.clears, 1);
+        assert_eq!(handler.downloader.requested.len(), 5);
+        assert_eq!(handler.downloader.requested[4], HashSet::from([b]));
+    }
+
+    #[test]
+    fn open_download_circuit_skips_downloads() {
+        let failed = || {
+            DownloadOutcome::Error(DownloadError::SenderRecovery { block: BlockNumHash::default() })
+        };
+        let downloader =
+            TestDownloader { outcomes: VecDeque::from([failed(), failed()]), ..Default::default() };
+        let mut handler = EngineHandler::new(
+            TestRequestHandler::default(),
+            downloader,
+            futures::stream::pending::<TestRequest>(),
+        )
+        .with_download_circuit_breaker(DownloadCircuitBreaker::new(
+            2,
+            Duration::from_secs(60),
+            Duration::from_secs(60),
+        ));
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        assert!(handler.poll(&mut cx).is_pending());
+        assert_eq!(handler.download_circuit_breaker.state(), CircuitState::Open);
+        // the in-flight downloads are stopped
+        assert_eq!(handler.downloader.clears, 1);
+
+        handler.handler_mut().events.push_back(RequestHandlerEvent::Download(
+            DownloadRequest::single_block(B256::random(), DownloadPriority::Urgent),
+        ));
+        assert!(handler.poll(&mut cx).is_pending());
+        assert!(handler.downloader.requested.is_empty());
+        assert!(handler.inflight_downloads.is_empty());
+    }
+
+    fn new_payload(
+    ) -> (TestRequest, oneshot::Receiver<Result<PayloadStatus, BeaconOnNewPayloadError>>) {
+        new_payload_for(B256::random())
+    }
+
+    fn new_payload_for(
+        block_hash: B256,
+    ) -> (TestRequest, oneshot::Receiver<Result<PayloadStatus, BeaconOnNewPayloadError>>) {
+        let (tx, rx) = oneshot::channel();
+        let block =
+            SealedBlock::new(SealedHeader::new(Default::default(), block_hash), Default::default());
+        let payload = block_to_payload_v1(block).into();
+        (TestRequest::NewPayload { payload, sidecar: ExecutionPayloadSidecar::none(), tx }, rx)
+    }
+
+    fn forkchoice_updated() -> (TestRequest, oneshot::Receiver<RethResult<OnForkChoiceUpdated>>) {
+        forkchoice_updated_to(B256::random())
+    }
+
+    fn forkchoice_updated_to(
+        head_block_hash: B256,
+    ) -> (TestRequest, oneshot::Receiver<RethResult<OnForkChoiceUpdated>>) {
+        let (tx, rx) = oneshot::channel();
+        let request = TestRequest::ForkchoiceUpdated {
+            state: ForkchoiceState { head_block_hash, ..Default::default() },
+            payload_attrs: None,
+            version: EngineApiMessageVersion::default(),
+            tx,
+        };
+        (request, rx)
+    }
+
+    #[test]
+    fn queue_overflow() {
+        let (fcu1, mut fcu1_rx) = forkchoice_updated();
+        let (fcu2, mut fcu2_rx) = forkchoice_updated();
+        let (fcu3, mut fcu3_rx) = forkchoice_updated();
+        let (payload1, mut payload1_rx) = new_payload();
+        let (payload2, mut payload2_rx) = new_payload();
+        let mut handler = EngineHandler::new(
+            TestRequestHandler::default(),
+            TestDownloader::default(),
+            futures::stream::iter([fcu1, payload1, payload2, fcu2, fcu3]),
+        )
+        .with_max_queued_requests(2);
+
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+        assert!(handler.poll(&mut cx).is_pending());
+
+        // the new payload is rejected and the newer forkchoice updates replace the queued ones
+        assert!(payload2_rx.try_recv().unwrap().unwrap().is_syncing());
+        assert!(fcu1_rx.try_recv().unwrap().unwrap().forkchoice_status().is_syncing());
+        assert!(fcu2_rx.try_recv().unwrap().unwrap().forkchoice_status().is_syncing());
+
+        // the queued forkchoice update is delegated before the older payload
+        let requests = &handler.handler_mut().requests;
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].is_forkchoice_update());
+        assert!(!requests[1].is_forkchoice_update());
+        assert_eq!(payload1_rx.try_recv().unwrap_err(), TryRecvError::Empty);
+        assert_eq!(fcu3_rx.try_recv().unwrap_err(), TryRecvError::Empty);
+    }
+
+    #[test]
+    fn queue_overflow_without_queued_forkchoice_update() {
+        let (payload1, mut payload1_rx) = new_payload();
+        let (payload2, mut payload2_rx) = new_payload();
+        let (fcu, mut fcu_rx) = forkchoice_updated();
+        let mut queue = RequestQueue::new(2);
+        assert!(!queue.push(payload1));
+        assert!(!queue.push(payload2));
+
+        // the forkchoice update is never rejected, it replaces the newest payload instead
+        assert!(queue.push(fcu));
+        assert!(payload2_rx.try_recv().unwrap().unwrap().is_syncing());
+        assert_eq!(queue.len(), 2);
+        assert!(!queue.pop().unwrap().0.is_forkchoice_update());
+        assert!(queue.pop().unwrap().0.is_forkchoice_update());
+        assert_eq!(payload1_rx.try_recv().unwrap_err(), TryRecvError::Empty);
+        assert_eq!(fcu_rx.try_recv().unwrap_err(), TryRecvError::Empty);
+    }
+
+    /// Delegates the requests to a handler and returns which of the delegated requests were
+    /// forkchoice updates, in the order they were delegated.
+    fn delegation_order(requests: Vec<TestRequest>) -> Vec<bool> {
+        let mut handler = EngineHandler::new(
+            TestRequestHandler::default(),
+            TestDownloader::default(),
+            futures::stream::iter(requests),
+        );
+
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+        assert!(handler.poll(&mut cx).is_pending());
+        handler.handler_mut().requests.iter().map(EngineRequest::is_forkchoice_update).collect()
+    }
+
+    #[test]
+    fn forkchoice_updates_are_prioritized() {
+        let (payload1, _payload1_rx) = new_payload();
+        let (payload2, _payload2_rx) = new_payload();
+        let (fcu, _fcu_rx) = forkchoice_updated();
+
+        let order = delegation_order(vec![payload1, payload2, fcu]);
+        assert_eq!(order, [true, false, false]);
+    }
+
+    #[test]
+    fn forkchoice_updates_keep_order_of_referenced_payloads() {
+        let (block1, block2) = (B256::random(), B256::random());
+        let (payload1, _payload1_rx) = new_payload_for(block1);
+        let (fcu1, _fcu1_rx) = forkchoice_updated_to(block1);
+        let (payload2, _payload2_rx) = new_payload_for(block2);
+        let (fcu2, _fcu2_rx) = forkchoice_updated_to(block2);
+
+        let order = delegation_order(vec![payload1, fcu1, payload2, fcu2]);
+        assert_eq!(order, [false, true, false, true]);
+
+        // a forkchoice update only moves past the payloads it doesn't reference
+        let (payload1, _payload1_rx) = new_payload_for(block1);
+        let (payload2, _payload2_rx) = new_payload();
+        let (fcu, _fcu_rx) = forkchoice_updated_to(block1);
+
+        let order = delegation_order(vec![payload1, payload2, fcu]);
+        assert_eq!(order, [false, true, false]);
+    }
+
+    #[test]
+    fn requests_are_not_prioritized_while_syncing() {
+        let (payload, _payload_rx) = new_payload();
+        let (fcu, _fcu_rx) = forkchoice_updated();
+        let mut handler = EngineHandler::new(
+            TestRequestHandler { syncing: true, ..Default::default() },
+            TestDownloader::default(),
+            futures::stream::iter([payload, fcu]),
+        );
+
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+        assert!(handler.poll(&mut cx).is_pending());
+        let requests = &handler.handler_mut().requests;
+        assert!(!requests[0].is_forkchoice_update());
+        assert!(requests[1].is_forkchoice_update());
+    }
+
+    #[test]
+    fn request_backpressure() {
+        let (payload1, mut payload1_rx) = new_payload();
+        let (payload2, mut payload2_rx) = new_payload();
+        let (incoming_tx, incoming_rx) = futures::channel::mpsc::unbounded();
+        let mut handler = EngineHandler::new(
+            TestRequestHandler::default(),
+            TestDownloader::default(),
+            incoming_rx,
+        )
+        .with_request_backpressure(1);
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        incoming_tx.unbounded_send(payload1).unwrap();
+        assert!(handler.poll(&mut cx).is_pending());
+        assert_eq!(handler.handler_mut().requests.len(), 1);
+
+        // the payload isn't answered yet, so the next request is not polled
+        incoming_tx.unbounded_send(payload2).unwrap();
+        assert!(handler.poll(&mut cx).is_pending());
+        assert_eq!(handler.handler_mut().requests.len(), 1);
+        assert_eq!(handler.queued_requests.len(), 0);
+
+        // the response is forwarded and the next request is delegated once the payload is answered
+        handler.handler_mut().requests.remove(0).respond_syncing();
+        assert!(handler.poll(&mut cx).is_pending());
+        assert!(payload1_rx.try_recv().unwrap().unwrap().is_syncing());
+        assert_eq!(handler.handler_mut().requests.len(), 1);
+        assert_eq!(payload2_rx.try_recv().unwrap_err(), TryRecvError::Empty);
+    }
+
+    #[test]
+    fn slow_tree_keeps_requests_queued() {
+        let (mut tree, request_handler) = MockEngineApiTreeHandler::new();
+        let (payload1, mut payload1_rx) = new_payload();
+        let (payload2, mut payload2_rx) = new_payload();
+        let (payload3, mut payload3_rx) = new_payload();
+        let mut handler = EngineHandler::new(
+            request_handler,
+            TestDownloader::default(),
+            futures::stream::iter([payload1, payload2, payload3]),
+        )
+        .with_request_backpressure(1)
+        .with_max_queued_requests(2);
+        let waker = Arc::new(CountingWaker::default());
+        let cx_waker = Waker::from(waker.clone());
+        let mut cx = Context::from_waker(&cx_waker);
+
+        // only one payload is sent to the tree, the next one stays queued and the last one doesn't
+        // fit into the queue
+        assert!(handler.poll(&mut cx).is_pending());
+        tree.process();
+        assert_eq!(tree.received.len(), 1);
+        assert_eq!(handler.queued_requests.len(), 1);
+        assert!(payload3_rx.try_recv().unwrap().unwrap().is_syncing());
+
+        // the handler is woken once the tree answers the payload, and sends the queued one
+        let Some(FromEngine::Request(request)) = tree.received.pop_front() else {
+            panic!("expected a request")
+        };
+        let wakes = waker.count();
+        request.respond_syncing();
+        assert!(waker.count() > wakes);
+        assert!(handler.poll(&mut cx).is_pending());
+        assert!(payload1_rx.try_recv().unwrap().unwrap().is_syncing());
+        tree.process();
+        assert_eq!(tree.received.len(), 1);
+        assert!(handler.queued_requests.is_empty());
+        assert_eq!(payload2_rx.try_recv().unwrap_err(), TryRecvError::Empty);
+    }
+
+    #[test]
+    fn drain() {
+        let (payload, mut payload_rx) = new_payload();
+        let (fcu, mut fcu_rx) = forkchoice_updated();
+        let (incoming_tx, incoming_rx) = futures::channel::mpsc::unbounded();
+        let mut handler = EngineHandler::new(
+            TestRequestHandler::default(),
+            TestDownloader::default(),
+            incoming_rx,
+        );
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        incoming_tx.unbounded_send(payload).unwrap();
+        handler.handler_mut().events.push_back(RequestHandlerEvent::Download(
+            DownloadRequest::single_block(B256::random(), DownloadPriority::Urgent),
+        ));
+        assert!(handler.poll(&mut cx).is_pending());
+        assert_eq!(handler.handler_mut().requests.len(), 1);
+        assert_eq!(handler.inflight_downloads.len(), 1);
+
+        handler.begin_drain();
+        assert_eq!(handler.downloader.clears, 1);
+        assert!(handler.inflight_downloads.is_empty());
+
+        // requests that arrive after the drain began are answered with SYNCING
+        incoming_tx.unbounded_send(fcu).unwrap();
+        assert!(matches!(handler.poll(&mut cx), Poll::Ready(HandlerEvent::Shutdown)));
+        assert!(handler.is_drained());
+        assert_eq!(handler.handler_mut().requests.len(), 1);
+        assert_eq!(payload_rx.try_recv().unwrap_err(), TryRecvError::Empty);
+        assert!(fcu_rx.try_recv().unwrap().unwrap().forkchoice_status().is_syncing());
+
+        // also after the drain finished
+        let (payload, mut payload_rx) = new_payload();
+        incoming_tx.unbounded_send(payload).unwrap();
+        assert!(handler.poll(&mut cx).is_pending());
+        assert_eq!(handler.handler_mut().requests.len(), 1);
+        assert!(payload_rx.try_recv().unwrap().unwrap().is_syncing());
+    }
+
+    #[test]
+    fn can_commit() {
+        let (to_tree, _from_handler) = std::sync::mpsc::channel();
+        let (to_handler, from_tree) = tokio::sync::mpsc::unbounded_channel();
+        let mut handler = EngineApiRequestHandler::<()>::new(to_tree, from_tree);
+        assert!(handler.can_commit());
+
+        // the tree requests a backfill sync
+        to_handler
+            .send(EngineApiEvent::BackfillAction(BackfillAction::Start(PipelineTarget::Sync(
+                B256::random(),
+            ))))
+            .unwrap();
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+        assert!(handler.poll(&mut cx).is_ready());
+        assert!(!handler.can_commit());
+
+        handler.on_event(FromEngine::Event(FromOrchestrator::BackfillSyncStarted));
+        assert!(!handler.can_commit());
+
+        handler.on_event(FromEngine::Event(FromOrchestrator::BackfillSyncFinished(
+            ControlFlow::Continue { block_number: 1 },
+        )));
+        assert!(handler.can_commit());
+    }
+
+    #[test]
+    fn forkchoice_update_while_payload_is_pending() {
+        let (mut tree, request_handler) = MockEngineApiTreeHandler::new();
+        tree.slow = true;
+        tree.responses.extend([
+            EngineApiEvent::BeaconConsensus(BeaconConsensusEngineEvent::CanonicalBlockAdded(
+                Arc::new(SealedBlock::default()),
+                Duration::ZERO,
+            )),
+            EngineApiEvent::BeaconConsensus(BeaconConsensusEngineEvent::ForkchoiceUpdated(
+                ForkchoiceState::default(),
+                ForkchoiceStatus::Valid,
+            )),
+        ]);
+        let (incoming_tx, incoming_rx) = futures::channel::mpsc::unbounded();
+        let mut handler =
+            EngineHandler::new(request_handler, TestDownloader::default(), incoming_rx);
+        let waker = Arc::new(CountingWaker::default());
+        let cx_waker = Waker::from(waker.clone());
+        let mut cx = Context::from_waker(&cx_waker);
+
+        let (payload, _payload_rx) = new_payload();
+        incoming_tx.unbounded_send(payload).unwrap();
+        assert!(handler.poll(&mut cx).is_pending());
+        tree.process();
+
+        // the forkchoice update is delegated while the tree is still executing the payload
+        let (fcu, _fcu_rx) = forkchoice_updated();
+        incoming_tx.unbounded_send(fcu).unwrap();
+        assert!(handler.poll(&mut cx).is_pending());
+        tree.process();
+        assert!(matches!(tree.received[0], FromEngine::Request(TestRequest::NewPayload { .. })));
+        assert!(matches!(
+            tree.received[1],
+            FromEngine::Request(TestRequest::ForkchoiceUpdated { .. })
+        ));
+
+        // the handler is woken once the tree responds
+        let wakes = waker.count();
+        tree.release();
+        assert!(waker.count() > wakes);
+        assert!(matches!(
+            handler.poll(&mut cx),
+            Poll::Ready(HandlerEvent::Event(EngineApiRequestHandlerEvent::BeaconConsensus(
+                BeaconConsensusEngineEvent::CanonicalBlockAdded(..)
+            )))
+        ));
+        assert!(matches!(
+            handler.poll(&mut cx),
+            Poll::Ready(HandlerEvent::Event(EngineApiRequestHandlerEvent::ForkchoiceProcessed(
+                _,
+                ForkchoiceStatus::Valid
+            )))
+        ));
+        assert!(handler.poll(&mut cx).is_pending());
+    }
+
+    #[test]
+    fn duplicate_block_hashes_are_downloaded_once() {
+        let (mut tree, request_handler) = MockEngineApiTreeHandler::new();
+        let parent = B256::random();
+        let download_parent = || {
+            EngineApiEvent::Download(DownloadRequest::single_block(
+                parent,
+                DownloadPriority::Urgent,
+            ))
+        };
+        tree.responses.extend([download_parent(), download_parent()]);
+        let (payload1, _payload1_rx) = new_payload();
+        let (payload2, _payload2_rx) = new_payload();
+        let mut handler = EngineHandler::new(
+            request_handler,
+            TestDownloader::default(),
+            futures::stream::iter([payload1, payload2]),
+        );
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        assert!(handler.poll(&mut cx).is_pending());
+        tree.process();
+        let hashes = tree
+            .received
+            .iter()
+            .map(|msg| match msg {
+                FromEngine::Request(TestRequest::NewPayload { payload, .. }) => {
+                    payload.block_hash()
+                }
+                msg => unreachable!("{msg:?}"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(hashes.len(), 2);
+        assert_eq!(hashes[0], hashes[1]);
+
+        // the tree requests the missing parent for both payloads, it's only downloaded once
+        assert!(handler.poll(&mut cx).is_pending());
+        assert_eq!(handler.downloader.requested, [HashSet::from([parent])]);
+        assert_eq!(handler.inflight_downloads.len(), 1);
+    }
+
+    #[test]
+    fn download_then_insert() {
+        let (mut tree, request_handler) = MockEngineApiTreeHandler::new();
+        let block = SealedBlockWithSenders::default();
+        tree.responses.extend([
+            EngineApiEvent::Download(DownloadRequest::single_block(
+                block.hash(),
+                DownloadPriority::Urgent,
+            )),
+            EngineApiEvent::BeaconConsensus(BeaconConsensusEngineEvent::CanonicalBlockAdded(
+                Arc::new(block.block.clone()),
+                Duration::ZERO,
+            )),
+        ]);
+        let (payload, _payload_rx) = new_payload();
+        let mut handler = EngineHandler::new(
+            request_handler,
+            TestDownloader::default(),
+            futures::stream::iter([payload]),
+        );
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        // the tree is missing the block and requests it
+        assert!(handler.poll(&mut cx).is_pending());
+        tree.process();
+        assert!(handler.poll(&mut cx).is_pending());
+        assert_eq!(handler.downloader.requested, [HashSet::from([block.hash()])]);
+
+        // the downloaded block is delegated to the tree, which inserts it
+        handler.downloader.outcomes.push_back(DownloadOutcome::Blocks(vec![block.clone()]));
+        assert!(handler.poll(&mut cx).is_pending());
+        assert!(handler.inflight_downloads.is_empty());
+        tree.process();
+        assert!(matches!(
+            tree.received.back(),
+            Some(FromEngine::DownloadedBlocks(blocks)) if blocks == &[block.clone()]
+        ));
+        match handler.poll(&mut cx) {
+            Poll::Ready(HandlerEvent::Event(EngineApiRequestHandlerEvent::BeaconConsensus(
+                BeaconConsensusEngineEvent::CanonicalBlockAdded(inserted, _),
+            ))) => assert_eq!(inserted.hash(), block.hash()),
+            _ => panic!("expected the block to be inserted"),
+        }
+    }
+
+    #[test]
+    fn payload_buffered_then_resolved() {
+        let (to_tree, _from_handler) = std::sync::mpsc::channel();
+        let (to_handler, from_tree) = tokio::sync::mpsc::unbounded_channel();
+        let mut handler = EngineApiRequestHandler::<()>::new(to_tree, from_tree);
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        let block = BlockNumHash::new(2, B256::random());
+        let missing_ancestor = BlockNumHash::new(1, B256::random());
+
+        // the tree buffers the payload and requests its missing parent
+        to_handler.send(EngineApiEvent::PayloadBuffered { block, missing_ancestor }).unwrap();
+        to_handler
+            .send(EngineApiEvent::Download(DownloadRequest::single_block(
+                missing_ancestor.hash,
+                DownloadPriority::Urgent,
+            )))
+            .unwrap();
+        match handler.poll(&mut cx) {
+            Poll::Ready(RequestHandlerEvent::HandlerEvent(HandlerEvent::Event(
+                EngineApiRequestHandlerEvent::PayloadBuffered {
+                    block: buffered,
+                    missing_ancestor: missing,
+                },
+            ))) => {
+                assert_eq!(buffered, block);
+                assert_eq!(missing, missing_ancestor);
+            }
+            _ => panic!("expected the payload to be buffered"),
+        }
+        assert!(matches!(handler.poll(&mut cx), Poll::Ready(RequestHandlerEvent::Download(_))));
+
+        // the parent is downloaded and the buffered payload is connected
+        to_handler.send(EngineApiEvent::PayloadResolved(block)).unwrap();
+        assert!(matches!(
+            handler.poll(&mut cx),
+            Poll::Ready(RequestHandlerEvent::HandlerEvent(HandlerEvent::Event(
+                EngineApiRequestHandlerEvent::PayloadResolved(resolved),
+            ))) if resolved == block
+        ));
+        assert!(handler.poll(&mut cx).is_pending());
+    }
+
+    #[test]
+    fn forkchoice_processed_event() {
+        let (to_tree, _from_handler) = std::sync::mpsc::channel();
+        let (to_handler, from_tree) = tokio::sync::mpsc::unbounded_channel();
+        let mut handler = EngineApiRequestHandler::<()>::new(to_tree, from_tree);
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+
+        let state = ForkchoiceState { head_block_hash: B256::random(), ..Default::default() };
+        to_handler
+            .send(EngineApiEvent::BeaconConsensus(BeaconConsensusEngineEvent::ForkchoiceUpdated(
+                state,
+                ForkchoiceStatus::Syncing,
+            )))
+            .unwrap();
+        let Poll::Ready(RequestHandlerEvent::HandlerEvent(HandlerEvent::Event(event))) =
+            handler.poll(&mut cx)
+        else {
+            panic!("expected a handler event")
+        };
+        assert!(matches!(
+            event,
+            EngineApiRequestHandlerEvent::ForkchoiceProcessed(processed, ForkchoiceStatus::Syncing)
+                if processed == state
+        ));
+
+        // the event is forwarded to the listeners of the consensus engine events
+        assert!(matches!(
+            event.into_beacon_consensus_event(),
+            Some(BeaconConsensusEngineEvent::ForkchoiceUpdated(processed, _)) if processed == state
+        ));
+    }
+
+    /// Returns the values of all histograms in the snapshot by name.
+    fn histograms(snapshotter: &Snapshotter) -> HashMap<String, Vec<f64>> {
+        snapshotter
+            .snapshot()
+            .into_vec()
+            .into_iter()
+            .filter_map(|(key, _, _, value)| match value {
+                DebugValue::Histogram(values) => Some((
+                    key.key().name().to_string(),
+                    values.into_iter().map(|value| value.into_inner()).collect(),
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn request_handler_metrics() {
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+
+        metrics::with_local_recorder(&recorder, || {
+            let (to_tree, _from_handler) = std::sync::mpsc::channel();
+            let (to_handler, from_tree) = tokio::sync::mpsc::unbounded_channel();
+            let mut handler = EngineApiRequestHandler::<()>::new(to_tree, from_tree);
+
+            to_handler
+                .send(EngineApiEvent::BeaconConsensus(
+                    BeaconConsensusEngineEvent::CanonicalBlockAdded(
+                        Arc::new(SealedBlock::default()),
+                        Duration::from_millis(100),
+                    ),
+                ))
+                .unwrap();
+            to_handler
+                .send(EngineApiEvent::BeaconConsensus(
+                    BeaconConsensusEngineEvent::CanonicalChainCommitted(
+                        Box::new(SealedHeader::default()),
+                        Duration::from_millis(20),
+                    ),
+                ))
+                .unwrap();
+
+            let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+            assert!(handler.poll(&mut cx).is_ready());
+            assert!(handler.poll(&mut cx).is_ready());
+        });
+
+        let histograms = histograms(&snapshotter);
+        assert_eq!(
+            histograms["consensus.engine.api_request_handler.forkchoice_commit_duration"],
+            vec![0.02]
+        );
+        assert_eq!(histograms.len(), 1);
+    }
+
+    #[test]
+    fn queue_wait_metrics() {
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+
+        metrics::with_local_recorder(&recorder, || {
+            let (fcu, _fcu_rx) = forkchoice_updated();
+            let (payload, _payload_rx) = new_payload();
+            let mut handler = EngineHandler::new(
+                TestRequestHandler::default(),
+                TestDownloader::default(),
+                futures::stream::iter([payload, fcu]),
+            );
+
+            let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+            assert!(handler.poll(&mut cx).is_pending());
+            assert_eq!(handler.handler_mut().requests.len(), 2);
+        });
+
+        let histograms = histograms(&snapshotter);
+        assert_eq!(histograms["consensus.engine.handler.request_queue_wait_duration"].len(), 2);
     }
 }
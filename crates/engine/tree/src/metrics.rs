@@ -1,8 +1,46 @@
 use reth_metrics::{
-    metrics::{Gauge, Histogram},
+    metrics::{Counter, Gauge, Histogram},
     Metrics,
 };
 
+/// Metrics for the `EngineHandler`.
+#[derive(Metrics)]
+#[metrics(scope = "consensus.engine.handler")]
+pub(crate) struct EngineHandlerMetrics {
+    /// The total count of requests received from the consensus layer.
+    pub(crate) incoming_requests: Counter,
+    /// The total count of download requests sent to the downloader.
+    pub(crate) download_requests: Counter,
+    /// The total count of downloaded block batches delegated to the request handler.
+    pub(crate) downloaded_block_batches: Counter,
+    /// The total count of failed block downloads.
+    pub(crate) failed_downloads: Counter,
+    /// The state of the download circuit breaker: 0 if closed, 1 if half-open and 2 if open.
+    pub(crate) download_circuit_state: Gauge,
+    /// The total count of download requests that were skipped because the download circuit was
+    /// open.
+    pub(crate) skipped_download_requests: Counter,
+    /// The number of incoming requests that are queued.
+    pub(crate) queued_requests: Gauge,
+    /// The number of delegated requests that the request handler hasn't answered yet.
+    pub(crate) pending_requests: Gauge,
+    /// The total count of incoming requests that were answered with `SYNCING` because the queue
+    /// was full.
+    pub(crate) dropped_requests: Counter,
+    /// Histogram of the time (in seconds) incoming requests were queued before they were
+    /// delegated to the request handler.
+    pub(crate) request_queue_wait_duration: Histogram,
+}
+
+/// Metrics for the `EngineApiRequestHandler`.
+#[derive(Metrics)]
+#[metrics(scope = "consensus.engine.api_request_handler")]
+pub(crate) struct EngineApiRequestHandlerMetrics {
+    /// Histogram of the time (in seconds) it took the tree to commit the canonical chain after a
+    /// forkchoice update.
+    pub(crate) forkchoice_commit_duration: Histogram,
+}
+
 /// Metrics for the `BasicBlockDownloader`.
 #[derive(Metrics)]
 #[metrics(scope = "consensus.engine.beacon")]
@@ -33,6 +33,12 @@ pub(crate) struct EngineMetrics {
     pub(crate) forkchoice_updated_messages: Counter,
     /// The total count of new payload messages received.
     pub(crate) new_payload_messages: Counter,
+    /// Histogram of forkchoice updated message processing durations (in seconds), from receiving
+    /// the message until the response is sent.
+    pub(crate) forkchoice_updated_duration: Histogram,
+    /// Histogram of new payload message processing durations (in seconds), from receiving the
+    /// message until the response is sent.
+    pub(crate) new_payload_duration: Histogram,
     /// Histogram of persistence operation durations (in seconds)
     pub(crate) persistence_duration: Histogram,
     /// Tracks the how often we failed to deliver a newPayload response.
@@ -43,7 +49,6 @@ pub(crate) struct EngineMetrics {
     pub(crate) failed_new_payload_response_deliveries: Counter,
     /// Tracks the how often we failed to deliver a forkchoice update response.
     pub(crate) failed_forkchoice_updated_response_deliveries: Counter,
-    // TODO add latency metrics
 }
 
 /// Metrics for non-execution related block validation.
@@ -1,7 +1,7 @@
 use crate::{
     backfill::{BackfillAction, BackfillSyncState},
     chain::FromOrchestrator,
-    engine::{DownloadRequest, EngineApiEvent, FromEngine},
+    engine::{DownloadPriority, DownloadRequest, EngineApiEvent, FromEngine},
     persistence::PersistenceHandle,
 };
 use alloy_eips::BlockNumHash;
@@ -395,6 +395,9 @@ pub struct EngineApiTreeState {
     /// Tracks the header of invalid payloads that were rejected by the engine because they're
     /// invalid.
     invalid_headers: InvalidHeaderCache,
+    /// Hashes of the new payloads that were buffered because their parent is missing, and that
+    /// weren't inserted yet.
+    buffered_payloads: HashSet<B256>,
 }
 
 impl EngineApiTreeState {
@@ -408,6 +411,7 @@ impl EngineApiTreeState {
             buffer: BlockBuffer::new(block_buffer_limit),
             tree_state: TreeState::new(canonical_block),
             forkchoice_state_tracker: ForkchoiceStateTracker::default(),
+            buffered_payloads: HashSet::default(),
         }
     }
 }
@@ -492,6 +496,8 @@ pub struct EngineApiTreeHandler<P, E, T: EngineTypes, Spec> {
     persistence_state: PersistenceState,
     /// Flag indicating the state of the node's backfill synchronization process.
     backfill_sync_state: BackfillSyncState,
+    /// Whether the node is shutting down. Requests are no longer processed if set.
+    is_shutting_down: bool,
     /// Keeps track of the state of the canonical chain that isn't persisted yet.
     /// This is intended to be accessed from external sources, such as rpc.
     canonical_in_memory_state: CanonicalInMemoryState,
@@ -522,6 +528,7 @@ impl<P: Debug, E: Debug, T: EngineTypes + Debug, Spec: Debug> std::fmt::Debug
             .field("persistence", &self.persistence)
             .field("persistence_state", &self.persistence_state)
             .field("backfill_sync_state", &self.backfill_sync_state)
+            .field("is_shutting_down", &self.is_shutting_down)
             .field("canonical_in_memory_state", &self.canonical_in_memory_state)
             .field("payload_builder", &self.payload_builder)
             .field("config", &self.config)
@@ -568,6 +575,7 @@ where
             persistence,
             persistence_state,
             backfill_sync_state: BackfillSyncState::Idle,
+            is_shutting_down: false,
             state,
             canonical_in_memory_state,
             payload_builder,
@@ -653,6 +661,7 @@ where
                     debug!(target: "engine::tree", %msg, "received new engine message");
                     if let Err(fatal) = self.on_engine_message(msg) {
                         error!(target: "engine::tree", %fatal, "insert block fatal error");
+                        self.emit_event(EngineApiEvent::Error(Box::new(fatal)));
                         return
                     }
                 }
@@ -667,6 +676,7 @@ where
 
             if let Err(err) = self.advance_persistence() {
                 error!(target: "engine::tree", %err, "Advancing persistence failed");
+                self.emit_event(EngineApiEvent::Error(Box::new(err)));
                 return
             }
         }
@@ -803,7 +813,14 @@ where
                             latest_valid_hash = Some(block_hash);
                             PayloadStatusEnum::Valid
                         }
-                        InsertPayloadOk2::Inserted(BlockStatus2::Disconnected { .. }) |
+                        InsertPayloadOk2::Inserted(BlockStatus2::Disconnected {
+                            missing_ancestor,
+                            ..
+                        }) => {
+                            self.on_payload_buffered(num_hash, missing_ancestor);
+                            // not known to be invalid, but we don't know anything else
+                            PayloadStatusEnum::Syncing
+                        }
                         InsertPayloadOk2::AlreadySeen(BlockStatus2::Disconnected { .. }) => {
                             // not known to be invalid, but we don't know anything else
                             PayloadStatusEnum::Syncing
@@ -1098,7 +1115,28 @@ where
         Ok(TreeOutcome::new(OnForkChoiceUpdated::valid(PayloadStatus::from_status(
             PayloadStatusEnum::Syncing,
         )))
-        .with_event(TreeEvent::Download(DownloadRequest::single_block(target))))
+        .with_event(TreeEvent::Download(
+            self.missing_block_download_request(target, DownloadPriority::Urgent),
+        )))
+    }
+
+    /// Returns the [`DownloadRequest`] for a single missing block.
+    ///
+    /// If the header of the block is already stored, but its body is not, e.g. because backfill
+    /// sync was interrupted after downloading the headers, only the body is requested with
+    /// [`DownloadRequest::Bodies`].
+    fn missing_block_download_request(
+        &self,
+        hash: B256,
+        priority: DownloadPriority,
+    ) -> DownloadRequest {
+        match self.provider.sealed_header_by_hash(hash) {
+            Ok(Some(header)) if matches!(self.provider.block_by_hash(hash), Ok(None)) => {
+                trace!(target: "engine::tree", %hash, "downloading body of known header");
+                DownloadRequest::Bodies(vec![header], priority)
+            }
+            _ => DownloadRequest::single_block(hash, priority),
+        }
     }
 
     /// Attempts to receive the next engine request.
@@ -1200,7 +1238,19 @@ where
                 FromOrchestrator::BackfillSyncFinished(ctrl) => {
                     self.on_backfill_sync_finished(ctrl)?;
                 }
+                FromOrchestrator::Shutdown => {
+                    debug!(target: "engine::tree", "received shutdown event");
+                    self.is_shutting_down = true;
+                    // all messages that were received before are processed
+                    self.emit_event(EngineApiEvent::Shutdown);
+                }
             },
+            FromEngine::Request(request) if self.is_shutting_down => {
+                self.on_request_while_shutting_down(request);
+            }
+            FromEngine::DownloadedBlocks(blocks) if self.is_shutting_down => {
+                debug!(target: "engine::tree", count = blocks.len(), "skipping downloaded blocks while shutting down");
+            }
             FromEngine::Request(request) => {
                 match request {
                     EngineApiRequest::InsertExecutedBlock(block) => {
@@ -1216,6 +1266,7 @@ where
                                 tx,
                                 version,
                             } => {
+                                let start = Instant::now();
                                 let mut output =
                                     self.on_forkchoice_updated(state, payload_attrs, version);
 
@@ -1235,6 +1286,10 @@ where
                                     self.on_maybe_tree_event(res.event.take())?;
                                 }
 
+                                self.metrics
+                                    .engine
+                                    .forkchoice_updated_duration
+                                    .record(start.elapsed());
                                 if let Err(err) =
                                     tx.send(output.map(|o| o.outcome).map_err(Into::into))
                                 {
@@ -1246,7 +1301,9 @@ where
                                 }
                             }
                             BeaconEngineMessage::NewPayload { payload, sidecar, tx } => {
+                                let start = Instant::now();
                                 let output = self.on_new_payload(payload, sidecar);
+                                self.metrics.engine.new_payload_duration.record(start.elapsed());
                                 if let Err(err) = tx.send(output.map(|o| o.outcome).map_err(|e| {
                                     reth_beacon_consensus::BeaconOnNewPayloadError::Internal(
                                         Box::new(e),
@@ -1278,6 +1335,22 @@ where
         Ok(())
     }
 
+    /// Answers a request that is received while shutting down, without processing it.
+    ///
+    /// Payloads and forkchoice updates are answered with `SYNCING`.
+    fn on_request_while_shutting_down(&self, request: EngineApiRequest<T>) {
+        match request {
+            EngineApiRequest::Beacon(BeaconEngineMessage::ForkchoiceUpdated { tx, .. }) => {
+                let _ = tx.send(Ok(OnForkChoiceUpdated::syncing()));
+            }
+            EngineApiRequest::Beacon(BeaconEngineMessage::NewPayload { tx, .. }) => {
+                let _ = tx.send(Ok(PayloadStatus::from_status(PayloadStatusEnum::Syncing)));
+            }
+            EngineApiRequest::Beacon(BeaconEngineMessage::TransitionConfigurationExchanged) |
+            EngineApiRequest::InsertExecutedBlock(_) => {}
+        }
+    }
+
     /// Invoked if the backfill sync has finished to target.
     ///
     /// At this point we consider the block synced to the backfill target.
@@ -1767,6 +1840,9 @@ where
             match self.insert_block(child) {
                 Ok(res) => {
                     debug!(target: "engine::tree", child =?child_num_hash, ?res, "connected buffered block");
+                    if self.state.buffered_payloads.remove(&child_num_hash.hash) {
+                        self.emit_event(EngineApiEvent::PayloadResolved(child_num_hash));
+                    }
                     if self.is_sync_target_head(child_num_hash.hash) &&
                         matches!(res, InsertPayloadOk2::Inserted(BlockStatus2::Valid))
                     {
@@ -1787,6 +1863,18 @@ where
         Ok(())
     }
 
+    /// Tracks a new payload that was buffered because its parent is missing, so that
+    /// [`EngineApiEvent::PayloadResolved`] is emitted once it is connected.
+    fn on_payload_buffered(&mut self, block: BlockNumHash, missing_ancestor: BlockNumHash) {
+        let EngineApiTreeState { buffer, buffered_payloads, .. } = &mut self.state;
+        if buffered_payloads.len() >= self.config.block_buffer_limit() as usize {
+            // forget the payloads that were evicted from the buffer
+            buffered_payloads.retain(|hash| buffer.block(hash).is_some());
+        }
+        buffered_payloads.insert(block.hash);
+        self.emit_event(EngineApiEvent::PayloadBuffered { block, missing_ancestor });
+    }
+
     /// Attempts to recover the block's senders and then buffers it.
     ///
     /// Returns an error if sender recovery failed or inserting into the buffer failed.
@@ -2039,12 +2127,18 @@ where
             self.distance_from_local_tip(head.number, missing_parent.number)
         {
             trace!(target: "engine::tree", %distance, missing=?missing_parent, "downloading missing parent block range");
-            DownloadRequest::BlockRange(missing_parent.hash, distance)
+            // the missing parent is above the local tip, so we fill the gap by walking its
+            // ancestors down to the local tip
+            DownloadRequest::descending_range(
+                missing_parent.hash,
+                distance,
+                DownloadPriority::Background,
+            )
         } else {
             trace!(target: "engine::tree", missing=?missing_parent, "downloading missing parent block");
             // This happens when the missing parent is on an outdated
             // sidechain and we can only download the missing block itself
-            DownloadRequest::single_block(missing_parent.hash)
+            self.missing_block_download_request(missing_parent.hash, DownloadPriority::Background)
         };
 
         Some(TreeEvent::Download(request))
@@ -2596,7 +2690,7 @@ pub enum AdvancePersistenceError {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::persistence::PersistenceAction;
+    use crate::{engine::DownloadDirection, persistence::PersistenceAction};
     use alloy_primitives::{Bytes, Sealable};
     use alloy_rlp::Decodable;
     use alloy_rpc_types_engine::{CancunPayloadFields, ExecutionPayloadSidecar};
@@ -2937,6 +3031,26 @@ mod tests {
             }
         }
 
+        async fn check_payload_buffered(&mut self, expected_hash: B256) {
+            let event = self.from_tree_rx.recv().await.unwrap();
+            match event {
+                EngineApiEvent::PayloadBuffered { block, .. } => {
+                    assert_eq!(block.hash, expected_hash);
+                }
+                _ => panic!("Unexpected event: {:#?}", event),
+            }
+        }
+
+        async fn check_payload_resolved(&mut self, expected_hash: B256) {
+            let event = self.from_tree_rx.recv().await.unwrap();
+            match event {
+                EngineApiEvent::PayloadResolved(block) => {
+                    assert_eq!(block.hash, expected_hash);
+                }
+                _ => panic!("Unexpected event: {:#?}", event),
+            }
+        }
+
         async fn check_fork_block_added(&mut self, expected_hash: B256) {
             let event = self.from_tree_rx.recv().await.unwrap();
             match event {
@@ -3153,6 +3267,14 @@ mod tests {
         // ensure block is buffered
         let buffered = test_harness.tree.state.buffer.block(&hash).unwrap();
         assert_eq!(buffered.block, sealed);
+
+        assert_matches!(
+            test_harness.from_tree_rx.try_recv(),
+            Ok(EngineApiEvent::PayloadBuffered { block, missing_ancestor }) => {
+                assert_eq!(block, sealed.num_hash());
+                assert_eq!(missing_ancestor, sealed.parent_num_hash());
+            }
+        );
     }
 
     #[test]
@@ -3202,6 +3324,32 @@ mod tests {
         assert!(resp.is_syncing());
     }
 
+    #[tokio::test]
+    async fn test_shutdown_answers_requests_with_syncing() {
+        let mut test_harness = TestHarness::new(MAINNET.clone());
+
+        test_harness.tree.on_engine_message(FromEngine::Event(FromOrchestrator::Shutdown)).unwrap();
+        assert_matches!(test_harness.from_tree_rx.recv().await, Some(EngineApiEvent::Shutdown));
+
+        // requests after the shutdown are not processed
+        test_harness.send_fcu(B256::random(), ForkchoiceStatus::Syncing).await;
+        assert!(test_harness.from_tree_rx.try_recv().is_err());
+
+        let (tx, rx) = oneshot::channel();
+        test_harness
+            .tree
+            .on_engine_message(FromEngine::Request(
+                BeaconEngineMessage::NewPayload {
+                    payload: block_to_payload_v1(SealedBlock::default()).into(),
+                    sidecar: ExecutionPayloadSidecar::none(),
+                    tx,
+                }
+                .into(),
+            ))
+            .unwrap();
+        assert!(rx.await.unwrap().unwrap().is_syncing());
+    }
+
     #[tokio::test]
     async fn test_tree_state_insert_executed() {
         let mut tree_state = TreeState::new(BlockNumHash::default());
@@ -3609,7 +3757,10 @@ mod tests {
         // after FCU we receive an EngineApiEvent::Download event to get the missing block.
         let event = test_harness.from_tree_rx.recv().await.unwrap();
         match event {
-            EngineApiEvent::Download(DownloadRequest::BlockSet(actual_block_set)) => {
+            EngineApiEvent::Download(DownloadRequest::BlockSet(
+                actual_block_set,
+                DownloadPriority::Urgent,
+            )) => {
                 let expected_block_set = HashSet::from_iter([missing_block.hash()]);
                 assert_eq!(actual_block_set, expected_block_set);
             }
@@ -3617,6 +3768,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_missing_block_download_request() {
+        let mut test_harness = TestHarness::new(MAINNET.clone());
+
+        let unknown = test_harness.block_builder.generate_random_block(1, B256::random());
+        assert!(matches!(
+            test_harness
+                .tree
+                .missing_block_download_request(unknown.hash(), DownloadPriority::Urgent),
+            DownloadRequest::BlockSet(hashes, DownloadPriority::Urgent)
+                if hashes == HashSet::from([unknown.hash()])
+        ));
+
+        // only the body is requested if the header is already stored
+        let header_only = test_harness.block_builder.generate_random_block(2, B256::random());
+        test_harness.provider.add_header(header_only.hash(), header_only.header.header().clone());
+        match test_harness
+            .tree
+            .missing_block_download_request(header_only.hash(), DownloadPriority::Background)
+        {
+            DownloadRequest::Bodies(headers, DownloadPriority::Background) => {
+                assert_eq!(headers, vec![header_only.header.clone()]);
+            }
+            request => panic!("Unexpected request: {request:#?}"),
+        }
+
+        // the whole block is requested if the body is stored as well
+        test_harness.provider.add_block(header_only.hash(), header_only.block.clone().unseal());
+        assert!(matches!(
+            test_harness
+                .tree
+                .missing_block_download_request(header_only.hash(), DownloadPriority::Background),
+            DownloadRequest::BlockSet(..)
+        ));
+    }
+
     #[tokio::test]
     async fn test_engine_tree_fcu_canon_chain_insertion() {
         let chain_spec = MAINNET.clone();
@@ -3704,7 +3891,7 @@ mod tests {
 
         let event = test_harness.from_tree_rx.recv().await.unwrap();
         match event {
-            EngineApiEvent::Download(DownloadRequest::BlockSet(hash_set)) => {
+            EngineApiEvent::Download(DownloadRequest::BlockSet(hash_set, _)) => {
                 assert_eq!(hash_set, HashSet::from_iter([main_chain_last_hash]));
             }
             _ => panic!("Unexpected event: {:#?}", event),
@@ -3720,7 +3907,12 @@ mod tests {
 
         let event = test_harness.from_tree_rx.recv().await.unwrap();
         match event {
-            EngineApiEvent::Download(DownloadRequest::BlockRange(initial_hash, total_blocks)) => {
+            EngineApiEvent::Download(DownloadRequest::BlockRange(
+                initial_hash,
+                total_blocks,
+                DownloadDirection::Descending,
+                _,
+            )) => {
                 assert_eq!(
                     total_blocks,
                     (main_chain.len() - backfill_finished_block_number as usize - 1) as u64
@@ -3767,7 +3959,7 @@ mod tests {
         // check download request for target
         let event = test_harness.from_tree_rx.recv().await.unwrap();
         match event {
-            EngineApiEvent::Download(DownloadRequest::BlockSet(hash_set)) => {
+            EngineApiEvent::Download(DownloadRequest::BlockSet(hash_set, _)) => {
                 assert_eq!(hash_set, HashSet::from_iter([main_chain_backfill_target_hash]));
             }
             _ => panic!("Unexpected event: {:#?}", event),
@@ -3812,7 +4004,7 @@ mod tests {
 
         let event = test_harness.from_tree_rx.recv().await.unwrap();
         match event {
-            EngineApiEvent::Download(DownloadRequest::BlockSet(target_hash)) => {
+            EngineApiEvent::Download(DownloadRequest::BlockSet(target_hash, _)) => {
                 assert_eq!(target_hash, HashSet::from_iter([main_chain_last_hash]));
             }
             _ => panic!("Unexpected event: {:#?}", event),
@@ -3827,7 +4019,12 @@ mod tests {
         // check download range request
         let event = test_harness.from_tree_rx.recv().await.unwrap();
         match event {
-            EngineApiEvent::Download(DownloadRequest::BlockRange(initial_hash, total_blocks)) => {
+            EngineApiEvent::Download(DownloadRequest::BlockRange(
+                initial_hash,
+                total_blocks,
+                DownloadDirection::Descending,
+                _,
+            )) => {
                 assert_eq!(
                     total_blocks,
                     (main_chain.len() - MIN_BLOCKS_FOR_PIPELINE_RUN as usize - 2) as u64
@@ -3981,6 +4178,7 @@ mod tests {
         test_harness.send_new_payload(buffered_block.clone()).await;
 
         assert!(test_harness.tree.state.buffer.block(&buffered_block_hash).is_some());
+        test_harness.check_payload_buffered(buffered_block_hash).await;
 
         let non_buffered_block = side_chain.first().unwrap();
         let non_buffered_block_hash = non_buffered_block.hash();
@@ -3996,6 +4194,7 @@ mod tests {
         // both blocks are added to the canon chain in order
         test_harness.check_canon_block_added(non_buffered_block_hash).await;
         test_harness.check_canon_block_added(buffered_block_hash).await;
+        test_harness.check_payload_resolved(buffered_block_hash).await;
     }
 
     #[tokio::test]
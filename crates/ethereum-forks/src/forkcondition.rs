@@ -1,9 +1,19 @@
 use crate::Head;
+use alloc::string::{String, ToString};
 use alloy_primitives::{BlockNumber, U256};
+use core::str::FromStr;
 
 /// The condition at which a fork is activated.
+///
+/// With the `serde` feature, a condition is (de)serialized as `{"block": 15537394}`,
+/// `{"timestamp": 1681338455}`, `{"ttd": "58750000000000000000000", "n": 15537394}` or `"never"`.
+/// The terminal total difficulty is a decimal string and its fork block `n` is optional.
+///
+/// The externally tagged format of earlier versions, e.g. `{"Block": 15537394}` or `"Never"`, is
+/// still accepted when deserializing.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "ForkConditionFormat", into = "ForkConditionRepr"))]
 pub enum ForkCondition {
     /// The fork is activated after a certain block.
     Block(BlockNumber),
@@ -27,6 +37,29 @@ pub enum ForkCondition {
 }
 
 impl ForkCondition {
+    /// Returns a block based fork condition, or [`ForkCondition::Never`] if the fork is disabled,
+    /// i.e. `block` is `None`.
+    pub const fn block_or_never(block: Option<BlockNumber>) -> Self {
+        match block {
+            Some(block) => Self::Block(block),
+            None => Self::Never,
+        }
+    }
+
+    /// Returns a timestamp based fork condition, or [`ForkCondition::Never`] if the fork is
+    /// disabled, i.e. `timestamp` is `None`.
+    pub const fn timestamp_or_never(timestamp: Option<u64>) -> Self {
+        match timestamp {
+            Some(timestamp) => Self::Timestamp(timestamp),
+            None => Self::Never,
+        }
+    }
+
+    /// Returns true if the fork is never activated.
+    pub const fn is_never(&self) -> bool {
+        matches!(self, Self::Never)
+    }
+
     /// Returns true if the fork condition is timestamp based.
     pub const fn is_timestamp(&self) -> bool {
         matches!(self, Self::Timestamp(_))
@@ -90,10 +123,72 @@ impl ForkCondition {
             self.active_at_ttd(head.total_difficulty, head.difficulty)
     }
 
+    /// Checks whether the fork condition is satisfied at the given block number, timestamp and
+    /// total difficulty.
+    ///
+    /// TTD based conditions are satisfied once the total difficulty reaches the terminal total
+    /// difficulty, or once the fork block is reached if it is known.
+    pub fn active_at(&self, block: BlockNumber, timestamp: u64, total_difficulty: U256) -> bool {
+        match self {
+            Self::Block(_) => self.active_at_block(block),
+            Self::TTD { total_difficulty: ttd, .. } => {
+                self.active_at_block(block) || total_difficulty >= *ttd
+            }
+            Self::Timestamp(_) => self.active_at_timestamp(timestamp),
+            Self::Never => false,
+        }
+    }
+
+    /// Checks whether the fork condition is satisfied at the head with the given block number,
+    /// timestamp and, if known, total difficulty.
+    ///
+    /// Like the activation of Paris, TTD based conditions are only satisfied if the total
+    /// difficulty reached the terminal total difficulty _and_ the fork block, if known, is reached.
+    /// If the total difficulty is unknown, the condition is satisfied only if the fork block is
+    /// known and reached.
+    ///
+    /// This will return false for [`ForkCondition::Never`].
+    pub fn is_active_at_head(
+        &self,
+        block_number: BlockNumber,
+        timestamp: u64,
+        total_difficulty: Option<U256>,
+    ) -> bool {
+        match self {
+            Self::Block(_) => self.active_at_block(block_number),
+            Self::Timestamp(_) => self.active_at_timestamp(timestamp),
+            Self::TTD { fork_block, .. } => {
+                let ttd_reached = total_difficulty.map_or(fork_block.is_some(), |difficulty| {
+                    self.active_at_ttd(difficulty, U256::ZERO)
+                });
+                ttd_reached && (fork_block.is_none() || self.active_at_block(block_number))
+            }
+            Self::Never => false,
+        }
+    }
+
     /// Get the total terminal difficulty for this fork condition.
     ///
-    /// Returns `None` for fork conditions that are not TTD based.
+    /// Returns `None` for fork conditions that are not TTD based, see [`Self::as_ttd`].
     pub const fn ttd(&self) -> Option<U256> {
+        self.as_ttd()
+    }
+
+    /// Returns the block number of the fork condition, if it is block based.
+    ///
+    /// Returns `None` for all other conditions, including TTD based conditions with a known fork
+    /// block.
+    pub const fn as_block(&self) -> Option<BlockNumber> {
+        match self {
+            Self::Block(block) => Some(*block),
+            _ => None,
+        }
+    }
+
+    /// Returns the terminal total difficulty of the fork condition, if it is TTD based.
+    ///
+    /// Returns `None` for all other conditions.
+    pub const fn as_ttd(&self) -> Option<U256> {
         match self {
             Self::TTD { total_difficulty, .. } => Some(*total_difficulty),
             _ => None,
@@ -101,6 +196,8 @@ impl ForkCondition {
     }
 
     /// Returns the timestamp of the fork condition, if it is timestamp based.
+    ///
+    /// Returns `None` for all other conditions, e.g. a block based fork has no timestamp.
     pub const fn as_timestamp(&self) -> Option<u64> {
         match self {
             Self::Timestamp(timestamp) => Some(*timestamp),
@@ -109,11 +206,237 @@ impl ForkCondition {
     }
 }
 
+impl core::fmt::Display for ForkCondition {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Block(block) => write!(f, "block {block}"),
+            Self::TTD { fork_block: Some(block), total_difficulty } => {
+                write!(f, "ttd {total_difficulty} (block {block})")
+            }
+            Self::TTD { fork_block: None, total_difficulty } => write!(f, "ttd {total_difficulty}"),
+            Self::Timestamp(timestamp) => write!(f, "timestamp {timestamp}"),
+            Self::Never => f.write_str("never"),
+        }
+    }
+}
+
+/// Parses a fork condition of the form `block:<number>`, `timestamp:<seconds>`,
+/// `ttd:<total difficulty>` or `never`, e.g. `block:12965000`.
+///
+/// The terminal total difficulty is a decimal number, its fork block is unknown.
+impl FromStr for ForkCondition {
+    type Err = ParseForkConditionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("never") {
+            return Ok(Self::Never)
+        }
+
+        let invalid_format = || ParseForkConditionError::InvalidFormat(s.to_string());
+        let (kind, value) = s.split_once(':').ok_or_else(invalid_format)?;
+        let invalid_number = |kind, expected| ParseForkConditionError::InvalidNumber {
+            kind,
+            value: value.to_string(),
+            expected,
+        };
+        match kind.to_ascii_lowercase().as_str() {
+            "block" => value.parse().map(Self::Block).map_err(|_| invalid_number("block", "u64")),
+            "timestamp" => {
+                value.parse().map(Self::Timestamp).map_err(|_| invalid_number("timestamp", "u64"))
+            }
+            // `from_str_radix` accepts an empty string as zero
+            "ttd" if !value.is_empty() => U256::from_str_radix(value, 10)
+                .map(|total_difficulty| Self::TTD { fork_block: None, total_difficulty })
+                .map_err(|_| invalid_number("ttd", "u256")),
+            "ttd" => Err(invalid_number("ttd", "u256")),
+            _ => Err(invalid_format()),
+        }
+    }
+}
+
+/// Error returned when parsing a [`ForkCondition`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror_no_std::Error)]
+pub enum ParseForkConditionError {
+    /// The string is not of the form `<kind>:<value>` with a known kind, or `never`.
+    #[error(
+        "invalid fork condition {0:?}, expected block:<number>, timestamp:<seconds>, ttd:<total \
+         difficulty> or never"
+    )]
+    InvalidFormat(String),
+    /// The value is not a decimal number of the expected type.
+    #[error("invalid {kind} {value:?}, expected a decimal {expected}")]
+    InvalidNumber {
+        /// The kind of the fork condition.
+        kind: &'static str,
+        /// The value that failed to parse.
+        value: String,
+        /// The expected number type.
+        expected: &'static str,
+    },
+}
+
+/// The serialized form of a [`ForkCondition`].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(untagged, deny_unknown_fields)]
+enum ForkConditionRepr {
+    Block {
+        block: BlockNumber,
+    },
+    Timestamp {
+        timestamp: u64,
+    },
+    Ttd {
+        #[serde(with = "ttd_decimal")]
+        ttd: U256,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        n: Option<BlockNumber>,
+    },
+    Never(NeverTag),
+}
+
+/// Serializes [`ForkCondition::Never`] as `"never"`.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum NeverTag {
+    Never,
+}
+
+/// The externally tagged serialized form of a [`ForkCondition`] of earlier versions.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+enum LegacyForkConditionRepr {
+    Block(BlockNumber),
+    #[serde(rename = "TTD")]
+    Ttd {
+        fork_block: Option<BlockNumber>,
+        total_difficulty: U256,
+    },
+    Timestamp(u64),
+    Never,
+}
+
+/// Any of the accepted serialized forms of a [`ForkCondition`].
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum ForkConditionFormat {
+    Repr(ForkConditionRepr),
+    Legacy(LegacyForkConditionRepr),
+}
+
+#[cfg(feature = "serde")]
+impl From<ForkConditionFormat> for ForkCondition {
+    fn from(format: ForkConditionFormat) -> Self {
+        match format {
+            ForkConditionFormat::Repr(ForkConditionRepr::Block { block }) |
+            ForkConditionFormat::Legacy(LegacyForkConditionRepr::Block(block)) => {
+                Self::Block(block)
+            }
+            ForkConditionFormat::Repr(ForkConditionRepr::Timestamp { timestamp }) |
+            ForkConditionFormat::Legacy(LegacyForkConditionRepr::Timestamp(timestamp)) => {
+                Self::Timestamp(timestamp)
+            }
+            ForkConditionFormat::Repr(ForkConditionRepr::Ttd { ttd, n }) => {
+                Self::TTD { fork_block: n, total_difficulty: ttd }
+            }
+            ForkConditionFormat::Legacy(LegacyForkConditionRepr::Ttd {
+                fork_block,
+                total_difficulty,
+            }) => Self::TTD { fork_block, total_difficulty },
+            ForkConditionFormat::Repr(ForkConditionRepr::Never(NeverTag::Never)) |
+            ForkConditionFormat::Legacy(LegacyForkConditionRepr::Never) => Self::Never,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<ForkCondition> for ForkConditionRepr {
+    fn from(condition: ForkCondition) -> Self {
+        match condition {
+            ForkCondition::Block(block) => Self::Block { block },
+            ForkCondition::Timestamp(timestamp) => Self::Timestamp { timestamp },
+            ForkCondition::TTD { fork_block, total_difficulty } => {
+                Self::Ttd { ttd: total_difficulty, n: fork_block }
+            }
+            ForkCondition::Never => Self::Never(NeverTag::Never),
+        }
+    }
+}
+
+/// (De)serializes a terminal total difficulty as a decimal string.
+#[cfg(feature = "serde")]
+pub(crate) mod ttd_decimal {
+    use alloc::string::{String, ToString};
+    use alloy_primitives::U256;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(ttd: &U256, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&ttd.to_string())
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<U256, D::Error> {
+        let ttd = String::deserialize(deserializer)?;
+        U256::from_str_radix(&ttd, 10).map_err(D::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use alloy_primitives::U256;
 
+    #[test]
+    fn test_from_str() {
+        assert_eq!("block:12965000".parse(), Ok(ForkCondition::Block(12965000)));
+        assert_eq!("timestamp:1710338135".parse(), Ok(ForkCondition::Timestamp(1710338135)));
+        assert_eq!(
+            "ttd:58750000000000000000000".parse(),
+            Ok(ForkCondition::TTD {
+                fork_block: None,
+                total_difficulty: U256::from(58_750_000_000_000_000_000_000u128)
+            })
+        );
+        assert_eq!(
+            format!("ttd:{}", U256::MAX).parse(),
+            Ok(ForkCondition::TTD { fork_block: None, total_difficulty: U256::MAX })
+        );
+        assert_eq!(" Never ".parse(), Ok(ForkCondition::Never));
+        assert_eq!("Block:1".parse(), Ok(ForkCondition::Block(1)));
+    }
+
+    #[test]
+    fn test_from_str_malformed() {
+        let invalid_format = |s: &str| Err(ParseForkConditionError::InvalidFormat(s.to_string()));
+        let invalid_number = |kind, value: &str, expected| {
+            Err(ParseForkConditionError::InvalidNumber { kind, value: value.to_string(), expected })
+        };
+
+        assert_eq!("12965000".parse::<ForkCondition>(), invalid_format("12965000"));
+        assert_eq!("height:1".parse::<ForkCondition>(), invalid_format("height:1"));
+        assert_eq!("".parse::<ForkCondition>(), invalid_format(""));
+        assert_eq!("block:".parse::<ForkCondition>(), invalid_number("block", "", "u64"));
+        assert_eq!("block:-1".parse::<ForkCondition>(), invalid_number("block", "-1", "u64"));
+        assert_eq!(
+            "timestamp:18446744073709551616".parse::<ForkCondition>(),
+            invalid_number("timestamp", "18446744073709551616", "u64")
+        );
+        assert_eq!("ttd:".parse::<ForkCondition>(), invalid_number("ttd", "", "u256"));
+        assert_eq!("ttd:0x10".parse::<ForkCondition>(), invalid_number("ttd", "0x10", "u256"));
+
+        // one more than U256::MAX
+        let overflow =
+            "115792089237316195423570985008687907853269984665640564039457584007913129639936";
+        assert_eq!(
+            format!("ttd:{overflow}").parse::<ForkCondition>(),
+            invalid_number("ttd", overflow, "u256")
+        );
+    }
+
     #[test]
     fn test_active_at_block() {
         // Test if the condition is active at the current block number
@@ -223,6 +546,112 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_active_at() {
+        // Block and timestamp conditions are active exactly at their activation point
+        let fork_condition = ForkCondition::Block(10);
+        assert!(fork_condition.active_at(10, 0, U256::ZERO));
+        assert!(!fork_condition.active_at(9, u64::MAX, U256::MAX));
+
+        let fork_condition = ForkCondition::Timestamp(12345);
+        assert!(fork_condition.active_at(0, 12345, U256::ZERO));
+        assert!(!fork_condition.active_at(u64::MAX, 12344, U256::MAX));
+
+        // TTD conditions are active once the total difficulty reaches the threshold
+        let fork_condition =
+            ForkCondition::TTD { fork_block: None, total_difficulty: U256::from(1000) };
+        assert!(fork_condition.active_at(0, 0, U256::from(1000)));
+        assert!(!fork_condition.active_at(0, 0, U256::from(999)));
+
+        // or once the known fork block is reached
+        let fork_condition =
+            ForkCondition::TTD { fork_block: Some(10), total_difficulty: U256::from(1000) };
+        assert!(fork_condition.active_at(10, 0, U256::ZERO));
+
+        assert!(!ForkCondition::Never.active_at(u64::MAX, u64::MAX, U256::MAX));
+    }
+
+    #[test]
+    fn test_accessors() {
+        let block = ForkCondition::Block(12965000);
+        assert_eq!(block.as_block(), Some(12965000));
+        assert_eq!(block.as_ttd(), None);
+        assert_eq!(block.as_timestamp(), None);
+
+        let ttd = ForkCondition::TTD {
+            fork_block: Some(1735371),
+            total_difficulty: U256::from(17_000_000_000_000_000u64),
+        };
+        assert_eq!(ttd.as_block(), None);
+        assert_eq!(ttd.as_ttd(), Some(U256::from(17_000_000_000_000_000u64)));
+        assert_eq!(ttd.ttd(), ttd.as_ttd());
+        assert_eq!(ttd.as_timestamp(), None);
+
+        let timestamp = ForkCondition::Timestamp(1681338455);
+        assert_eq!(timestamp.as_block(), None);
+        assert_eq!(timestamp.as_ttd(), None);
+        assert_eq!(timestamp.as_timestamp(), Some(1681338455));
+
+        let never = ForkCondition::Never;
+        assert_eq!(never.as_block(), None);
+        assert_eq!(never.as_ttd(), None);
+        assert_eq!(never.as_timestamp(), None);
+    }
+
+    #[test]
+    fn test_never() {
+        assert_eq!(ForkCondition::block_or_never(Some(10)), ForkCondition::Block(10));
+        assert_eq!(ForkCondition::block_or_never(None), ForkCondition::Never);
+        assert_eq!(ForkCondition::timestamp_or_never(Some(10)), ForkCondition::Timestamp(10));
+        assert_eq!(ForkCondition::timestamp_or_never(None), ForkCondition::Never);
+        assert!(ForkCondition::default().is_never());
+        assert!(!ForkCondition::Block(0).is_never());
+
+        // a disabled fork is never satisfied
+        let never = ForkCondition::Never;
+        assert!(!never.active_at_block(u64::MAX));
+        assert!(!never.transitions_at_block(u64::MAX));
+        assert!(!never.active_at_timestamp(u64::MAX));
+        assert!(!never.transitions_at_timestamp(u64::MAX, 0));
+        assert!(!never.active_at_ttd(U256::MAX, U256::ZERO));
+        assert!(!never.active_at_head(&Head {
+            number: u64::MAX,
+            timestamp: u64::MAX,
+            total_difficulty: U256::MAX,
+            ..Default::default()
+        }));
+        assert_eq!(never.ttd(), None);
+        assert_eq!(never.as_timestamp(), None);
+    }
+
+    #[test]
+    fn test_is_active_at_head() {
+        assert!(ForkCondition::Block(10).is_active_at_head(10, 0, None));
+        assert!(!ForkCondition::Block(10).is_active_at_head(9, u64::MAX, Some(U256::MAX)));
+
+        assert!(ForkCondition::Timestamp(12345).is_active_at_head(0, 12345, None));
+        assert!(!ForkCondition::Timestamp(12345).is_active_at_head(u64::MAX, 12344, None));
+
+        // TTD conditions require both the total difficulty and the fork block to be reached
+        let fork_condition =
+            ForkCondition::TTD { fork_block: Some(10), total_difficulty: U256::from(1000) };
+        assert!(fork_condition.is_active_at_head(10, 0, Some(U256::from(1000))));
+        assert!(!fork_condition.is_active_at_head(9, 0, Some(U256::from(1000))));
+        assert!(!fork_condition.is_active_at_head(10, 0, Some(U256::from(999))));
+        assert!(!fork_condition.is_active_at_head(9, 0, Some(U256::from(999))));
+        assert!(fork_condition.is_active_at_head(10, 0, None));
+        assert!(!fork_condition.is_active_at_head(9, 0, None));
+
+        // without a fork block only the total difficulty is considered
+        let fork_condition =
+            ForkCondition::TTD { fork_block: None, total_difficulty: U256::from(1000) };
+        assert!(fork_condition.is_active_at_head(0, 0, Some(U256::from(1000))));
+        assert!(!fork_condition.is_active_at_head(u64::MAX, 0, Some(U256::from(999))));
+        assert!(!fork_condition.is_active_at_head(u64::MAX, 0, None));
+
+        assert!(!ForkCondition::Never.is_active_at_head(u64::MAX, u64::MAX, Some(U256::MAX)));
+    }
+
     #[test]
     fn test_active_at_head() {
         let head = Head {
@@ -283,4 +712,79 @@ mod tests {
             "The condition should be active as the total difficulty is higher than head"
         );
     }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(ForkCondition::Block(15537394).to_string(), "block 15537394");
+        assert_eq!(ForkCondition::Timestamp(1681338455).to_string(), "timestamp 1681338455");
+        assert_eq!(
+            ForkCondition::TTD {
+                fork_block: None,
+                total_difficulty: U256::from(58_750_000_000_000_000_000_000_u128)
+            }
+            .to_string(),
+            "ttd 58750000000000000000000"
+        );
+        assert_eq!(
+            ForkCondition::TTD { fork_block: Some(1735371), total_difficulty: U256::from(17) }
+                .to_string(),
+            "ttd 17 (block 1735371)"
+        );
+        assert_eq!(ForkCondition::Never.to_string(), "never");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_roundtrip() {
+        let ttd = U256::from(58_750_000_000_000_000_000_000_u128);
+        let cases = [
+            (ForkCondition::Block(15537394), serde_json::json!({ "block": 15537394 })),
+            (ForkCondition::Timestamp(1681338455), serde_json::json!({ "timestamp": 1681338455 })),
+            (
+                ForkCondition::TTD { fork_block: Some(15537394), total_difficulty: ttd },
+                serde_json::json!({ "ttd": "58750000000000000000000", "n": 15537394 }),
+            ),
+            (
+                ForkCondition::TTD { fork_block: None, total_difficulty: ttd },
+                serde_json::json!({ "ttd": "58750000000000000000000" }),
+            ),
+            (ForkCondition::Never, serde_json::json!("never")),
+        ];
+
+        for (condition, json) in cases {
+            assert_eq!(serde_json::to_value(condition).unwrap(), json);
+            assert_eq!(serde_json::from_value::<ForkCondition>(json).unwrap(), condition);
+        }
+
+        // a fork block is only valid for TTD based conditions
+        assert!(serde_json::from_str::<ForkCondition>(r#"{"block":1,"n":2}"#).is_err());
+        assert!(serde_json::from_str::<ForkCondition>(r#""always""#).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_deserialize_legacy_format() {
+        let ttd = U256::from(58_750_000_000_000_000_000_000_u128);
+        let cases = [
+            (serde_json::json!({ "Block": 15537394 }), ForkCondition::Block(15537394)),
+            (serde_json::json!({ "Timestamp": 1681338455 }), ForkCondition::Timestamp(1681338455)),
+            (
+                serde_json::json!({
+                    "TTD": { "fork_block": 15537394, "total_difficulty": "0xc70d808a128d7380000" }
+                }),
+                ForkCondition::TTD { fork_block: Some(15537394), total_difficulty: ttd },
+            ),
+            (
+                serde_json::json!({
+                    "TTD": { "fork_block": null, "total_difficulty": "0xc70d808a128d7380000" }
+                }),
+                ForkCondition::TTD { fork_block: None, total_difficulty: ttd },
+            ),
+            (serde_json::json!("Never"), ForkCondition::Never),
+        ];
+
+        for (json, condition) in cases {
+            assert_eq!(serde_json::from_value::<ForkCondition>(json).unwrap(), condition);
+        }
+    }
 }
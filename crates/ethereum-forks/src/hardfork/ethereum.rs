@@ -1,60 +1,121 @@
-use crate::{hardfork, ChainHardforks, ForkCondition, Hardfork};
-use alloc::{boxed::Box, format, string::String};
+use crate::{hardfork, ChainHardforks, ConfigureHardforks, ForkCondition, Hardfork};
+use alloc::boxed::Box;
 use alloy_chains::Chain;
+use alloy_genesis::ChainConfig;
 use alloy_primitives::{uint, U256};
 use core::{
     fmt,
     fmt::{Display, Formatter},
     str::FromStr,
 };
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
 
 hardfork!(
     /// The name of an Ethereum hardfork.
+    ///
+    /// Variants are declared in the order they were activated on mainnet and their [`Ord`] impl
+    /// follows this protocol activation order, e.g. `EthereumHardfork::Berlin <
+    /// EthereumHardfork::London`.
     EthereumHardfork {
         /// Frontier: <https://blog.ethereum.org/2015/03/03/ethereum-launch-process>.
-        Frontier,
+        Frontier => frontier,
         /// Homestead: <https://github.com/ethereum/execution-specs/blob/master/network-upgrades/mainnet-upgrades/homestead.md>.
-        Homestead,
+        Homestead => homestead,
         /// The DAO fork: <https://github.com/ethereum/execution-specs/blob/master/network-upgrades/mainnet-upgrades/dao-fork.md>.
-        Dao,
+        Dao => dao,
         /// Tangerine: <https://github.com/ethereum/execution-specs/blob/master/network-upgrades/mainnet-upgrades/tangerine-whistle.md>.
-        Tangerine,
+        Tangerine => tangerine,
         /// Spurious Dragon: <https://github.com/ethereum/execution-specs/blob/master/network-upgrades/mainnet-upgrades/spurious-dragon.md>.
-        SpuriousDragon,
+        SpuriousDragon => spurious_dragon,
         /// Byzantium: <https://github.com/ethereum/execution-specs/blob/master/network-upgrades/mainnet-upgrades/byzantium.md>.
-        Byzantium,
+        Byzantium => byzantium,
         /// Constantinople: <https://github.com/ethereum/execution-specs/blob/master/network-upgrades/mainnet-upgrades/constantinople.md>.
-        Constantinople,
+        Constantinople => constantinople,
         /// Petersburg: <https://github.com/ethereum/execution-specs/blob/master/network-upgrades/mainnet-upgrades/petersburg.md>.
-        Petersburg,
+        Petersburg => petersburg,
         /// Istanbul: <https://github.com/ethereum/execution-specs/blob/master/network-upgrades/mainnet-upgrades/istanbul.md>.
-        Istanbul,
+        Istanbul => istanbul,
         /// Muir Glacier: <https://github.com/ethereum/execution-specs/blob/master/network-upgrades/mainnet-upgrades/muir-glacier.md>.
-        MuirGlacier,
+        MuirGlacier => muir_glacier,
         /// Berlin: <https://github.com/ethereum/execution-specs/blob/master/network-upgrades/mainnet-upgrades/berlin.md>.
-        Berlin,
+        Berlin => berlin,
         /// London: <https://github.com/ethereum/execution-specs/blob/master/network-upgrades/mainnet-upgrades/london.md>.
-        London,
+        London => london,
         /// Arrow Glacier: <https://github.com/ethereum/execution-specs/blob/master/network-upgrades/mainnet-upgrades/arrow-glacier.md>.
-        ArrowGlacier,
+        ArrowGlacier => arrow_glacier,
         /// Gray Glacier: <https://github.com/ethereum/execution-specs/blob/master/network-upgrades/mainnet-upgrades/gray-glacier.md>.
-        GrayGlacier,
+        GrayGlacier => gray_glacier,
         /// Paris: <https://github.com/ethereum/execution-specs/blob/master/network-upgrades/mainnet-upgrades/paris.md>.
-        Paris,
+        Paris => paris,
         /// Shanghai: <https://github.com/ethereum/execution-specs/blob/master/network-upgrades/mainnet-upgrades/shanghai.md>.
-        Shanghai,
+        Shanghai => shanghai,
         /// Cancun.
-        Cancun,
+        Cancun => cancun,
         /// Prague: <https://github.com/ethereum/execution-specs/blob/master/network-upgrades/mainnet-upgrades/prague.md>
-        Prague,
+        Prague => prague,
         /// Osaka: <https://eips.ethereum.org/EIPS/eip-7607>
-        Osaka,
+        Osaka => osaka,
     }
+    aliases = HARDFORK_ALIASES,
+    /// The activation of each [`EthereumHardfork`] that is configured in a [`ChainConfig`].
+    ///
+    /// This is a typed alternative to [`ConfigureHardforks::init`], e.g.
+    /// `EthereumForkSchedule::from_chain_config(&config).london.is_some()`. Note that `frontier` is
+    /// always `None`, because it's implicitly active at genesis and not part of the
+    /// [`ChainConfig`].
+    schedule = EthereumForkSchedule
 );
 
+/// Well-known alternative names of Ethereum hardforks that are accepted when parsing.
+///
+/// Combined consensus and execution layer upgrade names, such as "dencun", don't refer to a single
+/// hardfork and are rejected.
+const HARDFORK_ALIASES: &[(&str, Option<EthereumHardfork>)] = &[
+    ("tangerinewhistle", Some(EthereumHardfork::Tangerine)),
+    ("constantinoplefix", Some(EthereumHardfork::Petersburg)),
+    ("merge", Some(EthereumHardfork::Paris)),
+    ("shapella", None),
+    ("dencun", None),
+    ("pectra", None),
+    ("fusaka", None),
+];
+
 impl EthereumHardfork {
+    /// Returns the position of this hardfork in the mainnet activation order, starting with
+    /// [`EthereumHardfork::Frontier`] at `0`.
+    ///
+    /// This is consistent with the [`Ord`] impl and the index in [`EthereumHardfork::all`].
+    pub const fn ordering(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Returns the numbers of the EIPs that are activated by this hardfork.
+    ///
+    /// [`EthereumHardfork::Petersburg`] returns the EIPs of
+    /// [`EthereumHardfork::Constantinople`] without EIP-1283, which it removed. Returns an empty
+    /// slice for [`EthereumHardfork::Frontier`] and for hardforks whose scope is not final yet.
+    pub const fn associated_eips(&self) -> &'static [u32] {
+        match self {
+            Self::Frontier | Self::Osaka => &[],
+            Self::Homestead => &[2, 7, 8],
+            Self::Dao => &[779],
+            Self::Tangerine => &[150],
+            Self::SpuriousDragon => &[155, 160, 161, 170],
+            Self::Byzantium => &[100, 140, 196, 197, 198, 211, 214, 649, 658],
+            Self::Constantinople => &[145, 1014, 1052, 1234, 1283],
+            Self::Petersburg => &[145, 1014, 1052, 1234],
+            Self::Istanbul => &[152, 1108, 1344, 1884, 2028, 2200],
+            Self::MuirGlacier => &[2384],
+            Self::Berlin => &[2565, 2718, 2929, 2930],
+            Self::London => &[1559, 3198, 3529, 3541],
+            Self::ArrowGlacier => &[4345],
+            Self::GrayGlacier => &[5133],
+            Self::Paris => &[3675, 4399],
+            Self::Shanghai => &[3651, 3855, 3860, 4895],
+            Self::Cancun => &[1153, 4788, 4844, 5656, 6780, 7516],
+            Self::Prague => &[2537, 2935, 6110, 7002, 7251, 7549, 7623, 7685, 7691, 7702],
+        }
+    }
+
     /// Retrieves the activation block for the specified hardfork on the given chain.
     pub fn activation_block(&self, chain: Chain) -> Option<u64> {
         if chain == Chain::mainnet() {
@@ -410,6 +471,50 @@ impl EthereumHardfork {
     }
 }
 
+impl ConfigureHardforks for EthereumHardfork {
+    fn init_block_hardforks(config: &ChainConfig) -> impl Iterator<Item = (Self, ForkCondition)> {
+        let block_forks = [
+            (Self::Homestead, config.homestead_block),
+            (Self::Dao, config.dao_fork_block),
+            (Self::Tangerine, config.eip150_block),
+            (Self::SpuriousDragon, config.eip155_block),
+            (Self::Byzantium, config.byzantium_block),
+            (Self::Constantinople, config.constantinople_block),
+            (Self::Petersburg, config.petersburg_block),
+            (Self::Istanbul, config.istanbul_block),
+            (Self::MuirGlacier, config.muir_glacier_block),
+            (Self::Berlin, config.berlin_block),
+            (Self::London, config.london_block),
+            (Self::ArrowGlacier, config.arrow_glacier_block),
+            (Self::GrayGlacier, config.gray_glacier_block),
+        ];
+
+        // Paris
+        let paris = config.terminal_total_difficulty.map(|total_difficulty| {
+            (
+                Self::Paris,
+                ForkCondition::TTD { total_difficulty, fork_block: config.merge_netsplit_block },
+            )
+        });
+
+        block_forks
+            .into_iter()
+            .filter_map(|(fork, block)| block.map(|block| (fork, ForkCondition::Block(block))))
+            .chain(paris)
+    }
+
+    fn init_time_hardforks(config: &ChainConfig) -> impl Iterator<Item = (Self, ForkCondition)> {
+        [
+            (Self::Shanghai, config.shanghai_time),
+            (Self::Cancun, config.cancun_time),
+            (Self::Prague, config.prague_time),
+            (Self::Osaka, config.osaka_time),
+        ]
+        .into_iter()
+        .filter_map(|(fork, time)| time.map(|time| (fork, ForkCondition::Timestamp(time))))
+    }
+}
+
 impl<const N: usize> From<[(EthereumHardfork, ForkCondition); N]> for ChainHardforks {
     fn from(list: [(EthereumHardfork, ForkCondition); N]) -> Self {
         Self::new(
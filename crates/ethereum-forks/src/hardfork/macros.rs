@@ -1,10 +1,29 @@
 /// Macro that defines different variants of a chain specific enum. See [`crate::Hardfork`] as an
 /// example.
+///
+/// With the `serde` feature of the calling crate, the enum is serialized as its lowercase name and
+/// deserialized case-insensitively like its [`FromStr`](core::str::FromStr) implementation.
+///
+/// An optional `aliases = ...` table of lowercase `(alias, Option<variant>)` pairs can be supplied
+/// to accept additional names when parsing. Aliases mapped to `None` are rejected as ambiguous.
+///
+/// If the aliases are followed by `, configured`, [`Hardfork::activation`](crate::Hardfork) is
+/// resolved from the enum's [`ConfigureHardforks`](crate::ConfigureHardforks) implementation.
+///
+/// If every variant is followed by `=> field_name` and a `schedule = ...` struct name is supplied,
+/// a struct with an `Option<ForkCondition>` field per variant is generated as well, see
+/// [`crate::EthereumForkSchedule`]. This requires the enum to implement
+/// [`ConfigureHardforks`](crate::ConfigureHardforks).
 #[macro_export]
 macro_rules! hardfork {
     ($(#[$enum_meta:meta])* $enum:ident { $( $(#[$meta:meta])* $variant:ident ),* $(,)? }) => {
+        $crate::hardfork!($(#[$enum_meta])* $enum { $( $(#[$meta])* $variant ),* } aliases = &[]);
+    };
+    (
+        $(#[$enum_meta:meta])* $enum:ident { $( $(#[$meta:meta])* $variant:ident ),* $(,)? }
+        aliases = $aliases:expr $(, $configured:ident)?
+    ) => {
         $(#[$enum_meta])*
-        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
         #[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
         pub enum $enum {
             $( $(#[$meta])* $variant ),*
@@ -18,6 +37,20 @@ macro_rules! hardfork {
                 }
             }
 
+            /// Returns all variants in the order they are declared, which is the order in which
+            /// they activate.
+            pub const fn all() -> &'static [Self] {
+                &[$( $enum::$variant ),*]
+            }
+
+            /// Returns the hardfork if `fork` is of this type.
+            pub fn from_dyn(fork: &dyn Hardfork) -> Option<Self> {
+                if !fork.is::<Self>() {
+                    return None
+                }
+                Self::all().iter().copied().find(|variant| variant.name() == fork.name())
+            }
+
             /// Boxes `self` and returns it as `Box<dyn Hardfork>`.
             pub fn boxed(self) -> Box<dyn Hardfork> {
                 Box::new(self)
@@ -25,21 +58,52 @@ macro_rules! hardfork {
         }
 
         impl FromStr for $enum {
-            type Err = String;
+            type Err = $crate::ParseHardforkError;
 
             fn from_str(s: &str) -> Result<Self, Self::Err> {
-                match s.to_lowercase().as_str() {
-                    $(
-                        s if s == stringify!($variant).to_lowercase() => Ok($enum::$variant),
-                    )*
-                    _ => return Err(format!("Unknown hardfork: {s}")),
+                let name = s.to_lowercase();
+                $(
+                    if name == stringify!($variant).to_lowercase() {
+                        return Ok($enum::$variant)
+                    }
+                )*
+
+                let aliases: &[(&str, Option<$enum>)] = $aliases;
+                match aliases.iter().find(|(alias, _)| *alias == name) {
+                    Some((_, Some(fork))) => Ok(*fork),
+                    Some((_, None)) => Err($crate::ParseHardforkError::Ambiguous(s.into())),
+                    None => Err($crate::ParseHardforkError::Unknown(s.into())),
                 }
             }
         }
 
-        impl Hardfork for $enum {
-            fn name(&self) -> &'static str {
-                self.name()
+        $crate::hardfork!(@impl_hardfork $enum $(, $configured)?);
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $enum {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.name().to_lowercase())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $enum {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct NameVisitor;
+
+                impl serde::de::Visitor<'_> for NameVisitor {
+                    type Value = $enum;
+
+                    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                        f.write_str(concat!("the name of a ", stringify!($enum)))
+                    }
+
+                    fn visit_str<E: serde::de::Error>(self, name: &str) -> Result<$enum, E> {
+                        name.parse().map_err(E::custom)
+                    }
+                }
+
+                deserializer.deserialize_str(NameVisitor)
             }
         }
 
@@ -48,5 +112,68 @@ macro_rules! hardfork {
                 write!(f, "{self:?}")
             }
         }
-    }
+    };
+    (
+        $(#[$enum_meta:meta])* $enum:ident {
+            $( $(#[$meta:meta])* $variant:ident => $field:ident ),* $(,)?
+        }
+        aliases = $aliases:expr,
+        $(#[$schedule_meta:meta])* schedule = $schedule:ident
+    ) => {
+        $crate::hardfork!(
+            $(#[$enum_meta])* $enum { $( $(#[$meta])* $variant ),* } aliases = $aliases, configured
+        );
+
+        $(#[$schedule_meta])*
+        #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+        pub struct $schedule {
+            $( $(#[$meta])* pub $field: Option<$crate::ForkCondition>, )*
+        }
+
+        impl $schedule {
+            /// Returns the activation of all hardforks that are configured in the [`ChainConfig`].
+            ///
+            /// Forks that are not configured are `None`.
+            pub fn from_chain_config(config: &ChainConfig) -> Self {
+                let mut schedule = Self::default();
+                for (fork, condition) in <$enum as $crate::ConfigureHardforks>::init(config) {
+                    *schedule.get_mut(fork) = Some(condition);
+                }
+                schedule
+            }
+
+            /// Returns the activation of the given hardfork, if it's configured.
+            pub const fn get(&self, fork: $enum) -> Option<$crate::ForkCondition> {
+                match fork {
+                    $( $enum::$variant => self.$field, )*
+                }
+            }
+
+            fn get_mut(&mut self, fork: $enum) -> &mut Option<$crate::ForkCondition> {
+                match fork {
+                    $( $enum::$variant => &mut self.$field, )*
+                }
+            }
+        }
+    };
+    (@impl_hardfork $enum:ident) => {
+        impl Hardfork for $enum {
+            fn name(&self) -> &'static str {
+                self.name()
+            }
+        }
+    };
+    (@impl_hardfork $enum:ident, configured) => {
+        impl Hardfork for $enum {
+            fn name(&self) -> &'static str {
+                self.name()
+            }
+
+            fn activation(&self, config: &ChainConfig) -> Option<$crate::ForkCondition> {
+                <Self as $crate::ConfigureHardforks>::init(config)
+                    .find(|(fork, _)| fork == self)
+                    .map(|(_, condition)| condition)
+            }
+        }
+    };
 }
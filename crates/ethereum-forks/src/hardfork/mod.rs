@@ -1,13 +1,23 @@
 mod macros;
 
 mod ethereum;
-pub use ethereum::EthereumHardfork;
+pub use ethereum::{EthereumForkSchedule, EthereumHardfork};
 
 mod dev;
 pub use dev::DEV_HARDFORKS;
 
+mod schedule;
+pub use schedule::{ForkScheduleEntry, ScheduledActivation, ScheduledHardfork};
+
+mod string;
+pub use string::StringHardfork;
+
+use crate::{ChainHardforks, ForkCondition, ForkHash, ForkId};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use alloy_genesis::{ChainConfig, Genesis};
+use alloy_primitives::B256;
 use core::{
-    any::Any,
+    any::{Any, TypeId},
     hash::{Hash, Hasher},
 };
 use dyn_clone::DynClone;
@@ -17,16 +27,341 @@ use dyn_clone::DynClone;
 pub trait Hardfork: Any + DynClone + Send + Sync + 'static {
     /// Fork name.
     fn name(&self) -> &'static str;
+
+    /// Returns the [`ForkCondition`] at which this fork activates according to the
+    /// [`ChainConfig`], or `None` if the fork is not configured.
+    ///
+    /// Hardfork types that implement [`ConfigureHardforks`] resolve this from their configured
+    /// forks, which makes the activation available through a `&dyn Hardfork`. Other hardfork types
+    /// are never configured by a [`ChainConfig`].
+    fn activation(&self, _config: &ChainConfig) -> Option<ForkCondition> {
+        None
+    }
 }
 
 dyn_clone::clone_trait_object!(Hardfork);
 
+/// Error returned when parsing a hardfork from its name fails.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror_no_std::Error)]
+pub enum ParseHardforkError {
+    /// The name does not match any known hardfork.
+    #[error("Unknown hardfork: {0}")]
+    Unknown(String),
+    /// The name refers to an upgrade that bundles multiple hardforks.
+    #[error("ambiguous hardfork name {0}, use the name of a single hardfork instead")]
+    Ambiguous(String),
+}
+
+/// Converts the error into its message, for callers that handled the `String` error that
+/// [`FromStr`](core::str::FromStr) of the hardfork types returned before.
+impl From<ParseHardforkError> for String {
+    fn from(err: ParseHardforkError) -> Self {
+        alloc::string::ToString::to_string(&err)
+    }
+}
+
+/// Resolves the activation of a chain specific [`Hardfork`] type from a genesis [`ChainConfig`].
+pub trait ConfigureHardforks: Hardfork + PartialEq + Sized {
+    /// Returns the block based hardforks that are configured in the [`ChainConfig`].
+    ///
+    /// This includes TTD based forks, e.g. [`EthereumHardfork::Paris`].
+    fn init_block_hardforks(config: &ChainConfig) -> impl Iterator<Item = (Self, ForkCondition)>;
+
+    /// Returns the timestamp based hardforks that are configured in the [`ChainConfig`].
+    fn init_time_hardforks(config: &ChainConfig) -> impl Iterator<Item = (Self, ForkCondition)>;
+
+    /// Returns all hardforks that are configured in the [`ChainConfig`], block based forks first.
+    ///
+    /// Forks that are not configured are omitted.
+    fn init(config: &ChainConfig) -> impl Iterator<Item = (Self, ForkCondition)> {
+        Self::init_block_hardforks(config).chain(Self::init_time_hardforks(config))
+    }
+
+    /// Returns each of the given hardforks with its activation according to the [`ChainConfig`].
+    ///
+    /// Unlike [`Self::init`], this includes forks that are not configured, with
+    /// [`ForkCondition::Never`]. This is useful to build a complete activation table of all known
+    /// forks, e.g. `EthereumHardfork::init_all(config, EthereumHardfork::all().iter().copied())`.
+    /// Note that forks that are implicitly active at genesis, e.g. `Frontier`, are not part of the
+    /// [`ChainConfig`] and therefore marked as never activated.
+    fn init_all(
+        config: &ChainConfig,
+        forks: impl IntoIterator<Item = Self>,
+    ) -> Vec<(Self, ForkCondition)> {
+        let mut configured: Vec<_> = Self::init(config).collect();
+        forks
+            .into_iter()
+            .map(|fork| {
+                let condition = configured
+                    .iter()
+                    .position(|(configured, _)| *configured == fork)
+                    .map_or(ForkCondition::Never, |idx| configured.swap_remove(idx).1);
+                (fork, condition)
+            })
+            .collect()
+    }
+
+    /// Returns all hardforks that are configured in the [`ChainConfig`], sorted by their
+    /// activation.
+    ///
+    /// Block based forks come first, followed by TTD based forks and timestamp based forks. TTD
+    /// based forks with a known fork block are ordered like block based forks at that block. Forks
+    /// that activate at the same point are ordered by their canonical fork order. Forks that are
+    /// not configured or never activate are omitted.
+    fn init_sorted(config: &ChainConfig) -> Vec<(Self, ForkCondition)>
+    where
+        Self: Ord,
+    {
+        let mut forks: Vec<_> = Self::init(config)
+            .filter(|(_, condition)| *condition != ForkCondition::Never)
+            .collect();
+        forks.sort_by(|(a, a_condition), (b, b_condition)| {
+            activation_key(a_condition).cmp(&activation_key(b_condition)).then_with(|| a.cmp(b))
+        });
+        forks
+    }
+
+    /// Returns additional hardforks of other [`Hardfork`] types that are configured in the
+    /// [`ChainConfig`], e.g. the upgrades of a protocol fork that are read from
+    /// [`ChainConfig::extra_fields`] at runtime.
+    ///
+    /// These are merged into [`Self::init_chain_hardforks`]. By default, there are none.
+    fn custom_hardforks(
+        _config: &ChainConfig,
+    ) -> impl Iterator<Item = (Box<dyn Hardfork>, ForkCondition)> {
+        core::iter::empty()
+    }
+
+    /// Returns the [`ChainHardforks`] of all hardforks that are configured in the [`ChainConfig`],
+    /// including the [`Self::custom_hardforks`], sorted by their activation.
+    ///
+    /// Forks are sorted as in [`Self::init_sorted`]. A custom fork is ordered after all forks that
+    /// activate at the same point, and custom forks that activate at the same point keep their
+    /// order. Forks that never activate are omitted.
+    fn init_chain_hardforks(config: &ChainConfig) -> ChainHardforks
+    where
+        Self: Ord,
+    {
+        let mut forks: Vec<(Box<dyn Hardfork>, ForkCondition)> = Self::init_sorted(config)
+            .into_iter()
+            .map(|(fork, condition)| (Box::new(fork) as Box<dyn Hardfork>, condition))
+            .collect();
+        for (fork, condition) in
+            Self::custom_hardforks(config).filter(|(_, condition)| !condition.is_never())
+        {
+            let idx = forks
+                .partition_point(|(_, other)| activation_key(other) <= activation_key(&condition));
+            forks.insert(idx, (fork, condition));
+        }
+        ChainHardforks::new(forks)
+    }
+
+    /// Returns the hardforks of this type and of `O` that are configured in the [`ChainConfig`],
+    /// merged into a single schedule sorted by activation.
+    ///
+    /// This is useful for chains on which the forks of two [`Hardfork`] types are active at the
+    /// same time, e.g. `EthereumHardfork::init_merged::<OptimismHardfork>(config)` on OP-stack
+    /// chains. Forks of each type are sorted as in [`Self::init_sorted`], and forks of this type
+    /// are ordered before forks of `O` that activate at the same point. Forks that never activate
+    /// are omitted.
+    fn init_merged<O>(config: &ChainConfig) -> Vec<(Box<dyn Hardfork>, ForkCondition)>
+    where
+        Self: Ord,
+        O: ConfigureHardforks + Ord,
+    {
+        let mut forks: Vec<(Box<dyn Hardfork>, ForkCondition)> = Self::init_sorted(config)
+            .into_iter()
+            .map(|(fork, condition)| (Box::new(fork) as Box<dyn Hardfork>, condition))
+            .collect();
+        for (fork, condition) in O::init_sorted(config) {
+            let idx = forks
+                .partition_point(|(_, other)| activation_key(other) <= activation_key(&condition));
+            forks.insert(idx, (Box::new(fork), condition));
+        }
+        forks
+    }
+
+    /// Returns the hardforks of the [`Genesis`] config that are already active at the genesis
+    /// block, in their canonical order.
+    ///
+    /// TTD based forks are active if the genesis difficulty reaches the terminal total difficulty,
+    /// e.g. [`EthereumHardfork::Paris`] on post-merge networks.
+    fn active_at_genesis(genesis: &Genesis) -> Vec<Self>
+    where
+        Self: Ord,
+    {
+        let mut forks: Vec<_> = Self::init(&genesis.config)
+            .filter(|(_, condition)| condition.active_at(0, genesis.timestamp, genesis.difficulty))
+            .map(|(fork, _)| fork)
+            .collect();
+        forks.sort();
+        forks
+    }
+
+    /// Computes the [EIP-2124](https://eips.ethereum.org/EIPS/eip-2124) [`ForkId`] of the chain at
+    /// the head with the given block number and timestamp from the hardforks that are configured
+    /// in the [`Genesis`] config.
+    ///
+    /// Block based forks, including TTD based forks with a known fork block, are applied before
+    /// timestamp based forks as specified by [EIP-6122](https://eips.ethereum.org/EIPS/eip-6122),
+    /// each in ascending order. Forks that activate at the same point are applied once. Forks at
+    /// the genesis block or timestamp are part of the genesis hash and skipped, as are forks that
+    /// never activate or have no known activation point.
+    fn fork_id(
+        genesis: &Genesis,
+        genesis_hash: B256,
+        head_block: u64,
+        head_timestamp: u64,
+    ) -> ForkId {
+        let mut blocks = Vec::new();
+        let mut timestamps = Vec::new();
+        for (_, condition) in Self::init(&genesis.config) {
+            match condition {
+                ForkCondition::Block(block) |
+                ForkCondition::TTD { fork_block: Some(block), .. } => blocks.push(block),
+                ForkCondition::Timestamp(timestamp) => timestamps.push(timestamp),
+                ForkCondition::TTD { fork_block: None, .. } | ForkCondition::Never => {}
+            }
+        }
+        blocks.retain(|block| *block > 0);
+        blocks.sort_unstable();
+        blocks.dedup();
+        timestamps.retain(|timestamp| *timestamp > genesis.timestamp);
+        timestamps.sort_unstable();
+        timestamps.dedup();
+
+        let mut hash = ForkHash::from(genesis_hash);
+        let activations = blocks
+            .into_iter()
+            .map(|block| (block, head_block))
+            .chain(timestamps.into_iter().map(|timestamp| (timestamp, head_timestamp)));
+        for (activation, head) in activations {
+            if head < activation {
+                return ForkId { hash, next: activation }
+            }
+            hash += activation;
+        }
+        ForkId { hash, next: 0 }
+    }
+
+    /// Returns the hardforks that are configured in the [`ChainConfig`] as a serializable,
+    /// ordered schedule.
+    fn schedule(config: &ChainConfig) -> Vec<ScheduledHardfork> {
+        Self::init(config)
+            .map(|(fork, condition)| ScheduledHardfork::new(&fork, condition))
+            .collect()
+    }
+
+    /// Returns the differences between the hardforks that are configured in `config` and in
+    /// `other`.
+    ///
+    /// A fork that is configured in both, but with a different [`ForkCondition`], is reported as
+    /// [`ConfiguredHardforkDiff::Changed`], even if the kind of condition changed, e.g. from block
+    /// based to timestamp based activation. Changed and added forks are reported in the order of
+    /// `other`, followed by the removed forks in the order of `config`.
+    fn diff_configs(
+        config: &ChainConfig,
+        other: &ChainConfig,
+    ) -> Vec<ConfiguredHardforkDiff<Self>> {
+        let mut old: Vec<_> = Self::init(config).collect();
+        let mut diff = Vec::new();
+        for (fork, new) in Self::init(other) {
+            match old.iter().position(|(old_fork, _)| *old_fork == fork) {
+                Some(idx) => {
+                    let (_, old) = old.remove(idx);
+                    if old != new {
+                        diff.push(ConfiguredHardforkDiff::Changed { fork, old, new });
+                    }
+                }
+                None => diff.push(ConfiguredHardforkDiff::Added { fork, condition: new }),
+            }
+        }
+        diff.extend(
+            old.into_iter()
+                .map(|(fork, condition)| ConfiguredHardforkDiff::Removed { fork, condition }),
+        );
+        diff
+    }
+}
+
+/// Returns the sort key of an activation: the kind of condition and its value.
+///
+/// TTD based conditions with a known fork block are keyed like block based conditions.
+const fn activation_key(condition: &ForkCondition) -> (u8, u64) {
+    match condition {
+        ForkCondition::Block(block) | ForkCondition::TTD { fork_block: Some(block), .. } => {
+            (0, *block)
+        }
+        ForkCondition::TTD { fork_block: None, .. } => (1, 0),
+        ForkCondition::Timestamp(timestamp) => (2, *timestamp),
+        ForkCondition::Never => (3, 0),
+    }
+}
+
+/// A difference between the hardforks of two [`ChainConfig`]s, see
+/// [`ConfigureHardforks::diff_configs`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfiguredHardforkDiff<H> {
+    /// The fork is only configured in the other config.
+    Added {
+        /// The hardfork.
+        fork: H,
+        /// The activation condition in the other config.
+        condition: ForkCondition,
+    },
+    /// The fork is only configured in the first config.
+    Removed {
+        /// The hardfork.
+        fork: H,
+        /// The activation condition in the first config.
+        condition: ForkCondition,
+    },
+    /// The fork is configured in both configs, with different activation conditions.
+    Changed {
+        /// The hardfork.
+        fork: H,
+        /// The activation condition in the first config.
+        old: ForkCondition,
+        /// The activation condition in the other config.
+        new: ForkCondition,
+    },
+}
+
+impl<H> ConfiguredHardforkDiff<H> {
+    /// Returns the hardfork that differs.
+    pub const fn fork(&self) -> &H {
+        match self {
+            Self::Added { fork, .. } | Self::Removed { fork, .. } | Self::Changed { fork, .. } => {
+                fork
+            }
+        }
+    }
+}
+
+impl dyn Hardfork + 'static {
+    /// Returns `true` if the hardfork is of type `H`.
+    pub fn is<H: Hardfork>(&self) -> bool {
+        <Self as Any>::type_id(self) == TypeId::of::<H>()
+    }
+
+    /// Returns the [`EthereumHardfork`] if this is an Ethereum hardfork.
+    pub fn as_ethereum(&self) -> Option<EthereumHardfork> {
+        EthereumHardfork::from_dyn(self)
+    }
+}
+
 impl core::fmt::Debug for dyn Hardfork + 'static {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct(self.name()).finish()
     }
 }
 
+impl core::fmt::Display for dyn Hardfork + 'static {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
 impl PartialEq for dyn Hardfork + 'static {
     fn eq(&self, other: &Self) -> bool {
         self.name() == other.name()
@@ -44,50 +379,124 @@ impl Hash for dyn Hardfork + 'static {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloy_primitives::U256;
     use std::str::FromStr;
 
+    /// Hardfork names in mixed case, they're parsed as `EXPECTED_HARDFORKS`.
+    const HARDFORK_STR: [&str; 19] = [
+        "frOntier",
+        "homEstead",
+        "dao",
+        "tAngerIne",
+        "spurIousdrAgon",
+        "byzAntium",
+        "constantinople",
+        "petersburg",
+        "istanbul",
+        "muirglacier",
+        "bErlin",
+        "lonDon",
+        "arrowglacier",
+        "grayglacier",
+        "PARIS",
+        "ShAnGhAI",
+        "CaNcUn",
+        "PrAguE",
+        "OsAkA",
+    ];
+    const EXPECTED_HARDFORKS: [EthereumHardfork; 19] = [
+        EthereumHardfork::Frontier,
+        EthereumHardfork::Homestead,
+        EthereumHardfork::Dao,
+        EthereumHardfork::Tangerine,
+        EthereumHardfork::SpuriousDragon,
+        EthereumHardfork::Byzantium,
+        EthereumHardfork::Constantinople,
+        EthereumHardfork::Petersburg,
+        EthereumHardfork::Istanbul,
+        EthereumHardfork::MuirGlacier,
+        EthereumHardfork::Berlin,
+        EthereumHardfork::London,
+        EthereumHardfork::ArrowGlacier,
+        EthereumHardfork::GrayGlacier,
+        EthereumHardfork::Paris,
+        EthereumHardfork::Shanghai,
+        EthereumHardfork::Cancun,
+        EthereumHardfork::Prague,
+        EthereumHardfork::Osaka,
+    ];
+
     #[test]
     fn check_hardfork_from_str() {
-        let hardfork_str = [
-            "frOntier",
-            "homEstead",
-            "dao",
-            "tAngerIne",
-            "spurIousdrAgon",
-            "byzAntium",
-            "constantinople",
-            "petersburg",
-            "istanbul",
-            "muirglacier",
-            "bErlin",
-            "lonDon",
-            "arrowglacier",
-            "grayglacier",
-            "PARIS",
-            "ShAnGhAI",
-            "CaNcUn",
-            "PrAguE",
-        ];
-        let expected_hardforks = [
-            EthereumHardfork::Frontier,
-            EthereumHardfork::Homestead,
-            EthereumHardfork::Dao,
-            EthereumHardfork::Tangerine,
-            EthereumHardfork::SpuriousDragon,
-            EthereumHardfork::Byzantium,
-            EthereumHardfork::Constantinople,
-            EthereumHardfork::Petersburg,
-            EthereumHardfork::Istanbul,
-            EthereumHardfork::MuirGlacier,
-            EthereumHardfork::Berlin,
-            EthereumHardfork::London,
-            EthereumHardfork::ArrowGlacier,
-            EthereumHardfork::GrayGlacier,
-            EthereumHardfork::Paris,
-            EthereumHardfork::Shanghai,
-            EthereumHardfork::Cancun,
-            EthereumHardfork::Prague,
-        ];
+        let hardforks: Vec<EthereumHardfork> =
+            HARDFORK_STR.iter().map(|h| EthereumHardfork::from_str(h).unwrap()).collect();
+
+        assert_eq!(hardforks, EXPECTED_HARDFORKS);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn check_hardfork_serde() {
+        for (name, fork) in HARDFORK_STR.iter().zip(EXPECTED_HARDFORKS) {
+            let parsed: EthereumHardfork = serde_json::from_value(serde_json::json!(name)).unwrap();
+            assert_eq!(parsed, fork);
+
+            let json = serde_json::to_value(fork).unwrap();
+            assert_eq!(json, serde_json::json!(name.to_lowercase()));
+            assert_eq!(serde_json::from_value::<EthereumHardfork>(json).unwrap(), fork);
+        }
+
+        assert_eq!(
+            serde_json::from_value::<EthereumHardfork>(serde_json::json!("merge")).unwrap(),
+            EthereumHardfork::Paris
+        );
+        let err = serde_json::from_value::<EthereumHardfork>(serde_json::json!("not a hardfork"))
+            .unwrap_err();
+        assert!(err.to_string().contains("not a hardfork"), "{err}");
+        assert!(serde_json::from_value::<EthereumHardfork>(serde_json::json!(1)).is_err());
+    }
+
+    #[test]
+    fn check_dyn_hardfork_display() {
+        let fork: &dyn Hardfork = &EthereumHardfork::Cancun;
+        assert_eq!(fork.to_string(), "Cancun");
+        assert_eq!(format!("{:?}", fork), "Cancun");
+    }
+
+    #[test]
+    fn check_dyn_hardfork_downcast() {
+        let fork = EthereumHardfork::London.boxed();
+        assert!(fork.is::<EthereumHardfork>());
+        assert_eq!(fork.as_ethereum(), Some(EthereumHardfork::London));
+        assert_eq!(EthereumHardfork::from_dyn(&*fork), Some(EthereumHardfork::London));
+
+        // a different hardfork type with the same name is not an Ethereum hardfork
+        #[derive(Clone)]
+        struct London;
+        impl Hardfork for London {
+            fn name(&self) -> &'static str {
+                "London"
+            }
+        }
+        let fork: alloc::boxed::Box<dyn Hardfork> = alloc::boxed::Box::new(London);
+        assert!(!fork.is::<EthereumHardfork>());
+        assert_eq!(fork.as_ethereum(), None);
+    }
+
+    #[test]
+    fn check_nonexistent_hardfork_from_str() {
+        assert!(EthereumHardfork::from_str("not a hardfork").is_err());
+
+        // the error converts into the message of the former `String` error
+        let err: String = EthereumHardfork::from_str("not a hardfork").unwrap_err().into();
+        assert_eq!(err, "Unknown hardfork: not a hardfork");
+    }
+
+    #[test]
+    fn check_hardfork_alias_from_str() {
+        let hardfork_str = ["MeRgE", "tangerinewhistle", "ConstantinopleFix"];
+        let expected_hardforks =
+            [EthereumHardfork::Paris, EthereumHardfork::Tangerine, EthereumHardfork::Petersburg];
 
         let hardforks: Vec<EthereumHardfork> =
             hardfork_str.iter().map(|h| EthereumHardfork::from_str(h).unwrap()).collect();
@@ -96,7 +505,444 @@ mod tests {
     }
 
     #[test]
-    fn check_nonexistent_hardfork_from_str() {
-        assert!(EthereumHardfork::from_str("not a hardfork").is_err());
+    fn check_ambiguous_hardfork_alias_from_str() {
+        assert_eq!(
+            EthereumHardfork::from_str("Dencun"),
+            Err(ParseHardforkError::Ambiguous("Dencun".into()))
+        );
+        assert_eq!(
+            EthereumHardfork::from_str("not a hardfork"),
+            Err(ParseHardforkError::Unknown("not a hardfork".into()))
+        );
+    }
+
+    #[test]
+    fn check_all_hardforks_ordered() {
+        let all = EthereumHardfork::all();
+        assert_eq!(all.first(), Some(&EthereumHardfork::Frontier));
+        assert_eq!(all.last(), Some(&EthereumHardfork::Osaka));
+
+        // mainnet forks are listed in activation order
+        let mainnet = EthereumHardfork::mainnet().map(|(fork, _)| fork);
+        let mut forks = all.iter();
+        for fork in mainnet {
+            assert!(forks.any(|f| *f == fork), "{fork} is out of order");
+        }
+    }
+
+    #[test]
+    fn check_hardfork_ordering() {
+        assert!(EthereumHardfork::Frontier < EthereumHardfork::Homestead);
+        assert!(EthereumHardfork::MuirGlacier < EthereumHardfork::Berlin);
+        assert!(EthereumHardfork::Paris < EthereumHardfork::Shanghai);
+        assert!(EthereumHardfork::Cancun >= EthereumHardfork::London);
+
+        for forks in EthereumHardfork::all().windows(2) {
+            assert!(forks[0] < forks[1], "{} should activate before {}", forks[0], forks[1]);
+        }
+
+        assert_eq!(EthereumHardfork::Frontier.ordering(), 0);
+        assert_eq!(EthereumHardfork::Homestead.ordering(), 1);
+        assert_eq!(EthereumHardfork::Prague.ordering(), 17);
+        for (idx, fork) in EthereumHardfork::all().iter().enumerate() {
+            assert_eq!(fork.ordering() as usize, idx);
+        }
+    }
+
+    #[test]
+    fn check_associated_eips() {
+        assert!(EthereumHardfork::Frontier.associated_eips().is_empty());
+        assert_eq!(EthereumHardfork::London.associated_eips(), &[1559, 3198, 3529, 3541]);
+        assert_eq!(
+            EthereumHardfork::Cancun.associated_eips(),
+            &[1153, 4788, 4844, 5656, 6780, 7516]
+        );
+        assert!(!EthereumHardfork::Petersburg.associated_eips().contains(&1283));
+
+        // every EIP is activated by a single fork, except for the Constantinople EIPs that were
+        // re-activated by Petersburg
+        let mut eips: Vec<_> = EthereumHardfork::all()
+            .iter()
+            .filter(|fork| **fork != EthereumHardfork::Petersburg)
+            .flat_map(|fork| fork.associated_eips())
+            .collect();
+        let total = eips.len();
+        eips.sort_unstable();
+        eips.dedup();
+        assert_eq!(eips.len(), total);
+    }
+
+    #[test]
+    fn check_hardforks_init_sorted() {
+        let config = ChainConfig {
+            byzantium_block: Some(10),
+            constantinople_block: Some(5),
+            petersburg_block: Some(5),
+            terminal_total_difficulty: Some(U256::ZERO),
+            shanghai_time: Some(100),
+            cancun_time: Some(100),
+            prague_time: Some(50),
+            ..Default::default()
+        };
+
+        let forks = EthereumHardfork::init_sorted(&config);
+        assert_eq!(
+            forks,
+            vec![
+                (EthereumHardfork::Constantinople, ForkCondition::Block(5)),
+                (EthereumHardfork::Petersburg, ForkCondition::Block(5)),
+                (EthereumHardfork::Byzantium, ForkCondition::Block(10)),
+                (
+                    EthereumHardfork::Paris,
+                    ForkCondition::TTD { fork_block: None, total_difficulty: U256::ZERO }
+                ),
+                (EthereumHardfork::Prague, ForkCondition::Timestamp(50)),
+                (EthereumHardfork::Shanghai, ForkCondition::Timestamp(100)),
+                (EthereumHardfork::Cancun, ForkCondition::Timestamp(100)),
+            ]
+        );
+
+        // a TTD fork with a known fork block is ordered at that block
+        let config = ChainConfig {
+            byzantium_block: Some(10),
+            constantinople_block: Some(30),
+            terminal_total_difficulty: Some(U256::ZERO),
+            merge_netsplit_block: Some(20),
+            ..Default::default()
+        };
+        let forks = EthereumHardfork::init_sorted(&config);
+        assert_eq!(
+            forks,
+            vec![
+                (EthereumHardfork::Byzantium, ForkCondition::Block(10)),
+                (
+                    EthereumHardfork::Paris,
+                    ForkCondition::TTD { fork_block: Some(20), total_difficulty: U256::ZERO }
+                ),
+                (EthereumHardfork::Constantinople, ForkCondition::Block(30)),
+            ]
+        );
+    }
+
+    fn mainnet_config() -> ChainConfig {
+        ChainConfig {
+            homestead_block: Some(1150000),
+            dao_fork_block: Some(1920000),
+            eip150_block: Some(2463000),
+            eip155_block: Some(2675000),
+            byzantium_block: Some(4370000),
+            constantinople_block: Some(7280000),
+            petersburg_block: Some(7280000),
+            istanbul_block: Some(9069000),
+            muir_glacier_block: Some(9200000),
+            berlin_block: Some(12244000),
+            london_block: Some(12965000),
+            arrow_glacier_block: Some(13773000),
+            gray_glacier_block: Some(15050000),
+            terminal_total_difficulty: Some(U256::from(58_750_000_000_000_000_000_000_u128)),
+            shanghai_time: Some(1681338455),
+            cancun_time: Some(1710338135),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn check_fork_schedule_from_mainnet_config() {
+        let config = mainnet_config();
+
+        let schedule = EthereumForkSchedule::from_chain_config(&config);
+        assert!(schedule.london.is_some());
+        assert_eq!(schedule.prague, None);
+        assert_eq!(schedule.frontier, None);
+
+        // every configured mainnet fork maps back to its mainnet activation
+        for (fork, condition) in EthereumHardfork::mainnet() {
+            if fork != EthereumHardfork::Frontier {
+                assert_eq!(schedule.get(fork), Some(condition), "{fork}");
+            }
+        }
+        for (fork, condition) in EthereumHardfork::init(&config) {
+            assert_eq!(schedule.get(fork), Some(condition));
+        }
+    }
+
+    #[test]
+    fn check_mainnet_fork_ids() {
+        let genesis = Genesis { config: mainnet_config(), ..Default::default() };
+        let genesis_hash =
+            B256::from_str("0xd4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3")
+                .unwrap();
+
+        let cases = [
+            ((0, 0), ([0xfc, 0x64, 0xec, 0x04], 1150000)),
+            ((1149999, 0), ([0xfc, 0x64, 0xec, 0x04], 1150000)),
+            ((1150000, 0), ([0x97, 0xc2, 0xc3, 0x4c], 1920000)),
+            ((4370000, 0), ([0xa0, 0x0b, 0xc3, 0x24], 7280000)),
+            // constantinople and petersburg activate at the same block
+            ((7280000, 0), ([0x66, 0x8d, 0xb0, 0xaf], 9069000)),
+            ((12965000, 0), ([0xb7, 0x15, 0x07, 0x7d], 13773000)),
+            ((15050000, 0), ([0xf0, 0xaf, 0xd0, 0xe3], 1681338455)),
+            ((20000000, 1681338455), ([0xdc, 0xe9, 0x6c, 0x2d], 1710338135)),
+            ((20000001, 1710338135), ([0x9f, 0x3d, 0x22, 0x54], 0)),
+            ((20000002, 2000000000), ([0x9f, 0x3d, 0x22, 0x54], 0)),
+        ];
+        for ((head_block, head_timestamp), (hash, next)) in cases {
+            assert_eq!(
+                EthereumHardfork::fork_id(&genesis, genesis_hash, head_block, head_timestamp),
+                ForkId { hash: ForkHash(hash), next },
+                "head {head_block} {head_timestamp}"
+            );
+        }
+    }
+
+    #[test]
+    fn check_hardforks_active_at_genesis() {
+        let genesis = Genesis {
+            config: ChainConfig {
+                homestead_block: Some(0),
+                london_block: Some(0),
+                arrow_glacier_block: Some(10),
+                terminal_total_difficulty: Some(U256::ZERO),
+                shanghai_time: Some(0),
+                cancun_time: Some(100),
+                prague_time: Some(200),
+                ..Default::default()
+            },
+            timestamp: 100,
+            ..Default::default()
+        };
+        assert_eq!(
+            EthereumHardfork::active_at_genesis(&genesis),
+            vec![
+                EthereumHardfork::Homestead,
+                EthereumHardfork::London,
+                EthereumHardfork::Paris,
+                EthereumHardfork::Shanghai,
+                EthereumHardfork::Cancun,
+            ]
+        );
+
+        // the terminal total difficulty is not reached at genesis
+        let genesis = Genesis {
+            config: ChainConfig {
+                terminal_total_difficulty: Some(U256::from(2)),
+                ..genesis.config
+            },
+            difficulty: U256::from(1),
+            ..genesis
+        };
+        assert!(!EthereumHardfork::active_at_genesis(&genesis).contains(&EthereumHardfork::Paris));
+    }
+
+    #[test]
+    fn check_hardforks_init_all() {
+        let config = ChainConfig {
+            london_block: Some(10),
+            terminal_total_difficulty: Some(U256::ZERO),
+            shanghai_time: Some(100),
+            ..Default::default()
+        };
+
+        let forks = EthereumHardfork::init_all(&config, EthereumHardfork::all().iter().copied());
+        assert_eq!(forks.len(), EthereumHardfork::all().len());
+        for (fork, condition) in forks {
+            let expected = match fork {
+                EthereumHardfork::London => ForkCondition::Block(10),
+                EthereumHardfork::Paris => {
+                    ForkCondition::TTD { fork_block: None, total_difficulty: U256::ZERO }
+                }
+                EthereumHardfork::Shanghai => ForkCondition::Timestamp(100),
+                _ => ForkCondition::Never,
+            };
+            assert_eq!(condition, expected, "unexpected activation of {fork}");
+            assert_eq!(condition.is_never(), !condition.active_at(u64::MAX, u64::MAX, U256::MAX));
+        }
+    }
+
+    #[test]
+    fn check_hardforks_diff_configs() {
+        let config = ChainConfig {
+            london_block: Some(10),
+            terminal_total_difficulty: Some(U256::ZERO),
+            shanghai_time: Some(100),
+            cancun_time: Some(200),
+            ..Default::default()
+        };
+        assert!(EthereumHardfork::diff_configs(&config, &config).is_empty());
+
+        let other = ChainConfig {
+            berlin_block: Some(5),
+            london_block: Some(20),
+            terminal_total_difficulty: Some(U256::ZERO),
+            shanghai_time: Some(100),
+            ..Default::default()
+        };
+        assert_eq!(
+            EthereumHardfork::diff_configs(&config, &other),
+            vec![
+                ConfiguredHardforkDiff::Added {
+                    fork: EthereumHardfork::Berlin,
+                    condition: ForkCondition::Block(5)
+                },
+                ConfiguredHardforkDiff::Changed {
+                    fork: EthereumHardfork::London,
+                    old: ForkCondition::Block(10),
+                    new: ForkCondition::Block(20)
+                },
+                ConfiguredHardforkDiff::Removed {
+                    fork: EthereumHardfork::Cancun,
+                    condition: ForkCondition::Timestamp(200)
+                },
+            ]
+        );
+
+        /// A fork that is activated by block in some configs and by timestamp in others.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        struct TestHardfork;
+
+        impl Hardfork for TestHardfork {
+            fn name(&self) -> &'static str {
+                "Test"
+            }
+        }
+
+        impl ConfigureHardforks for TestHardfork {
+            fn init_block_hardforks(
+                config: &ChainConfig,
+            ) -> impl Iterator<Item = (Self, ForkCondition)> {
+                config.berlin_block.map(|block| (Self, ForkCondition::Block(block))).into_iter()
+            }
+
+            fn init_time_hardforks(
+                config: &ChainConfig,
+            ) -> impl Iterator<Item = (Self, ForkCondition)> {
+                config.shanghai_time.map(|time| (Self, ForkCondition::Timestamp(time))).into_iter()
+            }
+        }
+
+        // switching from block to timestamp based activation is a change of the fork
+        let block_based = ChainConfig { berlin_block: Some(5), ..Default::default() };
+        let time_based = ChainConfig { shanghai_time: Some(100), ..Default::default() };
+        assert_eq!(
+            TestHardfork::diff_configs(&block_based, &time_based),
+            vec![ConfiguredHardforkDiff::Changed {
+                fork: TestHardfork,
+                old: ForkCondition::Block(5),
+                new: ForkCondition::Timestamp(100)
+            }]
+        );
+    }
+
+    #[test]
+    fn check_custom_hardforks() {
+        /// An upgrade of a protocol fork that is not known to this crate.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        struct Wonderland;
+
+        impl Hardfork for Wonderland {
+            fn name(&self) -> &'static str {
+                "Wonderland"
+            }
+        }
+
+        /// The hardforks of the protocol fork, Ethereum forks with an additional upgrade.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+        struct TestHardfork(EthereumHardfork);
+
+        impl Hardfork for TestHardfork {
+            fn name(&self) -> &'static str {
+                self.0.name()
+            }
+        }
+
+        impl ConfigureHardforks for TestHardfork {
+            fn init_block_hardforks(
+                config: &ChainConfig,
+            ) -> impl Iterator<Item = (Self, ForkCondition)> {
+                EthereumHardfork::init_block_hardforks(config).map(|(fork, c)| (Self(fork), c))
+            }
+
+            fn init_time_hardforks(
+                config: &ChainConfig,
+            ) -> impl Iterator<Item = (Self, ForkCondition)> {
+                EthereumHardfork::init_time_hardforks(config).map(|(fork, c)| (Self(fork), c))
+            }
+
+            fn custom_hardforks(
+                config: &ChainConfig,
+            ) -> impl Iterator<Item = (Box<dyn Hardfork>, ForkCondition)> {
+                config
+                    .extra_fields
+                    .get_deserialized::<u64>("wonderlandTime")
+                    .and_then(Result::ok)
+                    .map(|time| {
+                        (Box::new(Wonderland) as Box<dyn Hardfork>, ForkCondition::Timestamp(time))
+                    })
+                    .into_iter()
+            }
+        }
+
+        let mut config = ChainConfig {
+            london_block: Some(0),
+            terminal_total_difficulty: Some(U256::ZERO),
+            shanghai_time: Some(100),
+            cancun_time: Some(200),
+            ..Default::default()
+        };
+
+        // without custom forks, the Ethereum forks are unchanged
+        let forks = TestHardfork::init_chain_hardforks(&config);
+        assert_eq!(forks.len(), 4);
+        assert_eq!(forks.get(Wonderland), None);
+
+        config.extra_fields.insert("wonderlandTime".into(), 100.into());
+        let forks = TestHardfork::init_chain_hardforks(&config);
+        assert_eq!(
+            forks
+                .forks_iter()
+                .map(|(fork, condition)| (fork.name(), condition))
+                .collect::<Vec<_>>(),
+            vec![
+                ("London", ForkCondition::Block(0)),
+                ("Paris", ForkCondition::TTD { fork_block: None, total_difficulty: U256::ZERO }),
+                ("Shanghai", ForkCondition::Timestamp(100)),
+                ("Wonderland", ForkCondition::Timestamp(100)),
+                ("Cancun", ForkCondition::Timestamp(200)),
+            ]
+        );
+        assert!(forks.is_fork_active_at_timestamp(Wonderland, 100));
+        assert!(!forks.is_fork_active_at_timestamp(Wonderland, 99));
+    }
+
+    #[test]
+    fn check_hardfork_activation_from_config() {
+        let config = ChainConfig {
+            london_block: Some(12965000),
+            terminal_total_difficulty: Some(U256::from(1000)),
+            shanghai_time: Some(1681338455),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            EthereumHardfork::London.activation(&config),
+            Some(ForkCondition::Block(12965000))
+        );
+        assert_eq!(
+            EthereumHardfork::Paris.activation(&config),
+            Some(ForkCondition::TTD { fork_block: None, total_difficulty: U256::from(1000) })
+        );
+        assert_eq!(
+            EthereumHardfork::Shanghai.activation(&config),
+            Some(ForkCondition::Timestamp(1681338455))
+        );
+
+        // forks that are not configured are not assumed to be active at genesis
+        assert_eq!(EthereumHardfork::Berlin.activation(&config), None);
+        assert_eq!(EthereumHardfork::Cancun.activation(&config), None);
+
+        // the activation can be resolved without knowing the concrete hardfork type
+        let forks = [EthereumHardfork::London.boxed(), EthereumHardfork::Cancun.boxed()];
+        let activations: Vec<_> = forks.iter().map(|fork| fork.activation(&config)).collect();
+        assert_eq!(activations, [Some(ForkCondition::Block(12965000)), None]);
     }
 }
@@ -0,0 +1,227 @@
+use super::activation_key;
+use crate::{ForkCondition, Hardfork};
+use alloc::{boxed::Box, string::String};
+use alloy_primitives::{BlockNumber, U256};
+use core::cmp::Ordering;
+
+/// A hardfork together with its [`ForkCondition`], ordered by activation.
+///
+/// Entries are ordered like [`ConfigureHardforks::init_sorted`](crate::ConfigureHardforks): block
+/// based forks first, followed by TTD based forks, timestamp based forks and forks that never
+/// activate. TTD based forks with a known fork block are ordered like block based forks at that
+/// block. Forks that activate at the same point are ordered by name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForkScheduleEntry {
+    fork: Box<dyn Hardfork>,
+    condition: ForkCondition,
+}
+
+impl ForkScheduleEntry {
+    /// Creates a new entry for the given hardfork and its [`ForkCondition`].
+    pub fn new(fork: Box<dyn Hardfork>, condition: ForkCondition) -> Self {
+        Self { fork, condition }
+    }
+
+    /// Returns the hardfork.
+    pub fn fork(&self) -> &dyn Hardfork {
+        &*self.fork
+    }
+
+    /// Returns the [`ForkCondition`] of the hardfork.
+    pub const fn condition(&self) -> ForkCondition {
+        self.condition
+    }
+
+    /// Returns the hardfork and its [`ForkCondition`].
+    pub fn into_inner(self) -> (Box<dyn Hardfork>, ForkCondition) {
+        (self.fork, self.condition)
+    }
+}
+
+impl From<(Box<dyn Hardfork>, ForkCondition)> for ForkScheduleEntry {
+    fn from((fork, condition): (Box<dyn Hardfork>, ForkCondition)) -> Self {
+        Self::new(fork, condition)
+    }
+}
+
+impl PartialOrd for ForkScheduleEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ForkScheduleEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        /// Returns the terminal total difficulty and the fork block of TTD based forks, which are
+        /// not part of the activation key.
+        const fn ttd(condition: &ForkCondition) -> Option<(U256, Option<BlockNumber>)> {
+            match condition {
+                ForkCondition::TTD { fork_block, total_difficulty } => {
+                    Some((*total_difficulty, *fork_block))
+                }
+                _ => None,
+            }
+        }
+
+        activation_key(&self.condition)
+            .cmp(&activation_key(&other.condition))
+            .then_with(|| self.fork.name().cmp(other.fork.name()))
+            .then_with(|| ttd(&self.condition).cmp(&ttd(&other.condition)))
+    }
+}
+
+/// A hardfork together with its activation, as resolved from a chain configuration.
+///
+/// This is serialized as `{"name": "London", "type": "block", "value": 12965000}`. The terminal
+/// total difficulty of TTD based forks is serialized as a decimal string to avoid precision loss.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScheduledHardfork {
+    /// Name of the hardfork.
+    pub name: String,
+    /// Activation of the hardfork.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub activation: ScheduledActivation,
+}
+
+impl ScheduledHardfork {
+    /// Creates a new entry for the given hardfork and its [`ForkCondition`].
+    pub fn new(fork: &dyn Hardfork, condition: ForkCondition) -> Self {
+        Self { name: fork.name().into(), activation: condition.into() }
+    }
+
+    /// Returns the [`ForkCondition`] of this entry.
+    pub fn condition(&self) -> ForkCondition {
+        self.activation.into()
+    }
+}
+
+/// The activation of a [`ScheduledHardfork`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "lowercase"))]
+pub enum ScheduledActivation {
+    /// The fork is activated at a block number.
+    Block {
+        /// The activation block number.
+        value: BlockNumber,
+    },
+    /// The fork is activated at a timestamp.
+    Timestamp {
+        /// The activation timestamp.
+        value: u64,
+    },
+    /// The fork is activated once the terminal total difficulty is reached.
+    Ttd {
+        /// The terminal total difficulty.
+        #[cfg_attr(feature = "serde", serde(with = "crate::forkcondition::ttd_decimal"))]
+        value: U256,
+        /// The block at which the terminal total difficulty is reached, if known.
+        #[cfg_attr(
+            feature = "serde",
+            serde(rename = "forkBlock", default, skip_serializing_if = "Option::is_none")
+        )]
+        fork_block: Option<BlockNumber>,
+    },
+    /// The fork is never activated.
+    Never,
+}
+
+impl From<ForkCondition> for ScheduledActivation {
+    fn from(condition: ForkCondition) -> Self {
+        match condition {
+            ForkCondition::Block(value) => Self::Block { value },
+            ForkCondition::Timestamp(value) => Self::Timestamp { value },
+            ForkCondition::TTD { fork_block, total_difficulty } => {
+                Self::Ttd { value: total_difficulty, fork_block }
+            }
+            ForkCondition::Never => Self::Never,
+        }
+    }
+}
+
+impl From<ScheduledActivation> for ForkCondition {
+    fn from(activation: ScheduledActivation) -> Self {
+        match activation {
+            ScheduledActivation::Block { value } => Self::Block(value),
+            ScheduledActivation::Timestamp { value } => Self::Timestamp(value),
+            ScheduledActivation::Ttd { value, fork_block } => {
+                Self::TTD { fork_block, total_difficulty: value }
+            }
+            ScheduledActivation::Never => Self::Never,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EthereumHardfork;
+
+    #[test]
+    fn fork_schedule_entry_ordering() {
+        let entry =
+            |fork: EthereumHardfork, condition| ForkScheduleEntry::new(fork.boxed(), condition);
+        let ttd = |total_difficulty| ForkCondition::TTD { fork_block: None, total_difficulty };
+
+        let expected = vec![
+            entry(EthereumHardfork::Homestead, ForkCondition::Block(0)),
+            entry(EthereumHardfork::Constantinople, ForkCondition::Block(5)),
+            entry(EthereumHardfork::Petersburg, ForkCondition::Block(5)),
+            entry(EthereumHardfork::Byzantium, ForkCondition::Block(10)),
+            entry(EthereumHardfork::Paris, ttd(U256::ZERO)),
+            entry(EthereumHardfork::Paris, ttd(U256::from(1))),
+            entry(EthereumHardfork::Prague, ForkCondition::Timestamp(50)),
+            entry(EthereumHardfork::Cancun, ForkCondition::Timestamp(100)),
+            entry(EthereumHardfork::Shanghai, ForkCondition::Timestamp(100)),
+            entry(EthereumHardfork::Osaka, ForkCondition::Never),
+        ];
+
+        let mut entries: Vec<_> =
+            [5, 2, 9, 0, 7, 3, 8, 1, 6, 4].into_iter().map(|idx| expected[idx].clone()).collect();
+        assert_ne!(entries, expected);
+        entries.sort();
+        assert_eq!(entries, expected);
+
+        let (fork, condition) = entries.remove(3).into_inner();
+        assert_eq!(fork.as_ethereum(), Some(EthereumHardfork::Byzantium));
+        assert_eq!(condition, ForkCondition::Block(10));
+        assert_eq!(entries[0].fork().name(), "Homestead");
+        assert_eq!(entries[0].condition(), ForkCondition::Block(0));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn schedule_json_roundtrip() {
+        use crate::ConfigureHardforks;
+        use alloy_genesis::ChainConfig;
+        use alloy_primitives::uint;
+
+        let config = ChainConfig {
+            homestead_block: Some(1150000),
+            london_block: Some(12965000),
+            terminal_total_difficulty: Some(uint!(58_750_000_000_000_000_000_000_U256)),
+            shanghai_time: Some(1681338455),
+            ..Default::default()
+        };
+
+        let schedule = EthereumHardfork::schedule(&config);
+        let json = serde_json::to_value(&schedule).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                { "name": "Homestead", "type": "block", "value": 1150000 },
+                { "name": "London", "type": "block", "value": 12965000 },
+                { "name": "Paris", "type": "ttd", "value": "58750000000000000000000" },
+                { "name": "Shanghai", "type": "timestamp", "value": 1681338455 },
+            ])
+        );
+
+        let decoded: Vec<ScheduledHardfork> = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, schedule);
+        assert_eq!(
+            decoded[2].condition().ttd(),
+            Some(U256::from(58_750_000_000_000_000_000_000u128))
+        );
+    }
+}
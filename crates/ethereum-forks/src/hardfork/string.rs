@@ -0,0 +1,132 @@
+use crate::Hardfork;
+use alloc::{boxed::Box, string::String, sync::Arc};
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    hash::{Hash, Hasher},
+};
+
+/// A [`Hardfork`] that is identified by its name only.
+///
+/// This allows chains with named forks that are neither an
+/// [`EthereumHardfork`](crate::EthereumHardfork) nor part of another hardfork enum, e.g. devnets,
+/// to use [`ChainHardforks`](crate::ChainHardforks) without defining a new hardfork type.
+///
+/// Hardforks are identified by their name in [`ChainHardforks`](crate::ChainHardforks), so a
+/// `StringHardfork` with the name of a known hardfork, e.g. `"London"`, refers to that hardfork.
+///
+/// Since [`Hardfork::name`] returns a `&'static str`, the name of a hardfork that is created from
+/// a non-static string is leaked once when the hardfork is created. Clones share the leaked name.
+/// This is intended for the few forks that are configured when a chain spec is built, not for
+/// hardforks that are created repeatedly.
+#[derive(Debug, Clone)]
+pub struct StringHardfork {
+    name: Arc<str>,
+    /// The name as returned by [`Hardfork::name`].
+    static_name: &'static str,
+}
+
+impl StringHardfork {
+    /// Creates a new hardfork with the given name.
+    ///
+    /// The name is leaked, use [`StringHardfork::from`] with a `&'static str` to avoid this.
+    pub fn new(name: impl Into<Arc<str>>) -> Self {
+        let name = name.into();
+        let static_name = Box::leak(Box::<str>::from(&*name));
+        Self { name, static_name }
+    }
+
+    /// Returns the name of the hardfork.
+    pub fn as_str(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Hardfork for StringHardfork {
+    fn name(&self) -> &'static str {
+        self.static_name
+    }
+}
+
+impl PartialEq for StringHardfork {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for StringHardfork {}
+
+impl Hash for StringHardfork {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
+impl PartialOrd for StringHardfork {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for StringHardfork {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.name.cmp(&other.name)
+    }
+}
+
+impl From<&'static str> for StringHardfork {
+    fn from(name: &'static str) -> Self {
+        Self { name: name.into(), static_name: name }
+    }
+}
+
+impl From<String> for StringHardfork {
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+impl Display for StringHardfork {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ChainHardforks, EthereumHardfork, ForkCondition};
+    use alloc::{string::ToString, vec};
+
+    #[test]
+    fn string_hardfork_in_chain_hardforks() {
+        let custom = StringHardfork::from("Custom");
+        let mut hardforks = ChainHardforks::new(vec![
+            (EthereumHardfork::London.boxed(), ForkCondition::Block(0)),
+            (Box::new(custom.clone()), ForkCondition::Block(10)),
+        ]);
+        assert_eq!(hardforks.fork(custom.clone()), ForkCondition::Block(10));
+        assert!(!hardforks.is_fork_active_at_block(custom.clone(), 9));
+        assert!(hardforks.is_fork_active_at_block(custom.clone(), 10));
+
+        // a hardfork created from an owned name refers to the same fork
+        let owned = StringHardfork::from("Custom".to_string());
+        assert_eq!(owned, custom);
+        hardforks.insert(owned, ForkCondition::Timestamp(1));
+        assert_eq!(hardforks.fork(custom.clone()), ForkCondition::Timestamp(1));
+
+        // the name of a known hardfork refers to that hardfork
+        assert_eq!(hardforks.fork(StringHardfork::new("London")), ForkCondition::Block(0));
+        assert_eq!(custom.to_string(), "Custom");
+    }
+
+    #[test]
+    fn string_hardfork_name() {
+        let name = "Static";
+        assert!(core::ptr::eq(StringHardfork::from(name).name(), name));
+
+        let owned = StringHardfork::from("Owned".to_string());
+        assert_eq!(owned.name(), "Owned");
+        assert!(core::ptr::eq(owned.clone().name(), owned.name()));
+    }
+}
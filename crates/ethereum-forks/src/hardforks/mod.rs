@@ -2,7 +2,7 @@
 mod ethereum;
 pub use ethereum::EthereumHardforks;
 
-use crate::{ForkCondition, ForkFilter, ForkId, Hardfork, Head};
+use crate::{EthereumHardfork, ForkCondition, ForkFilter, ForkId, Hardfork, Head};
 #[cfg(feature = "std")]
 use rustc_hash::FxHashMap;
 #[cfg(feature = "std")]
@@ -103,6 +103,44 @@ impl ChainHardforks {
         self.forks.last().map(|(f, b)| (f.clone(), *b))
     }
 
+    /// Returns the next hardfork that is not yet active at the given block number and timestamp,
+    /// together with its activation condition.
+    ///
+    /// Forks without a known activation point, i.e. [`ForkCondition::Never`] and TTD based forks
+    /// without a fork block, are skipped.
+    pub fn next_fork(&self, block: u64, timestamp: u64) -> Option<(&dyn Hardfork, ForkCondition)> {
+        self.forks_iter().find(|(_, condition)| match condition {
+            ForkCondition::Block(_) | ForkCondition::TTD { fork_block: Some(_), .. } => {
+                !condition.active_at_block(block)
+            }
+            ForkCondition::Timestamp(_) => !condition.active_at_timestamp(timestamp),
+            ForkCondition::TTD { fork_block: None, .. } | ForkCondition::Never => false,
+        })
+    }
+
+    /// Returns the differences between this and the `other` list of hardforks.
+    ///
+    /// Forks of this list are reported first, in order, followed by the forks that are only
+    /// present in `other`.
+    pub fn diff<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = HardforkDiff<'a>> {
+        let changed = self.forks.iter().filter_map(move |(fork, condition)| {
+            let fork = &**fork;
+            match other.map.get(fork.name()) {
+                None => Some(HardforkDiff::Missing { fork, condition: *condition }),
+                Some(other) if other != condition => {
+                    Some(HardforkDiff::Changed { fork, condition: *condition, other: *other })
+                }
+                Some(_) => None,
+            }
+        });
+        let added =
+            other.forks.iter().filter(move |(fork, _)| !self.map.contains_key(fork.name())).map(
+                |(fork, condition)| HardforkDiff::Added { fork: &**fork, condition: *condition },
+            );
+
+        changed.chain(added)
+    }
+
     /// Convenience method to check if a fork is active at a given timestamp.
     pub fn is_fork_active_at_timestamp<H: Hardfork>(&self, fork: H, timestamp: u64) -> bool {
         self.fork(fork).active_at_timestamp(timestamp)
@@ -136,6 +174,158 @@ impl ChainHardforks {
         self.forks.retain(|(inner_fork, _)| inner_fork.name() != fork.name());
         self.map.remove(fork.name());
     }
+
+    /// Checks that the fork schedule is consistent:
+    ///
+    /// - [`EthereumHardfork`]s are listed in their protocol activation order.
+    /// - Block based forks don't activate before an earlier listed block based fork, and the same
+    ///   applies to timestamp based forks.
+    /// - Block based forks are listed before timestamp based forks.
+    /// - A TTD based fork is scheduled if and only if [`EthereumHardfork::Paris`] is scheduled.
+    ///
+    /// Forks that are never activated, and TTD based forks without a fork block, are only
+    /// subject to the first and last check.
+    pub fn validate(&self) -> Result<(), HardforkValidationError> {
+        let mut last_ethereum: Option<(EthereumHardfork, &dyn Hardfork)> = None;
+        let mut last_block: Option<(&dyn Hardfork, u64)> = None;
+        let mut last_timestamp: Option<(&dyn Hardfork, u64)> = None;
+        let mut ttd_fork = None;
+
+        for (fork, condition) in self.forks_iter() {
+            if let Some(ethereum) = EthereumHardfork::from_dyn(fork) {
+                if let Some((previous, previous_fork)) = last_ethereum {
+                    if ethereum < previous {
+                        return Err(HardforkValidationError::OutOfOrder {
+                            fork: fork.name(),
+                            previous: previous_fork.name(),
+                        })
+                    }
+                }
+                last_ethereum = Some((ethereum, fork));
+            }
+
+            match condition {
+                ForkCondition::Block(block) |
+                ForkCondition::TTD { fork_block: Some(block), .. } => {
+                    if let Some((previous, _)) = last_timestamp {
+                        return Err(HardforkValidationError::BlockAfterTimestamp {
+                            fork: fork.name(),
+                            previous: previous.name(),
+                        })
+                    }
+                    if let Some((previous, previous_block)) = last_block {
+                        if block < previous_block {
+                            return Err(HardforkValidationError::ActivatesEarlier {
+                                fork: fork.name(),
+                                previous: previous.name(),
+                            })
+                        }
+                    }
+                    last_block = Some((fork, block));
+                }
+                ForkCondition::Timestamp(timestamp) => {
+                    if let Some((previous, previous_timestamp)) = last_timestamp {
+                        if timestamp < previous_timestamp {
+                            return Err(HardforkValidationError::ActivatesEarlier {
+                                fork: fork.name(),
+                                previous: previous.name(),
+                            })
+                        }
+                    }
+                    last_timestamp = Some((fork, timestamp));
+                }
+                ForkCondition::TTD { fork_block: None, .. } | ForkCondition::Never => {}
+            }
+
+            if matches!(condition, ForkCondition::TTD { .. }) && ttd_fork.is_none() {
+                ttd_fork = Some(fork.name());
+            }
+        }
+
+        let paris_scheduled = self.get(EthereumHardfork::Paris).is_some_and(|c| !c.is_never());
+        match (paris_scheduled, ttd_fork) {
+            (true, None) => Err(HardforkValidationError::ParisWithoutTtd),
+            (false, Some(fork)) => Err(HardforkValidationError::TtdWithoutParis { fork }),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Error returned by [`ChainHardforks::validate`] if the fork schedule is inconsistent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror_no_std::Error)]
+pub enum HardforkValidationError {
+    /// An Ethereum fork is listed after a fork that succeeded it on mainnet.
+    #[error("{fork} is listed after {previous}, but precedes it")]
+    OutOfOrder {
+        /// The misplaced fork.
+        fork: &'static str,
+        /// The earlier listed fork that succeeds it.
+        previous: &'static str,
+    },
+    /// A fork activates before an earlier listed fork.
+    #[error("{fork} activates before {previous}, but is listed after it")]
+    ActivatesEarlier {
+        /// The fork that activates too early.
+        fork: &'static str,
+        /// The earlier listed fork.
+        previous: &'static str,
+    },
+    /// A block based fork is listed after a timestamp based fork.
+    #[error("block based fork {fork} is listed after timestamp based fork {previous}")]
+    BlockAfterTimestamp {
+        /// The block based fork.
+        fork: &'static str,
+        /// The earlier listed timestamp based fork.
+        previous: &'static str,
+    },
+    /// Paris is scheduled, but no fork is activated by a terminal total difficulty.
+    #[error("Paris is scheduled without a TTD based fork")]
+    ParisWithoutTtd,
+    /// A fork is activated by a terminal total difficulty, but Paris is not scheduled.
+    #[error("{fork} is activated by TTD, but Paris is not scheduled")]
+    TtdWithoutParis {
+        /// The TTD based fork.
+        fork: &'static str,
+    },
+}
+
+/// A difference between two [`ChainHardforks`], see [`ChainHardforks::diff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HardforkDiff<'a> {
+    /// The fork is only present in the first list.
+    Missing {
+        /// The hardfork.
+        fork: &'a (dyn Hardfork + 'static),
+        /// The activation condition in the first list.
+        condition: ForkCondition,
+    },
+    /// The fork is only present in the other list.
+    Added {
+        /// The hardfork.
+        fork: &'a (dyn Hardfork + 'static),
+        /// The activation condition in the other list.
+        condition: ForkCondition,
+    },
+    /// The fork is present in both lists, with different activation conditions.
+    Changed {
+        /// The hardfork.
+        fork: &'a (dyn Hardfork + 'static),
+        /// The activation condition in the first list.
+        condition: ForkCondition,
+        /// The activation condition in the other list.
+        other: ForkCondition,
+    },
+}
+
+impl HardforkDiff<'_> {
+    /// Returns the hardfork that differs.
+    pub const fn fork(&self) -> &dyn Hardfork {
+        match self {
+            Self::Missing { fork, .. } | Self::Added { fork, .. } | Self::Changed { fork, .. } => {
+                *fork
+            }
+        }
+    }
 }
 
 impl core::fmt::Debug for ChainHardforks {
@@ -145,3 +335,122 @@ impl core::fmt::Debug for ChainHardforks {
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::U256;
+
+    #[test]
+    fn next_fork_mainnet() {
+        let hardforks: ChainHardforks = EthereumHardfork::mainnet().into();
+
+        let (fork, condition) = hardforks.next_fork(0, 1438226773).unwrap();
+        assert_eq!(fork.name(), EthereumHardfork::Homestead.name());
+        assert_eq!(condition, ForkCondition::Block(1150000));
+
+        // the block based fork is active at its activation block
+        let (fork, _) = hardforks.next_fork(1150000, 1457938193).unwrap();
+        assert_eq!(fork.name(), EthereumHardfork::Dao.name());
+
+        // paris has no known fork block on mainnet and is skipped
+        let (fork, condition) = hardforks.next_fork(15050000, 1656586444).unwrap();
+        assert_eq!(fork.name(), EthereumHardfork::Shanghai.name());
+        assert_eq!(condition, ForkCondition::Timestamp(1681338455));
+
+        assert!(hardforks.next_fork(19426587, 1710338135).is_none());
+    }
+
+    #[test]
+    fn diff_hardforks() {
+        let mainnet: ChainHardforks = EthereumHardfork::mainnet().into();
+        assert_eq!(mainnet.diff(&mainnet).count(), 0);
+
+        let mut devnet = mainnet.clone();
+        devnet.remove(EthereumHardfork::Dao);
+        devnet.insert(EthereumHardfork::Cancun, ForkCondition::Timestamp(0));
+        devnet.insert(EthereumHardfork::Prague, ForkCondition::Timestamp(1));
+
+        let diff: Vec<_> = mainnet.diff(&devnet).collect();
+        assert_eq!(
+            diff,
+            vec![
+                HardforkDiff::Missing {
+                    fork: &EthereumHardfork::Dao,
+                    condition: ForkCondition::Block(1920000)
+                },
+                HardforkDiff::Changed {
+                    fork: &EthereumHardfork::Cancun,
+                    condition: ForkCondition::Timestamp(1710338135),
+                    other: ForkCondition::Timestamp(0)
+                },
+                HardforkDiff::Added {
+                    fork: &EthereumHardfork::Prague,
+                    condition: ForkCondition::Timestamp(1)
+                },
+            ]
+        );
+        assert_eq!(diff[0].fork().name(), EthereumHardfork::Dao.name());
+    }
+
+    #[test]
+    fn validate_hardforks() {
+        for hardforks in [
+            ChainHardforks::from(EthereumHardfork::mainnet()),
+            EthereumHardfork::sepolia().into(),
+            EthereumHardfork::holesky().into(),
+            crate::DEV_HARDFORKS.clone(),
+        ] {
+            hardforks.validate().unwrap();
+        }
+
+        let mainnet: ChainHardforks = EthereumHardfork::mainnet().into();
+
+        let mut hardforks = mainnet.clone();
+        hardforks.remove(EthereumHardfork::Paris);
+        hardforks.insert(EthereumHardfork::Paris, ForkCondition::Block(15537394));
+        assert_eq!(
+            hardforks.validate(),
+            Err(HardforkValidationError::OutOfOrder { fork: "Paris", previous: "Cancun" })
+        );
+
+        let mut hardforks = mainnet.clone();
+        hardforks.insert(EthereumHardfork::London, ForkCondition::Block(1));
+        assert_eq!(
+            hardforks.validate(),
+            Err(HardforkValidationError::ActivatesEarlier { fork: "London", previous: "Berlin" })
+        );
+
+        let mut hardforks = mainnet.clone();
+        hardforks.insert(EthereumHardfork::Shanghai, ForkCondition::Timestamp(1710338136));
+        assert_eq!(
+            hardforks.validate(),
+            Err(HardforkValidationError::ActivatesEarlier { fork: "Cancun", previous: "Shanghai" })
+        );
+
+        let mut hardforks = mainnet.clone();
+        hardforks.insert(EthereumHardfork::Berlin, ForkCondition::Timestamp(1618481223));
+        assert_eq!(
+            hardforks.validate(),
+            Err(HardforkValidationError::BlockAfterTimestamp {
+                fork: "London",
+                previous: "Berlin"
+            })
+        );
+
+        let mut hardforks = mainnet.clone();
+        hardforks.insert(EthereumHardfork::Paris, ForkCondition::Block(15537394));
+        assert_eq!(hardforks.validate(), Err(HardforkValidationError::ParisWithoutTtd));
+
+        let mut hardforks = mainnet;
+        hardforks.insert(EthereumHardfork::Paris, ForkCondition::Never);
+        hardforks.insert(
+            EthereumHardfork::GrayGlacier,
+            ForkCondition::TTD { fork_block: None, total_difficulty: U256::from(1) },
+        );
+        assert_eq!(
+            hardforks.validate(),
+            Err(HardforkValidationError::TtdWithoutParis { fork: "GrayGlacier" })
+        );
+    }
+}
@@ -27,11 +27,15 @@ mod head;
 pub use forkid::{
     EnrForkIdEntry, ForkFilter, ForkFilterKey, ForkHash, ForkId, ForkTransition, ValidationError,
 };
-pub use hardfork::{EthereumHardfork, Hardfork, DEV_HARDFORKS};
+pub use hardfork::{
+    ConfigureHardforks, ConfiguredHardforkDiff, EthereumForkSchedule, EthereumHardfork,
+    ForkScheduleEntry, Hardfork, ParseHardforkError, ScheduledActivation, ScheduledHardfork,
+    StringHardfork, DEV_HARDFORKS,
+};
 pub use head::Head;
 
 pub use display::DisplayHardforks;
-pub use forkcondition::ForkCondition;
+pub use forkcondition::{ForkCondition, ParseForkConditionError};
 pub use hardforks::*;
 
 #[cfg(any(test, feature = "arbitrary"))]
@@ -9,6 +9,7 @@
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
 use alloy_consensus::EMPTY_OMMER_ROOT_HASH;
+use alloy_eips::eip4844::MAX_DATA_GAS_PER_BLOCK;
 use alloy_primitives::U256;
 use reth_chainspec::{EthChainSpec, EthereumHardfork, EthereumHardforks};
 use reth_consensus::{Consensus, ConsensusError, PostExecutionInput};
@@ -19,7 +20,8 @@ use reth_consensus_common::validation::{
     validate_header_extradata, validate_header_gas,
 };
 use reth_primitives::{
-    constants::MINIMUM_GAS_LIMIT, BlockWithSenders, Header, SealedBlock, SealedHeader,
+    constants::MINIMUM_GAS_LIMIT, BlockWithSenders, GotExpected, Header, SealedBlock, SealedHeader,
+    TransactionSigned,
 };
 use std::{fmt::Debug, sync::Arc, time::SystemTime};
 
@@ -213,7 +215,43 @@ impl<ChainSpec: Send + Sync + EthChainSpec + EthereumHardforks + Debug> Consensu
     }
 
     fn validate_block_pre_execution(&self, block: &SealedBlock) -> Result<(), ConsensusError> {
-        validate_block_pre_execution(block, &self.chain_spec)
+        validate_block_pre_execution(block, &self.chain_spec)?;
+
+        // EIP-4844: Shard Blob Transactions
+        if self.chain_spec.is_cancun_active_at_timestamp(block.timestamp) {
+            self.validate_blob_gas_usage(&block.header, &block.body.transactions)?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the blob gas used by all blob transactions matches the header's
+    /// `blob_gas_used` and that it doesn't exceed [`MAX_DATA_GAS_PER_BLOCK`].
+    ///
+    /// This is part of [`Consensus::validate_block_pre_execution`] once Cancun is active.
+    fn validate_blob_gas_usage(
+        &self,
+        header: &SealedHeader,
+        transactions: &[TransactionSigned],
+    ) -> Result<(), ConsensusError> {
+        let header_blob_gas_used =
+            header.blob_gas_used.ok_or(ConsensusError::BlobGasUsedMissing)?;
+        let total_blob_gas = transactions.iter().filter_map(|tx| tx.blob_gas_used()).sum();
+        if total_blob_gas != header_blob_gas_used {
+            return Err(ConsensusError::BlobGasUsedDiff(GotExpected {
+                got: header_blob_gas_used,
+                expected: total_blob_gas,
+            }))
+        }
+
+        if total_blob_gas > MAX_DATA_GAS_PER_BLOCK {
+            return Err(ConsensusError::BlobGasUsedExceedsMaxBlobGasPerBlock {
+                blob_gas_used: total_blob_gas,
+                max_blob_gas_per_block: MAX_DATA_GAS_PER_BLOCK,
+            })
+        }
+
+        Ok(())
     }
 
     fn validate_block_post_execution(
@@ -228,9 +266,14 @@ impl<ChainSpec: Send + Sync + EthChainSpec + EthereumHardforks + Debug> Consensu
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::{Sealable, B256};
+    use alloy_consensus::TxEip4844;
+    use alloy_eips::{
+        eip4844::{DATA_GAS_PER_BLOB, MAX_DATA_GAS_PER_BLOCK},
+        eip4895::Withdrawal,
+    };
+    use alloy_primitives::{PrimitiveSignature as Signature, Sealable, B256};
     use reth_chainspec::{ChainSpec, ChainSpecBuilder};
-    use reth_primitives::proofs;
+    use reth_primitives::{proofs, BlockBody, Transaction, Withdrawals};
 
     fn header_with_gas_limit(gas_limit: u64) -> SealedHeader {
         let header = Header { gas_limit, ..Default::default() };
@@ -326,4 +369,152 @@ mod tests {
             Ok(())
         );
     }
+
+    fn blob_tx(num_blobs: usize) -> TransactionSigned {
+        let tx = Transaction::Eip4844(TxEip4844 {
+            blob_versioned_hashes: vec![B256::ZERO; num_blobs],
+            ..Default::default()
+        });
+        TransactionSigned::from_transaction_and_signature(tx, Signature::test_signature())
+    }
+
+    fn header_with_blob_gas(blob_gas_used: u64) -> SealedHeader {
+        let header = Header {
+            blob_gas_used: Some(blob_gas_used),
+            excess_blob_gas: Some(0),
+            ..Default::default()
+        };
+        SealedHeader::new(header, B256::ZERO)
+    }
+
+    #[test]
+    fn test_validate_blob_gas_usage() {
+        let consensus = EthBeaconConsensus::new(Arc::new(ChainSpec::default()));
+        let transactions = vec![blob_tx(2), blob_tx(1)];
+
+        let header = header_with_blob_gas(3 * DATA_GAS_PER_BLOB);
+        assert_eq!(consensus.validate_blob_gas_usage(&header, &transactions), Ok(()));
+
+        // header doesn't match the blob gas used by the transactions
+        let header = header_with_blob_gas(2 * DATA_GAS_PER_BLOB);
+        assert_eq!(
+            consensus.validate_blob_gas_usage(&header, &transactions),
+            Err(ConsensusError::BlobGasUsedDiff(GotExpected {
+                got: 2 * DATA_GAS_PER_BLOB,
+                expected: 3 * DATA_GAS_PER_BLOB
+            }))
+        );
+
+        // too many blobs in the block
+        let max_blobs = (MAX_DATA_GAS_PER_BLOCK / DATA_GAS_PER_BLOB) as usize;
+        let transactions = vec![blob_tx(max_blobs + 1)];
+        let blob_gas_used = (max_blobs as u64 + 1) * DATA_GAS_PER_BLOB;
+        let header = header_with_blob_gas(blob_gas_used);
+        assert_eq!(
+            consensus.validate_blob_gas_usage(&header, &transactions),
+            Err(ConsensusError::BlobGasUsedExceedsMaxBlobGasPerBlock {
+                blob_gas_used,
+                max_blob_gas_per_block: MAX_DATA_GAS_PER_BLOCK
+            })
+        );
+
+        // a header without blob gas used
+        let header = SealedHeader::new(Header::default(), B256::ZERO);
+        assert_eq!(
+            consensus.validate_blob_gas_usage(&header, &[]),
+            Err(ConsensusError::BlobGasUsedMissing)
+        );
+    }
+
+    #[test]
+    fn test_block_pre_execution_validates_blob_gas_usage() {
+        let chain_spec = Arc::new(ChainSpecBuilder::mainnet().cancun_activated().build());
+        let consensus = EthBeaconConsensus::new(chain_spec);
+        let block = |blob_gas_used: u64, transactions: Vec<TransactionSigned>| {
+            let header = Header {
+                transactions_root: proofs::calculate_transaction_root(&transactions),
+                withdrawals_root: Some(proofs::calculate_withdrawals_root(&[])),
+                blob_gas_used: Some(blob_gas_used),
+                excess_blob_gas: Some(0),
+                ..Default::default()
+            };
+            let body = BlockBody {
+                transactions,
+                ommers: Vec::new(),
+                withdrawals: Some(Withdrawals::default()),
+            };
+            let (header, seal) = header.seal_slow().into_parts();
+            SealedBlock::new(SealedHeader::new(header, seal), body)
+        };
+
+        let transactions = vec![blob_tx(2), blob_tx(1)];
+        assert_eq!(
+            consensus
+                .validate_block_pre_execution(&block(3 * DATA_GAS_PER_BLOB, transactions.clone())),
+            Ok(())
+        );
+        assert_eq!(
+            consensus.validate_block_pre_execution(&block(2 * DATA_GAS_PER_BLOB, transactions)),
+            Err(ConsensusError::BlobGasUsedDiff(GotExpected {
+                got: 2 * DATA_GAS_PER_BLOB,
+                expected: 3 * DATA_GAS_PER_BLOB
+            }))
+        );
+
+        let max_blobs = (MAX_DATA_GAS_PER_BLOCK / DATA_GAS_PER_BLOB) as usize;
+        let blob_gas_used = (max_blobs as u64 + 1) * DATA_GAS_PER_BLOB;
+        assert_eq!(
+            consensus
+                .validate_block_pre_execution(&block(blob_gas_used, vec![blob_tx(max_blobs + 1)])),
+            Err(ConsensusError::BlobGasUsedExceedsMaxBlobGasPerBlock {
+                blob_gas_used,
+                max_blob_gas_per_block: MAX_DATA_GAS_PER_BLOCK
+            })
+        );
+    }
+
+    #[test]
+    fn test_block_pre_execution_validates_withdrawals_root() {
+        let chain_spec = Arc::new(ChainSpecBuilder::mainnet().shanghai_activated().build());
+        let consensus = EthBeaconConsensus::new(chain_spec);
+        let block = |withdrawals: Option<Vec<Withdrawal>>| {
+            let header = Header {
+                withdrawals_root: withdrawals
+                    .as_ref()
+                    .map(|withdrawals| proofs::calculate_withdrawals_root(withdrawals)),
+                ..Default::default()
+            };
+            let body = BlockBody {
+                transactions: Vec::new(),
+                ommers: Vec::new(),
+                withdrawals: withdrawals.map(Withdrawals::new),
+            };
+            let (header, seal) = header.seal_slow().into_parts();
+            SealedBlock::new(SealedHeader::new(header, seal), body)
+        };
+        let withdrawal = |index| Withdrawal { index, amount: 1, ..Default::default() };
+
+        assert_eq!(
+            consensus
+                .validate_block_pre_execution(&block(Some(vec![withdrawal(3), withdrawal(4)]))),
+            Ok(())
+        );
+        // the indices are only checked by `Consensus::validate_withdrawals`
+        assert_eq!(
+            consensus
+                .validate_block_pre_execution(&block(Some(vec![withdrawal(3), withdrawal(5)]))),
+            Ok(())
+        );
+
+        let mut invalid_root = block(Some(vec![withdrawal(3)]));
+        invalid_root.body.withdrawals = Some(Withdrawals::new(vec![withdrawal(4)]));
+        assert!(matches!(
+            consensus.validate_block_pre_execution(&invalid_root),
+            Err(ConsensusError::BodyWithdrawalsRootDiff(_))
+        ));
+        assert_eq!(
+            consensus.validate_block_pre_execution(&block(None)),
+            Err(ConsensusError::BodyWithdrawalsMissing)
+        );
+    }
 }
@@ -459,6 +459,50 @@ mod tests {
         assert_eq!(parent_beacon_block_root_storage, U256::from(0x69));
     }
 
+    #[test]
+    fn dry_run_eip_4788() {
+        let header = Header {
+            timestamp: 1,
+            number: 1,
+            parent_beacon_block_root: Some(B256::with_last_byte(0x69)),
+            excess_blob_gas: Some(0),
+            ..Header::default()
+        };
+        let block = BlockWithSenders {
+            block: Block { header: header.clone(), body: BlockBody::default() },
+            senders: vec![],
+        };
+
+        let db = create_state_provider_with_beacon_root_contract();
+
+        let chain_spec = Arc::new(
+            ChainSpecBuilder::from(&*MAINNET)
+                .shanghai_activated()
+                .with_fork(EthereumHardfork::Cancun, ForkCondition::Timestamp(1))
+                .build(),
+        );
+
+        let provider = executor_provider(chain_spec);
+
+        let output = provider.dry_run((&block, U256::ZERO).into(), &db).unwrap();
+        assert!(output.receipts.is_empty());
+        assert_eq!(output.gas_used, 0);
+
+        // the beacon root is only part of the returned state changes
+        let history_buffer_length = 8191u64;
+        let parent_beacon_block_root_index =
+            header.timestamp % history_buffer_length + history_buffer_length;
+        assert_eq!(
+            output.state.account(&BEACON_ROOTS_ADDRESS).and_then(
+                |account| account.storage_slot(U256::from(parent_beacon_block_root_index))
+            ),
+            Some(U256::from(0x69))
+        );
+
+        // nothing was committed, so running the block again yields the same output
+        assert_eq!(provider.dry_run((&block, U256::ZERO).into(), &db).unwrap(), output);
+    }
+
     #[test]
     fn eip_4788_no_code_cancun() {
         // This test ensures that we "silently fail" when cancun is active and there is no code at
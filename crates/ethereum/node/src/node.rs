@@ -18,14 +18,14 @@ use reth_node_api::{
 };
 use reth_node_builder::{
     components::{
-        ComponentsBuilder, ConsensusBuilder, ExecutorBuilder, NetworkBuilder,
+        ComponentFamily, ComponentsBuilder, ConsensusBuilder, ExecutorBuilder, NetworkBuilder,
         PayloadServiceBuilder, PoolBuilder,
     },
     node::{FullNodeTypes, NodeTypes, NodeTypesWithEngine},
     rpc::{EngineValidatorBuilder, RpcAddOns},
     BuilderContext, Node, NodeAdapter, NodeComponentsBuilder, PayloadBuilderConfig, PayloadTypes,
 };
-use reth_payload_builder::{PayloadBuilderHandle, PayloadBuilderService};
+use reth_payload_builder::{InclusionListProvider, PayloadBuilderHandle, PayloadBuilderService};
 use reth_primitives::{Block, Header, Receipt};
 use reth_provider::CanonStateSubscriptions;
 use reth_rpc::EthApi;
@@ -153,6 +153,10 @@ where
 
         Ok((evm_config, executor))
     }
+
+    fn family(&self) -> Option<ComponentFamily> {
+        Some(ComponentFamily::ETHEREUM)
+    }
 }
 
 /// A basic ethereum transaction pool.
@@ -227,12 +231,19 @@ where
 
         Ok(transaction_pool)
     }
+
+    fn family(&self) -> Option<ComponentFamily> {
+        Some(ComponentFamily::ETHEREUM)
+    }
 }
 
 /// A basic ethereum payload service.
 #[derive(Debug, Default, Clone)]
 #[non_exhaustive]
-pub struct EthereumPayloadBuilder;
+pub struct EthereumPayloadBuilder {
+    /// The source of the inclusion lists that built payloads must satisfy, if any.
+    inclusion_lists: Option<Arc<dyn InclusionListProvider>>,
+}
 
 impl EthereumPayloadBuilder {
     /// A helper method initializing [`PayloadBuilderService`] with the given EVM config.
@@ -254,7 +265,8 @@ impl EthereumPayloadBuilder {
         >,
     {
         let payload_builder =
-            reth_ethereum_payload_builder::EthereumPayloadBuilder::new(evm_config);
+            reth_ethereum_payload_builder::EthereumPayloadBuilder::new(evm_config)
+                .with_inclusion_lists(self.inclusion_lists);
         let conf = ctx.payload_builder_config();
 
         let payload_job_config = BasicPayloadJobGeneratorConfig::default()
@@ -297,6 +309,15 @@ where
     ) -> eyre::Result<PayloadBuilderHandle<Types::Engine>> {
         self.spawn(EthEvmConfig::new(ctx.chain_spec()), ctx, pool)
     }
+
+    fn with_inclusion_lists(mut self, provider: Arc<dyn InclusionListProvider>) -> Self {
+        self.inclusion_lists = Some(provider);
+        self
+    }
+
+    fn family(&self) -> Option<ComponentFamily> {
+        Some(ComponentFamily::ETHEREUM)
+    }
 }
 
 /// A basic ethereum payload service.
@@ -341,6 +362,10 @@ where
             Ok(Arc::new(EthBeaconConsensus::new(ctx.chain_spec())))
         }
     }
+
+    fn family(&self) -> Option<ComponentFamily> {
+        Some(ComponentFamily::ETHEREUM)
+    }
 }
 
 /// Builder for [`EthereumEngineValidator`].
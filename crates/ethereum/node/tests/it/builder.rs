@@ -1,16 +1,35 @@
 //! Node builder setup tests.
 
-use std::sync::Arc;
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    time::Duration,
+};
 
 use reth_db::{
     test_utils::{create_test_rw_db, TempDatabase},
     DatabaseEnv,
 };
-use reth_node_api::NodeTypesWithDBAdapter;
-use reth_node_builder::{EngineNodeLauncher, FullNodeComponents, NodeBuilder, NodeConfig};
-use reth_node_ethereum::node::{EthereumAddOns, EthereumNode};
+use reth_node_api::{
+    FullNodeTypes, FullNodeTypesAdapter, NodeTypesWithDBAdapter, NodeTypesWithEngine,
+};
+use reth_node_builder::{
+    components::{
+        ComponentFamily, ComponentsBuilder, ExecutorBuilder, NodeComponent, NodeComponentsBuilder,
+        NodeComponentsBuilderExt, PayloadServiceBuilder, RetryingComponentsBuilder,
+    },
+    BuilderContext, EngineNodeLauncher, FullNodeComponents, NodeBuilder, NodeConfig,
+};
+use reth_node_ethereum::node::{
+    EthereumAddOns, EthereumExecutorBuilder, EthereumNode, EthereumPayloadBuilder,
+    EthereumPoolBuilder,
+};
+use reth_payload_builder::PayloadBuilderHandle;
 use reth_provider::providers::BlockchainProvider2;
 use reth_tasks::TaskManager;
+use reth_transaction_pool::TransactionPool;
 
 #[test]
 fn test_basic_setup() {
@@ -42,6 +61,25 @@ fn test_basic_setup() {
         .check_launch();
 }
 
+#[test]
+fn test_components_from_context() {
+    let config = NodeConfig::test();
+    let db = create_test_rw_db();
+    let _builder = NodeBuilder::new(config)
+        .with_database(db)
+        .with_types::<EthereumNode>()
+        .with_components(ComponentsBuilder::from_context(
+            EthereumNode::components(),
+            |ctx, builder| {
+                // only the pool is overridden, all other components use the defaults
+                let _chain_spec = ctx.chain_spec();
+                builder.pool(EthereumPoolBuilder::default())
+            },
+        ))
+        .with_add_ons(EthereumAddOns::default())
+        .check_launch();
+}
+
 #[tokio::test]
 async fn test_eth_launcher() {
     let tasks = TaskManager::current();
@@ -72,3 +110,202 @@ fn test_node_setup() {
     let _builder =
         NodeBuilder::new(config).with_database(db).node(EthereumNode::default()).check_launch();
 }
+
+/// An executor builder that claims to be specific to Optimism nodes.
+#[derive(Debug, Clone, Copy)]
+struct OptimismFamilyExecutorBuilder;
+
+impl<Node> ExecutorBuilder<Node> for OptimismFamilyExecutorBuilder
+where
+    Node: FullNodeTypes,
+    EthereumExecutorBuilder: ExecutorBuilder<Node>,
+{
+    type EVM = <EthereumExecutorBuilder as ExecutorBuilder<Node>>::EVM;
+    type Executor = <EthereumExecutorBuilder as ExecutorBuilder<Node>>::Executor;
+
+    async fn build_evm(
+        self,
+        ctx: &BuilderContext<Node>,
+    ) -> eyre::Result<(Self::EVM, Self::Executor)> {
+        EthereumExecutorBuilder::default().build_evm(ctx).await
+    }
+
+    fn family(&self) -> Option<ComponentFamily> {
+        Some(ComponentFamily::OPTIMISM)
+    }
+}
+
+#[test]
+fn test_incompatible_components() {
+    type Types = NodeTypesWithDBAdapter<EthereumNode, Arc<TempDatabase<DatabaseEnv>>>;
+    type Node = FullNodeTypesAdapter<Types, BlockchainProvider2<Types>>;
+
+    EthereumNode::components::<Node>().check_compatibility().unwrap();
+
+    let err = EthereumNode::components::<Node>()
+        .executor(OptimismFamilyExecutorBuilder)
+        .check_compatibility()
+        .unwrap_err();
+    assert_eq!(err.first, (NodeComponent::Evm, ComponentFamily::OPTIMISM));
+    assert_eq!(err.second, (NodeComponent::Pool, ComponentFamily::ETHEREUM));
+    assert_eq!(
+        err.to_string(),
+        "the evm builder (optimism) is incompatible with the pool builder (ethereum)"
+    );
+}
+
+#[tokio::test]
+async fn test_inspect_components() -> eyre::Result<()> {
+    let tasks = TaskManager::current();
+    let inspected = Arc::new(AtomicUsize::new(0));
+
+    let components = EthereumNode::components().inspect_components({
+        let inspected = inspected.clone();
+        move |components| {
+            let _types = std::any::type_name_of_val(components);
+            inspected.fetch_add(1, Ordering::Relaxed);
+        }
+    });
+    let _handle = NodeBuilder::new(NodeConfig::test())
+        .testing_node(tasks.executor())
+        .with_types::<EthereumNode>()
+        .with_components(components)
+        .with_add_ons(EthereumAddOns::default())
+        .launch()
+        .await?;
+
+    assert_eq!(inspected.load(Ordering::Relaxed), 1);
+    Ok(())
+}
+
+/// A payload builder that reports the name of the thread its tasks are spawned on.
+#[derive(Debug)]
+struct ThreadReportingPayloadBuilder(mpsc::Sender<Option<String>>);
+
+impl<Node, Pool> PayloadServiceBuilder<Node, Pool> for ThreadReportingPayloadBuilder
+where
+    Node: FullNodeTypes,
+    Pool: TransactionPool,
+    EthereumPayloadBuilder: PayloadServiceBuilder<Node, Pool>,
+{
+    async fn spawn_payload_service(
+        self,
+        ctx: &BuilderContext<Node>,
+        pool: Pool,
+    ) -> eyre::Result<PayloadBuilderHandle<<Node::Types as NodeTypesWithEngine>::Engine>> {
+        let thread = self.0;
+        ctx.task_executor().spawn(async move {
+            let _ = thread.send(std::thread::current().name().map(str::to_string));
+        });
+        EthereumPayloadBuilder::default().spawn_payload_service(ctx, pool).await
+    }
+}
+
+#[tokio::test]
+async fn test_components_task_executor() -> eyre::Result<()> {
+    let tasks = TaskManager::current();
+
+    // a single-threaded runtime on a dedicated thread
+    let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build()?;
+    let components_tasks = TaskManager::new(runtime.handle().clone());
+    let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+    let runtime_thread =
+        std::thread::Builder::new().name("components-runtime".to_string()).spawn(move || {
+            let _ = runtime.block_on(stopped);
+        })?;
+
+    let (thread_tx, thread_rx) = mpsc::channel();
+    let components = EthereumNode::components()
+        .payload(ThreadReportingPayloadBuilder(thread_tx))
+        .with_task_executor(components_tasks.executor());
+    let _handle = NodeBuilder::new(NodeConfig::test())
+        .testing_node(tasks.executor())
+        .with_types::<EthereumNode>()
+        .with_components(components)
+        .with_add_ons(EthereumAddOns::default())
+        .launch()
+        .await?;
+
+    let thread = thread_rx.recv_timeout(Duration::from_secs(10))?;
+    assert_eq!(thread.as_deref(), Some("components-runtime"));
+
+    let _ = stop.send(());
+    runtime_thread.join().unwrap();
+    Ok(())
+}
+
+/// A components builder that fails with a transient error in its first attempts.
+#[derive(Debug, Clone)]
+struct FlakyComponentsBuilder<B> {
+    builder: B,
+    attempts: Arc<AtomicUsize>,
+    failures: usize,
+}
+
+impl<Node, B> NodeComponentsBuilder<Node> for FlakyComponentsBuilder<B>
+where
+    Node: FullNodeTypes,
+    B: NodeComponentsBuilder<Node>,
+{
+    type Components = B::Components;
+
+    async fn build_components(self, ctx: &BuilderContext<Node>) -> eyre::Result<Self::Components> {
+        if self.attempts.fetch_add(1, Ordering::Relaxed) < self.failures {
+            eyre::bail!("transient")
+        }
+        self.builder.build_components(ctx).await
+    }
+}
+
+#[tokio::test]
+async fn test_retrying_components() -> eyre::Result<()> {
+    let tasks = TaskManager::current();
+    let is_transient = |err: &eyre::Report| err.to_string() == "transient";
+
+    // fails twice, then succeeds
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let components = FlakyComponentsBuilder {
+        builder: EthereumNode::components(),
+        attempts: attempts.clone(),
+        failures: 2,
+    };
+    let _handle = NodeBuilder::new(NodeConfig::test())
+        .testing_node(tasks.executor())
+        .with_types::<EthereumNode>()
+        .with_components(RetryingComponentsBuilder::new(
+            components,
+            3,
+            Duration::from_millis(1),
+            is_transient,
+        ))
+        .with_add_ons(EthereumAddOns::default())
+        .launch()
+        .await?;
+    assert_eq!(attempts.load(Ordering::Relaxed), 3);
+
+    // the last error is returned once the retries are exhausted
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let components = FlakyComponentsBuilder {
+        builder: EthereumNode::components(),
+        attempts: attempts.clone(),
+        failures: 2,
+    };
+    let err = NodeBuilder::new(NodeConfig::test())
+        .testing_node(tasks.executor())
+        .with_types::<EthereumNode>()
+        .with_components(RetryingComponentsBuilder::new(
+            components,
+            1,
+            Duration::from_millis(1),
+            is_transient,
+        ))
+        .with_add_ons(EthereumAddOns::default())
+        .launch()
+        .await
+        .err()
+        .unwrap();
+    assert!(err.chain().any(|err| err.to_string() == "transient"), "{err:?}");
+    assert_eq!(attempts.load(Ordering::Relaxed), 2);
+
+    Ok(())
+}
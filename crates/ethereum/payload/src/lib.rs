@@ -23,7 +23,9 @@ use reth_evm::{system_calls::SystemCaller, ConfigureEvm, NextBlockEnvAttributes}
 use reth_evm_ethereum::{eip6110::parse_deposits_from_receipts, EthEvmConfig};
 use reth_execution_types::ExecutionOutcome;
 use reth_payload_builder::{EthBuiltPayload, EthPayloadBuilderAttributes};
-use reth_payload_primitives::{PayloadBuilderAttributes, PayloadBuilderError};
+use reth_payload_primitives::{
+    InclusionList, InclusionListProvider, PayloadBuilderAttributes, PayloadBuilderError,
+};
 use reth_primitives::{
     proofs::{self},
     revm_primitives::{BlockEnv, CfgEnvWithHandlerCfg},
@@ -51,19 +53,32 @@ type BestTransactionsIter<Pool> = Box<
 
 /// Ethereum payload builder
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct EthereumPayloadBuilder<EvmConfig = EthEvmConfig> {
+pub struct EthereumPayloadBuilder<EvmConfig = EthEvmConfig, InclusionLists = ()> {
     /// The type responsible for creating the evm.
     evm_config: EvmConfig,
+    /// The source of the inclusion lists that built payloads must satisfy.
+    inclusion_lists: InclusionLists,
 }
 
 impl<EvmConfig> EthereumPayloadBuilder<EvmConfig> {
     /// `EthereumPayloadBuilder` constructor.
     pub const fn new(evm_config: EvmConfig) -> Self {
-        Self { evm_config }
+        Self { evm_config, inclusion_lists: () }
+    }
+}
+
+impl<EvmConfig, InclusionLists> EthereumPayloadBuilder<EvmConfig, InclusionLists> {
+    /// Enforces the [EIP-7547](https://eips.ethereum.org/EIPS/eip-7547) inclusion lists of the
+    /// given provider, see [`default_ethereum_payload`].
+    pub fn with_inclusion_lists<P: InclusionListProvider>(
+        self,
+        provider: P,
+    ) -> EthereumPayloadBuilder<EvmConfig, P> {
+        EthereumPayloadBuilder { evm_config: self.evm_config, inclusion_lists: provider }
     }
 }
 
-impl<EvmConfig> EthereumPayloadBuilder<EvmConfig>
+impl<EvmConfig, InclusionLists> EthereumPayloadBuilder<EvmConfig, InclusionLists>
 where
     EvmConfig: ConfigureEvm<Header = Header>,
 {
@@ -84,9 +99,11 @@ where
 }
 
 // Default implementation of [PayloadBuilder] for unit type
-impl<EvmConfig, Pool, Client> PayloadBuilder<Pool, Client> for EthereumPayloadBuilder<EvmConfig>
+impl<EvmConfig, InclusionLists, Pool, Client> PayloadBuilder<Pool, Client>
+    for EthereumPayloadBuilder<EvmConfig, InclusionLists>
 where
     EvmConfig: ConfigureEvm<Header = Header>,
+    InclusionLists: InclusionListProvider + Clone,
     Client: StateProviderFactory + ChainSpecProvider<ChainSpec = ChainSpec>,
     Pool: TransactionPool,
 {
@@ -101,10 +118,17 @@ where
             .cfg_and_block_env(&args.config, &args.config.parent_header)
             .map_err(PayloadBuilderError::other)?;
 
+        let inclusion_list = self.inclusion_lists.inclusion_list(args.config.parent_header.hash());
+
         let pool = args.pool.clone();
-        default_ethereum_payload(self.evm_config.clone(), args, cfg_env, block_env, |attributes| {
-            pool.best_transactions_with_attributes(attributes)
-        })
+        default_ethereum_payload(
+            self.evm_config.clone(),
+            args,
+            cfg_env,
+            block_env,
+            inclusion_list,
+            |attributes| pool.best_transactions_with_attributes(attributes),
+        )
     }
 
     fn build_empty_payload(
@@ -128,9 +152,15 @@ where
 
         let pool = args.pool.clone();
 
-        default_ethereum_payload(self.evm_config.clone(), args, cfg_env, block_env, |attributes| {
-            pool.best_transactions_with_attributes(attributes)
-        })?
+        // the empty payload is a fallback that doesn't include any transactions
+        default_ethereum_payload(
+            self.evm_config.clone(),
+            args,
+            cfg_env,
+            block_env,
+            None,
+            |attributes| pool.best_transactions_with_attributes(attributes),
+        )?
         .into_payload()
         .ok_or_else(|| PayloadBuilderError::MissingPayload)
     }
@@ -141,12 +171,19 @@ where
 /// Given build arguments including an Ethereum client, transaction pool,
 /// and configuration, this function creates a transaction payload. Returns
 /// a result indicating success with the payload or an error in case of failure.
+///
+/// If an inclusion list is given, its transactions are included first. Transactions that are
+/// invalid on top of the parent and the preceding transactions are skipped, but if a valid
+/// transaction doesn't fit into the block, the payload is rejected with
+/// [`PayloadBuilderError::InclusionListUnsatisfied`]. The remaining gas is filled with the best
+/// transactions from the pool.
 #[inline]
 pub fn default_ethereum_payload<EvmConfig, Pool, Client, F>(
     evm_config: EvmConfig,
     args: BuildArguments<Pool, Client, EthPayloadBuilderAttributes, EthBuiltPayload>,
     initialized_cfg: CfgEnvWithHandlerCfg,
     initialized_block_env: BlockEnv,
+    inclusion_list: Option<InclusionList>,
     best_txs: F,
 ) -> Result<BuildOutcome<EthBuiltPayload>, PayloadBuilderError>
 where
@@ -220,6 +257,63 @@ where
     let mut evm = evm_config.evm_with_env(&mut db, env);
 
     let mut receipts = Vec::new();
+    for tx in inclusion_list.iter().flat_map(InclusionList::transactions) {
+        // check if the job was dropped, if so we can exit early. A job that was cancelled
+        // explicitly still includes the inclusion list, because a partial block must satisfy it
+        if cancel.is_dropped() {
+            return Ok(BuildOutcome::Cancelled)
+        }
+
+        // blob transactions can't be part of an inclusion list, because their sidecars are not
+        // available to the builder
+        if tx.is_eip4844() {
+            trace!(target: "payload_builder", tx=?tx.hash, "skipping inclusion list blob transaction");
+            continue
+        }
+
+        *evm.tx_mut() = evm_config.tx_env(tx.as_signed(), tx.signer());
+
+        let ResultAndState { result, state } = match evm.transact() {
+            Ok(res) => res,
+            Err(EVMError::Transaction(err)) => {
+                // the transaction is no longer valid, e.g. because the sender's nonce or balance
+                // changed, so it doesn't have to be included
+                trace!(target: "payload_builder", %err, ?tx, "skipping invalid inclusion list transaction");
+                continue
+            }
+            Err(err) => return Err(PayloadBuilderError::EvmExecutionError(err)),
+        };
+
+        // a transaction of the inclusion list that is still valid must be included
+        if cumulative_gas_used + tx.gas_limit() > block_gas_limit {
+            return Err(PayloadBuilderError::InclusionListUnsatisfied(tx.hash))
+        }
+
+        evm.db_mut().commit(state);
+
+        let gas_used = result.gas_used();
+        cumulative_gas_used += gas_used;
+
+        #[allow(clippy::needless_update)] // side-effect of optimism fields
+        receipts.push(Some(Receipt {
+            tx_type: tx.tx_type(),
+            success: result.is_success(),
+            cumulative_gas_used,
+            logs: result.into_logs().into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }));
+
+        let miner_fee = tx
+            .effective_tip_per_gas(Some(base_fee))
+            .expect("fee is always valid; execution succeeded");
+        total_fees += U256::from(miner_fee) * U256::from(gas_used);
+
+        executed_senders.push(tx.signer());
+        executed_txs.push(tx.clone().into_signed());
+    }
+
+    // fill the rest of the block with the best transactions from the pool, transactions that were
+    // included from the inclusion list fail with a nonce that is too low and are skipped
     while let Some(pool_tx) = best_txs.next() {
         // ensure we still have capacity for this transaction
         if cumulative_gas_used + pool_tx.gas_limit() > block_gas_limit {
@@ -232,7 +326,7 @@ where
 
         // check if the job was cancelled, if so we can exit early
         if cancel.is_cancelled() {
-            return Ok(BuildOutcome::Cancelled)
+            break
         }
 
         // convert tx to a signed transaction
@@ -324,6 +418,12 @@ where
     // Release db
     drop(evm);
 
+    // a cancelled job only finishes the block if it was cancelled explicitly and already included
+    // transactions, otherwise the block is not needed
+    if cancel.is_cancelled() && (cancel.is_dropped() || executed_txs.is_empty()) {
+        return Ok(BuildOutcome::Cancelled)
+    }
+
     // check if we have a better block
     if !is_better_payload(best_payload.as_ref(), total_fees) {
         // can skip building the block
@@ -462,3 +562,205 @@ where
 
     Ok(BuildOutcome::Better { payload, cached_reads })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_eips::eip1559::MIN_PROTOCOL_BASE_FEE;
+    use alloy_primitives::{Address, B256};
+    use alloy_rpc_types_engine::PayloadAttributes;
+    use reth_basic_payload_builder::Cancelled;
+    use reth_primitives::{SealedHeader, TransactionSignedEcRecovered};
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+    use reth_transaction_pool::{
+        test_utils::{testing_pool, MockTransaction, TestPool},
+        TransactionOrigin,
+    };
+
+    const BLOCK_GAS_LIMIT: u64 = 50_000;
+
+    #[derive(Debug, Clone)]
+    struct TestInclusionList(InclusionList);
+
+    impl InclusionListProvider for TestInclusionList {
+        fn inclusion_list(&self, _parent_hash: B256) -> Option<InclusionList> {
+            Some(self.0.clone())
+        }
+    }
+
+    /// Returns a client with funded accounts for the given senders.
+    fn client(senders: &[Address]) -> MockEthProvider {
+        let client = MockEthProvider::default();
+        client.extend_accounts(
+            senders
+                .iter()
+                .map(|sender| (*sender, ExtendedAccount::new(0, U256::from(10u64.pow(18))))),
+        );
+        client
+    }
+
+    fn transfer(sender: Address, nonce: u64, gas_limit: u64) -> MockTransaction {
+        MockTransaction::eip1559()
+            .with_sender(sender)
+            .with_nonce(nonce)
+            .with_gas_limit(gas_limit)
+            .with_max_fee(MIN_PROTOCOL_BASE_FEE as u128 * 2)
+    }
+
+    fn inclusion_list(txs: &[MockTransaction]) -> TestInclusionList {
+        TestInclusionList(txs.iter().cloned().map(TransactionSignedEcRecovered::from).collect())
+    }
+
+    fn build(
+        client: MockEthProvider,
+        pool: TestPool,
+        inclusion_list: TestInclusionList,
+    ) -> Result<BuildOutcome<EthBuiltPayload>, PayloadBuilderError> {
+        build_with_cancel(client, pool, inclusion_list, Cancelled::default())
+    }
+
+    fn build_with_cancel(
+        client: MockEthProvider,
+        pool: TestPool,
+        inclusion_list: TestInclusionList,
+        cancel: Cancelled,
+    ) -> Result<BuildOutcome<EthBuiltPayload>, PayloadBuilderError> {
+        let parent = Header {
+            gas_limit: BLOCK_GAS_LIMIT,
+            gas_used: BLOCK_GAS_LIMIT / 2,
+            base_fee_per_gas: Some(MIN_PROTOCOL_BASE_FEE),
+            ..Default::default()
+        };
+        let parent = Arc::new(SealedHeader::new(parent, B256::random()));
+        let attributes = EthPayloadBuilderAttributes::new(
+            parent.hash(),
+            PayloadAttributes {
+                timestamp: 1,
+                prev_randao: B256::random(),
+                suggested_fee_recipient: Address::random(),
+                withdrawals: None,
+                parent_beacon_block_root: None,
+            },
+        );
+        let args = BuildArguments::new(
+            client.clone(),
+            pool,
+            Default::default(),
+            PayloadConfig::new(parent, Default::default(), attributes),
+            cancel,
+            None,
+        );
+
+        EthereumPayloadBuilder::new(EthEvmConfig::new(client.chain_spec()))
+            .with_inclusion_lists(inclusion_list)
+            .try_build(args)
+    }
+
+    fn block_transactions(outcome: BuildOutcome<EthBuiltPayload>) -> Vec<B256> {
+        let payload = outcome.into_payload().expect("payload is built");
+        payload.block().body.transactions.iter().map(|tx| tx.hash).collect()
+    }
+
+    #[test]
+    fn inclusion_list_skips_invalid_transactions() {
+        let (alice, bob) = (Address::random(), Address::random());
+        let included = transfer(alice, 0, 21_000);
+        // bob can't pay for the transaction, which also wouldn't fit into the block
+        let unfunded = transfer(bob, 0, 40_000);
+        let nonce_too_high = transfer(alice, 5, 21_000);
+
+        let outcome = build(
+            client(&[alice]),
+            testing_pool(),
+            inclusion_list(&[included.clone(), unfunded, nonce_too_high]),
+        )
+        .unwrap();
+        assert_eq!(block_transactions(outcome), [included.get_hash()]);
+    }
+
+    #[test]
+    fn inclusion_list_unsatisfied() {
+        let (alice, bob) = (Address::random(), Address::random());
+        let included = transfer(alice, 0, 21_000);
+        let too_large = transfer(bob, 0, 30_000);
+
+        let err = build(
+            client(&[alice, bob]),
+            testing_pool(),
+            inclusion_list(&[included, too_large.clone()]),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            PayloadBuilderError::InclusionListUnsatisfied(hash) if hash == too_large.get_hash()
+        ));
+    }
+
+    #[tokio::test]
+    async fn inclusion_list_fills_up_from_pool() {
+        let (alice, bob) = (Address::random(), Address::random());
+        let included = transfer(alice, 0, 21_000);
+        let pooled = transfer(bob, 0, 21_000);
+
+        let pool = testing_pool();
+        // the pooled copy of the included transaction is skipped
+        pool.add_transaction(TransactionOrigin::External, included.clone()).await.unwrap();
+        pool.add_transaction(TransactionOrigin::External, pooled.clone()).await.unwrap();
+
+        let outcome =
+            build(client(&[alice, bob]), pool, inclusion_list(&[included.clone()])).unwrap();
+        assert_eq!(block_transactions(outcome), [included.get_hash(), pooled.get_hash()]);
+    }
+
+    #[tokio::test]
+    async fn cancelled_build_without_transactions() {
+        let alice = Address::random();
+        let pool = testing_pool();
+        pool.add_transaction(TransactionOrigin::External, transfer(alice, 0, 21_000))
+            .await
+            .unwrap();
+
+        // the build stops before the first transaction, so there's no block to finish
+        let cancel = Cancelled::default();
+        cancel.cancel();
+        let outcome =
+            build_with_cancel(client(&[alice]), pool, inclusion_list(&[]), cancel).unwrap();
+        assert!(matches!(outcome, BuildOutcome::Cancelled));
+    }
+
+    #[tokio::test]
+    async fn cancelled_build_returns_partial_payload() {
+        let (alice, bob) = (Address::random(), Address::random());
+        let included = transfer(alice, 0, 21_000);
+        let pool = testing_pool();
+        pool.add_transaction(TransactionOrigin::External, transfer(bob, 0, 21_000)).await.unwrap();
+
+        // the inclusion list is still included, but the block is not filled up from the pool
+        let cancel = Cancelled::default();
+        cancel.cancel();
+        let outcome = build_with_cancel(
+            client(&[alice, bob]),
+            pool,
+            inclusion_list(&[included.clone()]),
+            cancel,
+        )
+        .unwrap();
+        assert_eq!(block_transactions(outcome), [included.get_hash()]);
+    }
+
+    #[test]
+    fn dropped_build_is_cancelled() {
+        let alice = Address::random();
+        let cancel = Cancelled::default();
+        // dropping a clone of the marker drops the job
+        drop(cancel.clone());
+        let outcome = build_with_cancel(
+            client(&[alice]),
+            testing_pool(),
+            inclusion_list(&[transfer(alice, 0, 21_000)]),
+            cancel,
+        )
+        .unwrap();
+        assert!(matches!(outcome, BuildOutcome::Cancelled));
+    }
+}
@@ -16,7 +16,7 @@ use revm_primitives::db::Database;
 
 // re-export Either
 pub use futures_util::future::Either;
-use revm::State;
+use revm::{db::BundleState, State};
 
 impl<A, B> BlockExecutorProvider for Either<A, B>
 where
@@ -160,4 +160,11 @@ where
             Self::Right(b) => b.size_hint(),
         }
     }
+
+    fn bundle_state(&self) -> Option<&BundleState> {
+        match self {
+            Self::Left(a) => a.bundle_state(),
+            Self::Right(b) => b.bundle_state(),
+        }
+    }
 }
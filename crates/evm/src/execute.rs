@@ -12,11 +12,14 @@ use crate::system_calls::OnStateHook;
 use alloc::{boxed::Box, vec::Vec};
 use alloy_eips::eip7685::Requests;
 use alloy_primitives::BlockNumber;
-use core::{fmt::Display, marker::PhantomData};
+use core::{fmt::Display, marker::PhantomData, num::NonZeroUsize};
 use reth_consensus::ConsensusError;
 use reth_primitives::{BlockWithSenders, Receipt};
 use reth_prune_types::PruneModes;
-use reth_revm::batch::BlockBatchRecord;
+use reth_revm::{
+    batch::BlockBatchRecord,
+    database::{EvmStateProvider, StateProviderDatabase},
+};
 use revm::{db::BundleState, State};
 use revm_primitives::{db::Database, U256};
 
@@ -121,6 +124,13 @@ pub trait BatchExecutor<DB> {
     ///
     /// This is used to optimize DB commits depending on the size of the state.
     fn size_hint(&self) -> Option<usize>;
+
+    /// The state changes accumulated by the batch so far, if the executor keeps track of them.
+    ///
+    /// This can be used to compute intermediate state roots without finalizing the batch.
+    fn bundle_state(&self) -> Option<&BundleState> {
+        None
+    }
 }
 
 /// A type that can create a new executor for block execution.
@@ -165,6 +175,65 @@ pub trait BlockExecutorProvider: Send + Sync + Clone + Unpin + 'static {
     fn batch_executor<DB>(&self, db: DB) -> Self::BatchExecutor<DB>
     where
         DB: Database<Error: Into<ProviderError> + Display>;
+
+    /// Executes a single block on top of the given state, without persisting anything.
+    ///
+    /// This is useful to simulate a block, e.g. to evaluate a block proposal. The returned
+    /// [`BlockExecutionOutput`] contains the receipts, the gas used and the state changes of the
+    /// block, which are never applied to the given state.
+    fn dry_run<S>(
+        &self,
+        input: BlockExecutionInput<'_, BlockWithSenders>,
+        state: S,
+    ) -> Result<BlockExecutionOutput<Receipt>, BlockExecutionError>
+    where
+        S: EvmStateProvider,
+    {
+        self.executor(StateProviderDatabase::new(state)).execute(input)
+    }
+
+    /// Executes a contiguous range of blocks on top of the given database, accumulating the state
+    /// changes of all blocks in a single batch.
+    ///
+    /// Every `checkpoint_interval` blocks and after the last block, `on_checkpoint` is invoked with
+    /// the number of the last executed block and the state changes accumulated so far, e.g. to
+    /// compute and verify the state root at that block. This avoids computing the state root for
+    /// every block when importing a large range of blocks.
+    ///
+    /// If the batch executor does not expose its state, see [`BatchExecutor::bundle_state`],
+    /// `on_checkpoint` is never invoked.
+    ///
+    /// Returns the [`ExecutionOutcome`] of the entire range.
+    fn execute_many<'a, DB, I, F>(
+        &self,
+        db: DB,
+        blocks: I,
+        checkpoint_interval: NonZeroUsize,
+        mut on_checkpoint: F,
+    ) -> Result<ExecutionOutcome, BlockExecutionError>
+    where
+        DB: Database<Error: Into<ProviderError> + Display>,
+        I: IntoIterator<Item = BlockExecutionInput<'a, BlockWithSenders>>,
+        F: FnMut(BlockNumber, &BundleState) -> Result<(), BlockExecutionError>,
+    {
+        let mut executor = self.batch_executor(db);
+        let mut blocks = blocks.into_iter().peekable();
+        let mut executed = 0usize;
+
+        while let Some(input) = blocks.next() {
+            let number = input.block.number;
+            executor.execute_and_verify_one(input)?;
+            executed += 1;
+
+            if executed % checkpoint_interval == 0 || blocks.peek().is_none() {
+                if let Some(state) = executor.bundle_state() {
+                    on_checkpoint(number, state)?;
+                }
+            }
+        }
+
+        Ok(executor.finalize())
+    }
 }
 
 /// Helper type for the output of executing a block.
@@ -472,6 +541,10 @@ where
     fn size_hint(&self) -> Option<usize> {
         Some(self.strategy.state_ref().bundle_state.size_hint())
     }
+
+    fn bundle_state(&self) -> Option<&BundleState> {
+        Some(&self.strategy.state_ref().bundle_state)
+    }
 }
 
 #[cfg(test)]
@@ -703,4 +776,43 @@ mod tests {
         assert_eq!(block_execution_output.requests, expected_apply_post_execution_changes_result);
         assert_eq!(block_execution_output.state, expected_finish_result);
     }
+
+    #[test]
+    fn test_execute_many() {
+        let strategy_factory = TestExecutorStrategyFactory {
+            execute_transactions_result: ExecuteOutput {
+                receipts: vec![Receipt::default()],
+                gas_used: 10,
+            },
+            apply_post_execution_changes_result: Requests::default(),
+            finish_result: BundleState::default(),
+        };
+        let provider = BasicBlockExecutorProvider::new(strategy_factory);
+        let db = CacheDB::<EmptyDBTyped<ProviderError>>::default();
+
+        let blocks: Vec<BlockWithSenders> = (1..=5)
+            .map(|number| {
+                let mut block = BlockWithSenders::default();
+                block.block.header.number = number;
+                block
+            })
+            .collect();
+
+        let mut checkpoints = Vec::new();
+        let outcome = provider
+            .execute_many(
+                db,
+                blocks.iter().map(|block| BlockExecutionInput::new(block, U256::ZERO)),
+                NonZeroUsize::new(2).unwrap(),
+                |number, _state| {
+                    checkpoints.push(number);
+                    Ok(())
+                },
+            )
+            .unwrap();
+
+        assert_eq!(checkpoints, vec![2, 4, 5]);
+        assert_eq!(outcome.first_block(), 1);
+        assert_eq!(outcome.receipts().len(), 5);
+    }
 }
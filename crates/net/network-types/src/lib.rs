@@ -25,7 +25,9 @@ pub use backoff::BackoffKind;
 pub use peers::{
     addr::PeerAddr,
     kind::PeerKind,
-    reputation::{is_banned_reputation, ReputationChangeOutcome, DEFAULT_REPUTATION},
+    reputation::{
+        is_banned_reputation, ReputationChangeOutcome, DEFAULT_REPUTATION, TRUSTED_PEER_SCORE,
+    },
     state::PeerConnectionState,
     ConnectionsConfig, Peer, PeersConfig,
 };
@@ -6,7 +6,9 @@ pub enum PeerKind {
     /// Basic peer kind.
     #[default]
     Basic,
-    /// Static peer, added via JSON-RPC.
+    /// Static peer, added via JSON-RPC or the network builder.
+    ///
+    /// Static peers are never removed from the peer set and are reconnected after a disconnect.
     Static,
     /// Trusted peer.
     Trusted,
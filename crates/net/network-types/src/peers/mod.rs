@@ -22,6 +22,9 @@ pub struct Peer {
     pub addr: PeerAddr,
     /// Reputation of the peer.
     pub reputation: i32,
+    /// The part of the reputation that was applied from the score of a custom peer scoring
+    /// function.
+    pub score: i32,
     /// The state of the connection, if any.
     pub state: PeerConnectionState,
     /// The [`ForkId`] that the peer announced via discovery.
@@ -61,6 +64,7 @@ impl Peer {
             addr,
             state,
             reputation: DEFAULT_REPUTATION,
+            score: 0,
             fork_id: None,
             remove_after_disconnect: false,
             kind: Default::default(),
@@ -78,6 +82,7 @@ impl Peer {
     /// [`ReputationChangeOutcome::None`].
     pub fn reset_reputation(&mut self) -> ReputationChangeOutcome {
         self.reputation = DEFAULT_REPUTATION;
+        self.score = 0;
 
         ReputationChangeOutcome::None
     }
@@ -106,6 +111,13 @@ impl Peer {
         ReputationChangeOutcome::None
     }
 
+    /// Applies a change of the score of a custom peer scoring function to the reputation and
+    /// returns what action should be taken.
+    pub fn apply_score_change(&mut self, change: i32) -> ReputationChangeOutcome {
+        self.score = self.score.saturating_add(change);
+        self.apply_reputation(change)
+    }
+
     /// Returns true if the peer's reputation is below the banned threshold.
     #[inline]
     pub const fn is_banned(&self) -> bool {
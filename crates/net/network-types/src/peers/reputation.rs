@@ -9,6 +9,10 @@ const REPUTATION_UNIT: i32 = -1024;
 /// The reputation value below which new connection from/to peers are rejected.
 pub const BANNED_REPUTATION: i32 = 50 * REPUTATION_UNIT;
 
+/// The score of a custom peer scoring function at or above which a peer is promoted to a trusted
+/// peer.
+pub const TRUSTED_PEER_SCORE: i32 = -BANNED_REPUTATION;
+
 /// The reputation change to apply to a peer that dropped the connection.
 const REMOTE_DISCONNECT_REPUTATION_CHANGE: i32 = 4 * REPUTATION_UNIT;
 
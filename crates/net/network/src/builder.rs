@@ -1,11 +1,13 @@
 //! Builder support for configuring the entire setup.
 
-use reth_network_api::test_utils::PeersHandleProvider;
+use reth_network_api::{test_utils::PeersHandleProvider, PeerInfo};
+use reth_network_peers::{NodeRecord, PeerId};
 use reth_transaction_pool::TransactionPool;
 use tokio::sync::mpsc;
 
 use crate::{
     eth_requests::EthRequestHandler,
+    peers::PeerScoring,
     transactions::{TransactionsManager, TransactionsManagerConfig},
     NetworkHandle, NetworkManager,
 };
@@ -53,6 +55,35 @@ impl<Tx, Eth> NetworkBuilder<Tx, Eth> {
         (handle, network, transactions, request_handler)
     }
 
+    /// Sets a custom peer scoring function to bias the reputation management of the network.
+    ///
+    /// The function is called when a session to a peer is established or the data of a connected
+    /// peer is updated, and its result is added to the internal reputation of the peer, replacing
+    /// the previous score. A score of at least
+    /// [`TRUSTED_PEER_SCORE`](reth_network_types::TRUSTED_PEER_SCORE) promotes the peer to a
+    /// trusted peer until its score drops below it again. A negative score that puts the
+    /// reputation below the banned threshold disconnects and bans the peer immediately, but the
+    /// reputation of trusted and static peers is slashed by a limited amount at once.
+    pub fn with_peer_scoring<F>(mut self, f: F) -> Self
+    where
+        F: Fn(PeerId, &PeerInfo) -> i32 + Send + Sync + 'static,
+    {
+        self.network.set_peer_scoring(PeerScoring::new(f));
+        self
+    }
+
+    /// Adds static peers that the network always tries to stay connected to, like the static nodes
+    /// of geth.
+    ///
+    /// Static peers are never removed from the peer set, a static peer that disconnects or can't
+    /// be reached is backed off and reconnected once the backoff expired. Static peers are
+    /// prioritized when filling outbound slots, are exempt from the inbound connection limits and
+    /// from reputation slashing for failures that can be attributed to network conditions.
+    pub fn with_static_peers(mut self, peers: Vec<NodeRecord>) -> Self {
+        self.network.add_static_peers(peers);
+        self
+    }
+
     /// Creates a new [`TransactionsManager`] and wires it to the network.
     pub fn transactions<Pool: TransactionPool>(
         self,
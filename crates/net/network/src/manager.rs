@@ -36,7 +36,7 @@ use reth_network_api::{
     test_utils::PeersHandle, EthProtocolInfo, NetworkEvent, NetworkStatus, PeerInfo, PeerRequest,
 };
 use reth_network_peers::{NodeRecord, PeerId};
-use reth_network_types::ReputationChangeKind;
+use reth_network_types::{PeerAddr, PeerKind, ReputationChangeKind};
 use reth_storage_api::BlockNumReader;
 use reth_tasks::shutdown::GracefulShutdown;
 use reth_tokio_util::EventSender;
@@ -56,7 +56,7 @@ use crate::{
     message::{NewBlockMessage, PeerMessage},
     metrics::{DisconnectMetrics, NetworkMetrics, NETWORK_POOL_TRANSACTIONS_SCOPE},
     network::{NetworkHandle, NetworkHandleMessage},
-    peers::PeersManager,
+    peers::{PeerScoring, PeersManager},
     poll_nested_stream_with_budget,
     protocol::IntoRlpxSubProtocol,
     session::SessionManager,
@@ -113,6 +113,9 @@ pub struct NetworkManager {
     metrics: NetworkMetrics,
     /// Disconnect metrics for the Network
     disconnect_metrics: DisconnectMetrics,
+    /// Custom peer scoring function that is applied when a session is established or the data of
+    /// an active peer is updated.
+    peer_scoring: Option<PeerScoring>,
 }
 
 // === impl NetworkManager ===
@@ -130,6 +133,21 @@ impl NetworkManager {
         self.to_eth_request_handler = Some(tx);
     }
 
+    /// Sets the custom peer scoring function, see
+    /// [`NetworkBuilder::with_peer_scoring`](crate::NetworkBuilder::with_peer_scoring).
+    pub fn set_peer_scoring(&mut self, scoring: PeerScoring) {
+        self.peer_scoring = Some(scoring);
+    }
+
+    /// Adds [`PeerKind::Static`] peers to the peer set, see
+    /// [`NetworkBuilder::with_static_peers`](crate::NetworkBuilder::with_static_peers).
+    pub fn add_static_peers(&mut self, peers: impl IntoIterator<Item = NodeRecord>) {
+        for peer in peers {
+            let addr = PeerAddr::new(peer.tcp_addr(), Some(peer.udp_addr()));
+            self.swarm.state_mut().add_peer_kind(peer.id, PeerKind::Static, addr);
+        }
+    }
+
     /// Adds an additional protocol handler to the `RLPx` sub-protocol list.
     pub fn add_rlpx_sub_protocol(&mut self, protocol: impl IntoRlpxSubProtocol) {
         self.swarm.add_rlpx_sub_protocol(protocol)
@@ -282,6 +300,7 @@ impl NetworkManager {
             num_active_peers,
             metrics: Default::default(),
             disconnect_metrics: Default::default(),
+            peer_scoring: None,
         })
     }
 
@@ -706,6 +725,8 @@ impl NetworkManager {
                     self.swarm.state_mut().peers_mut().on_active_outgoing_established(peer_id);
                 }
 
+                self.score_peer(peer_id);
+
                 self.update_active_connection_metrics();
 
                 self.event_sender.notify(NetworkEvent::SessionEstablished {
@@ -913,6 +934,22 @@ impl NetworkManager {
         })
     }
 
+    /// Applies the score of the custom peer scoring function to the active peer, if one is set.
+    fn score_peer(&mut self, peer_id: PeerId) {
+        let Some(scoring) = &self.peer_scoring else { return };
+        if let Some(info) = self.get_peer_info_by_id(peer_id) {
+            let score = scoring.score(peer_id, &info);
+            self.swarm.state_mut().peers_mut().apply_peer_score(&peer_id, score);
+        }
+    }
+
+    /// Scores the active peers whose data was updated again, see [`Self::score_peer`].
+    fn score_updated_peers(&mut self) {
+        for peer_id in self.swarm.state_mut().peers_mut().take_updated_peers() {
+            self.score_peer(peer_id);
+        }
+    }
+
     /// Returns [`PeerInfo`] for a given peers.
     ///
     /// Ignore the non-active peer.
@@ -1040,6 +1077,8 @@ impl Future for NetworkManager {
         poll_durations.acc_swarm =
             start_network_handle.elapsed() - poll_durations.acc_network_handle;
 
+        this.score_updated_peers();
+
         // all streams are fully drained and import futures pending
         if maybe_more_handle_messages || maybe_more_swarm_events {
             // make sure we're woken up again
@@ -2,9 +2,10 @@
 
 use std::{
     collections::{hash_map::Entry, HashMap, HashSet, VecDeque},
-    fmt::Display,
+    fmt::{self, Display},
     io::{self},
     net::{IpAddr, SocketAddr},
+    sync::Arc,
     task::{Context, Poll},
     time::Duration,
 };
@@ -12,7 +13,10 @@ use std::{
 use futures::StreamExt;
 use reth_eth_wire::{errors::EthStreamError, DisconnectReason};
 use reth_net_banlist::BanList;
-use reth_network_api::test_utils::{PeerCommand, PeersHandle};
+use reth_network_api::{
+    test_utils::{PeerCommand, PeersHandle},
+    PeerInfo,
+};
 use reth_network_peers::{NodeRecord, PeerId};
 use reth_network_types::{
     peers::{
@@ -20,7 +24,7 @@ use reth_network_types::{
         reputation::{DEFAULT_REPUTATION, MAX_TRUSTED_PEER_REPUTATION_CHANGE},
     },
     ConnectionsConfig, Peer, PeerAddr, PeerConnectionState, PeerKind, PeersConfig,
-    ReputationChangeKind, ReputationChangeOutcome, ReputationChangeWeights,
+    ReputationChangeKind, ReputationChangeOutcome, ReputationChangeWeights, TRUSTED_PEER_SCORE,
 };
 use reth_primitives::ForkId;
 use thiserror::Error;
@@ -37,6 +41,32 @@ use crate::{
     swarm::NetworkConnectionState,
 };
 
+/// A custom peer scoring function, see
+/// [`NetworkBuilder::with_peer_scoring`](crate::NetworkBuilder::with_peer_scoring).
+#[derive(Clone)]
+pub struct PeerScoring(Arc<dyn Fn(PeerId, &PeerInfo) -> i32 + Send + Sync>);
+
+impl PeerScoring {
+    /// Creates a new [`PeerScoring`] from the given scoring function.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(PeerId, &PeerInfo) -> i32 + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    /// Returns the score of the peer.
+    pub fn score(&self, peer_id: PeerId, info: &PeerInfo) -> i32 {
+        (self.0)(peer_id, info)
+    }
+}
+
+impl fmt::Debug for PeerScoring {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PeerScoring").finish_non_exhaustive()
+    }
+}
+
 /// Maintains the state of _all_ the peers known to the network.
 ///
 /// This is supposed to be owned by the network itself, but can be reached via the [`PeersHandle`].
@@ -52,6 +82,19 @@ pub struct PeersManager {
     /// This tracks peer ids that are considered trusted, but for which we don't necessarily have
     /// an address: [`Self::add_trusted_peer_id`]
     trusted_peer_ids: HashSet<PeerId>,
+    /// The set of static peer ids, see [`PeerKind::Static`].
+    static_peer_ids: HashSet<PeerId>,
+    /// The IPs of all static peers.
+    ///
+    /// Incoming connections from these IPs are exempt from the inbound limits and the IP rate
+    /// limit until the handshake, after which the peer id is checked.
+    static_peer_ips: HashSet<IpAddr>,
+    /// Peers that were promoted to trusted peers by a custom peer scoring function, with their
+    /// kind before the promotion.
+    score_promoted_peers: HashMap<PeerId, PeerKind>,
+    /// Connected peers whose data was updated since they were last scored by a custom peer
+    /// scoring function.
+    updated_peers: HashSet<PeerId>,
     /// Copy of the sender half, so new [`PeersHandle`] can be created on demand.
     manager_tx: mpsc::UnboundedSender<PeerCommand>,
     /// Receiver half of the command channel.
@@ -136,6 +179,10 @@ impl PeersManager {
         Self {
             peers,
             trusted_peer_ids,
+            static_peer_ids: Default::default(),
+            static_peer_ips: Default::default(),
+            score_promoted_peers: Default::default(),
+            updated_peers: Default::default(),
             manager_tx,
             handle_rx: UnboundedReceiverStream::new(handle_rx),
             queued_actions: Default::default(),
@@ -238,8 +285,13 @@ impl PeersManager {
             return Err(InboundConnectionError::IpBanned)
         }
 
+        // connections from the IP of a static peer are exempt from the inbound limits and the rate
+        // limit, whether the remote is the static peer is checked after the handshake, see
+        // `on_incoming_session_established`
+        let is_static_peer_ip = self.static_peer_ips.contains(&addr);
+
         // check if we even have slots for a new incoming connection
-        if !self.connection_info.has_in_capacity() {
+        if !self.connection_info.has_in_capacity() && !is_static_peer_ip {
             if self.trusted_peer_ids.is_empty() {
                 // if we don't have any incoming slots and no trusted peers, we don't accept any new
                 // connections
@@ -270,12 +322,25 @@ impl PeersManager {
         }
 
         // apply the rate limit
-        self.throttle_incoming_ip(addr);
+        if !is_static_peer_ip {
+            self.throttle_incoming_ip(addr);
+        }
 
         self.connection_info.inc_pending_in();
         Ok(())
     }
 
+    /// Updates the IPs of the static peers after a static peer was added, removed or its address
+    /// changed.
+    fn update_static_peer_ips(&mut self) {
+        self.static_peer_ips = self
+            .static_peer_ids
+            .iter()
+            .filter_map(|peer_id| self.peers.get(peer_id))
+            .map(|peer| peer.addr.tcp().ip())
+            .collect();
+    }
+
     /// Invoked when a previous call to [`Self::on_incoming_pending_session`] succeeded but it was
     /// rejected.
     pub(crate) fn on_incoming_pending_session_rejected_internally(&mut self) {
@@ -323,6 +388,7 @@ impl PeersManager {
 
         // check if the peer is trustable or not
         let mut is_trusted = self.trusted_peer_ids.contains(&peer_id);
+        let is_static = self.static_peer_ids.contains(&peer_id);
         if self.trusted_nodes_only && !is_trusted {
             self.queued_actions.push_back(PeerAction::DisconnectUntrustedIncoming { peer_id });
             return
@@ -361,8 +427,9 @@ impl PeersManager {
             }
         }
 
-        // disconnect the peer if we don't have capacity for more inbound connections
-        if !is_trusted && !has_in_capacity {
+        // disconnect the peer if we don't have capacity for more inbound connections, trusted and
+        // static peers are exempt from the limit
+        if !is_trusted && !is_static && !has_in_capacity {
             self.queued_actions.push_back(PeerAction::Disconnect {
                 peer_id,
                 reason: Some(DisconnectReason::TooManyPeers),
@@ -442,9 +509,9 @@ impl PeersManager {
 
     /// Apply the corresponding reputation change to the given peer.
     ///
-    /// If the peer is a trusted peer, it will be exempt from reputation slashing for certain
-    /// reputation changes that can be attributed to network conditions. If the peer is a
-    /// trusted peer, it will also be less strict with the reputation slashing.
+    /// If the peer is a trusted or static peer, it will be exempt from reputation slashing for
+    /// certain reputation changes that can be attributed to network conditions. If the peer is a
+    /// trusted or static peer, it will also be less strict with the reputation slashing.
     pub(crate) fn apply_reputation_change(&mut self, peer_id: &PeerId, rep: ReputationChangeKind) {
         let outcome = if let Some(peer) = self.peers.get_mut(peer_id) {
             // First check if we should reset the reputation
@@ -476,6 +543,49 @@ impl PeersManager {
             return
         };
 
+        self.on_reputation_change_outcome(peer_id, outcome);
+    }
+
+    /// Applies the score of a custom peer scoring function to the peer's reputation, replacing the
+    /// score that was applied before.
+    ///
+    /// A peer with a score of at least [`TRUSTED_PEER_SCORE`] is promoted to a trusted peer, and
+    /// demoted again once its score drops below it. A score that puts the reputation below the
+    /// banned threshold disconnects and bans the peer. Like other reputation changes, the
+    /// reputation of trusted and static peers is lowered by at most
+    /// [`MAX_TRUSTED_PEER_REPUTATION_CHANGE`] at once.
+    pub(crate) fn apply_peer_score(&mut self, peer_id: &PeerId, score: i32) {
+        let Some(peer) = self.peers.get_mut(peer_id) else { return };
+        if score >= TRUSTED_PEER_SCORE {
+            if !peer.is_trusted() {
+                trace!(target: "net::peers", ?peer_id, score, "promoting scored peer to trusted");
+                self.score_promoted_peers.insert(*peer_id, peer.kind);
+                peer.kind = PeerKind::Trusted;
+                self.trusted_peer_ids.insert(*peer_id);
+            }
+        } else if let Some(kind) = self.score_promoted_peers.remove(peer_id) {
+            trace!(target: "net::peers", ?peer_id, score, "demoting scored peer from trusted");
+            peer.kind = kind;
+            self.trusted_peer_ids.remove(peer_id);
+        }
+
+        let mut score_change = score.saturating_sub(peer.score);
+        if peer.is_trusted() || peer.is_static() {
+            // be less strict with the reputation slashing for trusted and static peers
+            score_change = score_change.max(MAX_TRUSTED_PEER_REPUTATION_CHANGE);
+        }
+        let outcome = peer.apply_score_change(score_change);
+        self.on_reputation_change_outcome(peer_id, outcome);
+    }
+
+    /// Returns the connected peers whose data was updated since they were last scored, see
+    /// [`Self::apply_peer_score`].
+    pub(crate) fn take_updated_peers(&mut self) -> HashSet<PeerId> {
+        std::mem::take(&mut self.updated_peers)
+    }
+
+    /// Performs the action required by the outcome of a reputation change of the peer.
+    fn on_reputation_change_outcome(&mut self, peer_id: &PeerId, outcome: ReputationChangeOutcome) {
         match outcome {
             ReputationChangeOutcome::None => {}
             ReputationChangeOutcome::Ban => {
@@ -594,8 +704,8 @@ impl PeersManager {
             // issues.
             if let Entry::Occupied(mut entry) = self.peers.entry(*peer_id) {
                 self.connection_info.decr_state(entry.get().state);
-                // only remove if the peer is not trusted
-                if entry.get().is_trusted() {
+                // only remove if the peer is not trusted or static
+                if entry.get().is_trusted() || entry.get().is_static() {
                     entry.get_mut().state = PeerConnectionState::Idle;
                 } else {
                     entry.remove();
@@ -639,9 +749,12 @@ impl PeersManager {
                 self.connection_info.decr_state(peer.state);
                 peer.state = PeerConnectionState::Idle;
 
-                if peer.severe_backoff_counter > self.max_backoff_count && !peer.is_trusted() {
+                if peer.severe_backoff_counter > self.max_backoff_count &&
+                    !peer.is_trusted() &&
+                    !peer.is_static()
+                {
                     // mark peer for removal if it has been backoff too many times and is _not_
-                    // trusted
+                    // trusted or static
                     remove_peer = true;
                 }
             }
@@ -649,6 +762,7 @@ impl PeersManager {
             // remove peer if it has been marked for removal
             if remove_peer {
                 let (peer_id, _) = self.peers.remove_entry(peer_id).expect("peer must exist");
+                self.score_promoted_peers.remove(&peer_id);
                 self.queued_actions.push_back(PeerAction::PeerRemoved(peer_id));
             } else if let Some(backoff_until) = backoff_until {
                 // otherwise, backoff the peer if marked as such
@@ -729,6 +843,15 @@ impl PeersManager {
                 peer.fork_id = fork_id;
                 peer.addr = addr;
 
+                // the new kind replaces a kind set by the custom peer scoring function, the peer is
+                // scored again if it's connected
+                if self.score_promoted_peers.remove(&peer_id).is_some() && !kind.is_trusted() {
+                    self.trusted_peer_ids.remove(&peer_id);
+                }
+                if peer.state.is_connected() {
+                    self.updated_peers.insert(peer_id);
+                }
+
                 if peer.state.is_incoming() {
                     // now that we have an actual discovered address, for that peer and not just the
                     // ip of the incoming connection, we don't need to remove the peer after
@@ -748,6 +871,14 @@ impl PeersManager {
         if kind.is_trusted() {
             self.trusted_peer_ids.insert(peer_id);
         }
+
+        // the address of a static peer may have changed
+        if kind.is_static() {
+            self.static_peer_ids.insert(peer_id);
+            self.update_static_peer_ips();
+        } else if self.static_peer_ids.remove(&peer_id) {
+            self.update_static_peer_ips();
+        }
     }
 
     /// Removes the tracked node from the set.
@@ -757,6 +888,9 @@ impl PeersManager {
             return
         }
         let mut peer = entry.remove();
+        if self.static_peer_ids.remove(&peer_id) {
+            self.update_static_peer_ips();
+        }
 
         trace!(target: "net::peers", ?peer_id, "remove discovered node");
         self.queued_actions.push_back(PeerAction::PeerRemoved(peer_id));
@@ -817,6 +951,10 @@ impl PeersManager {
         if kind.is_trusted() {
             self.trusted_peer_ids.insert(peer_id);
         }
+        if kind.is_static() {
+            self.static_peer_ids.insert(peer_id);
+            self.update_static_peer_ips();
+        }
     }
 
     /// Removes the tracked node from the trusted set.
@@ -830,6 +968,7 @@ impl PeersManager {
         peer.kind = PeerKind::Basic;
 
         self.trusted_peer_ids.remove(&peer_id);
+        self.score_promoted_peers.remove(&peer_id);
     }
 
     /// Returns the idle peer with the highest reputation.
@@ -1151,9 +1290,13 @@ mod tests {
     use reth_network_api::Direction;
     use reth_network_peers::{PeerId, TrustedPeer};
     use reth_network_types::{
-        peers::reputation::DEFAULT_REPUTATION, BackoffKind, ReputationChangeKind,
+        peers::reputation::{
+            BANNED_REPUTATION, DEFAULT_REPUTATION, MAX_TRUSTED_PEER_REPUTATION_CHANGE,
+        },
+        BackoffKind, PeerKind, ReputationChangeKind, TRUSTED_PEER_SCORE,
     };
     use std::{
+        collections::HashSet,
         future::{poll_fn, Future},
         io,
         net::{IpAddr, Ipv4Addr, SocketAddr},
@@ -1849,6 +1992,207 @@ mod tests {
         assert_eq!(peers.connection_info.num_inbound, 1);
     }
 
+    #[tokio::test]
+    async fn test_apply_peer_score() {
+        let peer = PeerId::random();
+        let socket_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 1, 2)), 8008);
+        let mut peers = PeersManager::default();
+        peers.add_peer(peer, PeerAddr::from_tcp(socket_addr), None);
+
+        match event!(peers) {
+            PeerAction::PeerAdded(peer_id) => {
+                assert_eq!(peer_id, peer);
+            }
+            _ => unreachable!(),
+        }
+        match event!(peers) {
+            PeerAction::Connect { peer_id, .. } => {
+                assert_eq!(peer_id, peer);
+            }
+            _ => unreachable!(),
+        }
+        peers.on_active_outgoing_established(peer);
+
+        peers.apply_peer_score(&peer, 100);
+        let p = peers.peers.get(&peer).unwrap();
+        assert_eq!(p.reputation, DEFAULT_REPUTATION + 100);
+        assert!(!p.is_trusted());
+
+        // the new score replaces the previous score
+        peers.apply_peer_score(&peer, TRUSTED_PEER_SCORE);
+        let p = peers.peers.get(&peer).unwrap();
+        assert_eq!(p.reputation, DEFAULT_REPUTATION + TRUSTED_PEER_SCORE);
+        assert!(p.is_trusted());
+        assert!(peers.trusted_peer_ids.contains(&peer));
+
+        // the promotion is undone once the score drops
+        peers.apply_peer_score(&peer, 100);
+        let p = peers.peers.get(&peer).unwrap();
+        assert_eq!(p.reputation, DEFAULT_REPUTATION + 100);
+        assert!(!p.is_trusted());
+        assert!(!peers.trusted_peer_ids.contains(&peer));
+
+        // updating the data of the connected peer marks it to be scored again
+        assert!(peers.take_updated_peers().is_empty());
+        peers.add_peer(peer, PeerAddr::from_tcp(socket_addr), None);
+        assert_eq!(peers.take_updated_peers(), HashSet::from([peer]));
+        assert!(peers.take_updated_peers().is_empty());
+
+        peers.apply_peer_score(&peer, 2 * BANNED_REPUTATION);
+        let p = peers.peers.get(&peer).unwrap();
+        assert!(p.is_banned());
+        assert_eq!(p.state, PeerConnectionState::DisconnectingOut);
+        match event!(peers) {
+            PeerAction::Disconnect { peer_id, .. } => {
+                assert_eq!(peer_id, peer);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_static_peer_not_removed_on_max_backoff_count() {
+        let peer = PeerId::random();
+        let socket_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 1, 2)), 8008);
+        let config = PeersConfig::test();
+        let mut peers = PeersManager::new(config.clone());
+        peers.add_peer_kind(peer, PeerKind::Static, PeerAddr::from_tcp(socket_addr), None);
+        peers.peers.get_mut(&peer).unwrap().severe_backoff_counter = config.max_backoff_count;
+
+        match event!(peers) {
+            PeerAction::PeerAdded(peer_id) => {
+                assert_eq!(peer_id, peer);
+            }
+            _ => unreachable!(),
+        }
+        match event!(peers) {
+            PeerAction::Connect { peer_id, .. } => {
+                assert_eq!(peer_id, peer);
+            }
+            _ => unreachable!(),
+        }
+
+        peers.on_outgoing_pending_session_dropped(
+            &socket_addr,
+            &peer,
+            &PendingSessionHandshakeError::Eth(
+                io::Error::new(io::ErrorKind::ConnectionRefused, "peer unreachable").into(),
+            ),
+        );
+
+        // the static peer is backed off instead of removed
+        let p = peers.peers.get(&peer).unwrap();
+        assert!(p.backed_off);
+        assert_eq!(p.state, PeerConnectionState::Idle);
+
+        // and reconnected once the backoff expired
+        tokio::time::sleep(config.backoff_durations.max).await;
+        match event!(peers) {
+            PeerAction::Connect { peer_id, remote_addr } => {
+                assert_eq!(peer_id, peer);
+                assert_eq!(remote_addr, socket_addr);
+            }
+            err => unreachable!("{err:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_incoming_static_peer_exceeds_capacity() {
+        let peer = PeerId::random();
+        let socket_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 1, 2)), 8008);
+        let config = PeersConfig::test().with_max_inbound(0);
+        let mut peers = PeersManager::new(config);
+        peers.add_peer_kind(peer, PeerKind::Static, PeerAddr::from_tcp(socket_addr), None);
+
+        match event!(peers) {
+            PeerAction::PeerAdded(peer_id) => {
+                assert_eq!(peer_id, peer);
+            }
+            _ => unreachable!(),
+        }
+
+        assert!(peers.on_incoming_pending_session(socket_addr.ip()).is_ok());
+        peers.on_incoming_session_established(peer, socket_addr);
+
+        // the static peer is not disconnected even though there's no inbound capacity
+        assert!(!peers
+            .queued_actions
+            .iter()
+            .any(|action| matches!(action, PeerAction::Disconnect { .. })));
+        assert_eq!(peers.peers.get(&peer).unwrap().state, PeerConnectionState::In);
+
+        // another peer from the same IP is disconnected after the handshake
+        let other = PeerId::random();
+        let other_addr = SocketAddr::new(socket_addr.ip(), 8009);
+        assert!(peers.on_incoming_pending_session(other_addr.ip()).is_ok());
+        peers.on_incoming_session_established(other, other_addr);
+        assert!(peers.queued_actions.iter().any(|action| matches!(
+            action,
+            PeerAction::Disconnect { peer_id, reason: Some(DisconnectReason::TooManyPeers) }
+                if *peer_id == other
+        )));
+
+        // the IP is no longer exempt once the static peer is removed
+        peers.remove_peer(peer);
+        assert!(peers.static_peer_ips.is_empty());
+        assert!(peers.on_incoming_pending_session(socket_addr.ip()).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_reputation_change_static_peer() {
+        let peer = PeerId::random();
+        let socket_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 1, 2)), 8008);
+        let mut peers = PeersManager::default();
+        peers.add_peer_kind(peer, PeerKind::Static, PeerAddr::from_tcp(socket_addr), None);
+
+        // static peers are exempt from slashing for failures caused by network conditions
+        peers.apply_reputation_change(&peer, ReputationChangeKind::Timeout);
+        peers.apply_reputation_change(&peer, ReputationChangeKind::Dropped);
+        assert_eq!(peers.peers.get(&peer).unwrap().reputation, DEFAULT_REPUTATION);
+
+        // and other reputation changes are capped
+        peers.apply_reputation_change(&peer, ReputationChangeKind::BadProtocol);
+        let p = peers.peers.get(&peer).unwrap();
+        assert_eq!(p.reputation, DEFAULT_REPUTATION + MAX_TRUSTED_PEER_REPUTATION_CHANGE);
+        assert!(!p.is_banned());
+    }
+
+    #[tokio::test]
+    async fn test_apply_peer_score_trusted_peer() {
+        let peer = PeerId::random();
+        let socket_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 1, 2)), 8008);
+        let mut peers = PeersManager::default();
+        peers.add_trusted_peer(peer, PeerAddr::from_tcp(socket_addr));
+
+        match event!(peers) {
+            PeerAction::PeerAdded(peer_id) => {
+                assert_eq!(peer_id, peer);
+            }
+            _ => unreachable!(),
+        }
+        match event!(peers) {
+            PeerAction::Connect { peer_id, .. } => {
+                assert_eq!(peer_id, peer);
+            }
+            _ => unreachable!(),
+        }
+        peers.on_active_outgoing_established(peer);
+
+        // the reputation of a trusted peer is slashed by a limited amount
+        peers.apply_peer_score(&peer, 2 * BANNED_REPUTATION);
+        let p = peers.peers.get(&peer).unwrap();
+        assert_eq!(p.reputation, DEFAULT_REPUTATION + MAX_TRUSTED_PEER_REPUTATION_CHANGE);
+        assert!(!p.is_banned());
+        assert!(p.is_trusted());
+        assert_eq!(p.state, PeerConnectionState::Out);
+
+        // a configured trusted peer is not demoted
+        peers.apply_peer_score(&peer, 0);
+        let p = peers.peers.get(&peer).unwrap();
+        assert_eq!(p.reputation, DEFAULT_REPUTATION);
+        assert!(p.is_trusted());
+    }
+
     #[tokio::test]
     async fn test_reputation_change_trusted_peer() {
         let peer = PeerId::random();
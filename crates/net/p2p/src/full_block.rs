@@ -3,6 +3,7 @@ use crate::{
     bodies::client::{BodiesClient, SingleBodyRequest},
     error::PeerRequestResult,
     headers::client::{HeadersClient, SingleHeaderRequest},
+    priority::Priority,
     BlockClient,
 };
 use alloy_primitives::{Sealable, B256};
@@ -52,16 +53,30 @@ where
     /// Caution: This does no validation of body (transactions) response but guarantees that the
     /// [`SealedHeader`] matches the requested hash.
     pub fn get_full_block(&self, hash: B256) -> FetchFullBlockFuture<Client> {
+        self.get_full_block_with_priority(hash, Priority::Normal)
+    }
+
+    /// Returns a future that fetches the [`SealedBlock`] for the given hash with the given
+    /// [`Priority`].
+    ///
+    /// See also [`Self::get_full_block`].
+    pub fn get_full_block_with_priority(
+        &self,
+        hash: B256,
+        priority: Priority,
+    ) -> FetchFullBlockFuture<Client> {
         let client = self.client.clone();
         FetchFullBlockFuture {
             hash,
+            priority,
             request: FullBlockRequest {
-                header: Some(client.get_header(hash.into())),
-                body: Some(client.get_block_body(hash)),
+                header: Some(client.get_header_with_priority(hash.into(), priority)),
+                body: Some(client.get_block_body_with_priority(hash, priority)),
             },
             client,
             header: None,
             body: None,
+            failed_requests: 0,
         }
     }
 
@@ -78,17 +93,51 @@ where
         &self,
         hash: B256,
         count: u64,
+    ) -> FetchFullBlockRangeFuture<Client> {
+        self.get_full_block_range_with_priority(hash, count, Priority::Normal)
+    }
+
+    /// Returns a future that fetches [`SealedBlock`]s for the given hash and count with the given
+    /// [`Priority`].
+    ///
+    /// See also [`Self::get_full_block_range`].
+    pub fn get_full_block_range_with_priority(
+        &self,
+        hash: B256,
+        count: u64,
+        priority: Priority,
+    ) -> FetchFullBlockRangeFuture<Client> {
+        self.get_full_block_range_in_direction(hash, count, HeadersDirection::Falling, priority)
+    }
+
+    /// Returns a future that fetches `count` [`SealedBlock`]s starting at the given hash in the
+    /// given direction.
+    ///
+    /// With [`HeadersDirection::Falling`] the given hash is the highest block of the range, with
+    /// [`HeadersDirection::Rising`] it is the lowest block of the range.
+    ///
+    /// The returned future yields blocks in falling order, i.e. with descending block numbers,
+    /// regardless of the direction.
+    ///
+    /// See also [`Self::get_full_block_range`].
+    pub fn get_full_block_range_in_direction(
+        &self,
+        hash: B256,
+        count: u64,
+        direction: HeadersDirection,
+        priority: Priority,
     ) -> FetchFullBlockRangeFuture<Client> {
         let client = self.client.clone();
         FetchFullBlockRangeFuture {
             start_hash: hash,
             count,
+            direction,
+            priority,
             request: FullBlockRangeRequest {
-                headers: Some(client.get_headers(HeadersRequest {
-                    start: hash.into(),
-                    limit: count,
-                    direction: HeadersDirection::Falling,
-                })),
+                headers: Some(client.get_headers_with_priority(
+                    HeadersRequest { start: hash.into(), limit: count, direction },
+                    priority,
+                )),
                 bodies: None,
             },
             client,
@@ -96,6 +145,42 @@ where
             pending_headers: VecDeque::new(),
             bodies: HashMap::default(),
             consensus: Arc::clone(&self.consensus),
+            failed_requests: 0,
+        }
+    }
+
+    /// Returns a future that fetches the block bodies for the given headers and yields the
+    /// [`SealedBlock`]s.
+    ///
+    /// Unlike [`Self::get_full_block_range`] this only requests the bodies, the headers are
+    /// expected to be valid already. The headers don't have to be contiguous.
+    ///
+    /// The returned future yields the blocks in falling order, i.e. with descending block numbers.
+    ///
+    /// Note: this future never resolves if `headers` is empty.
+    pub fn get_block_bodies_for_headers(
+        &self,
+        mut headers: Vec<SealedHeader>,
+        priority: Priority,
+    ) -> FetchFullBlockRangeFuture<Client> {
+        headers.sort_unstable_by_key(|h| Reverse(h.number));
+        let hashes = headers.iter().map(|h| h.hash()).collect::<Vec<_>>();
+        let client = self.client.clone();
+        FetchFullBlockRangeFuture {
+            start_hash: hashes.first().copied().unwrap_or_default(),
+            count: headers.len() as u64,
+            direction: HeadersDirection::Falling,
+            priority,
+            request: FullBlockRangeRequest {
+                headers: None,
+                bodies: Some(client.get_block_bodies_with_priority(hashes, priority)),
+            },
+            client,
+            pending_headers: headers.clone().into(),
+            headers: Some(headers),
+            bodies: HashMap::default(),
+            consensus: Arc::clone(&self.consensus),
+            failed_requests: 0,
         }
     }
 }
@@ -111,9 +196,13 @@ where
 {
     client: Client,
     hash: B256,
+    priority: Priority,
     request: FullBlockRequest<Client>,
     header: Option<SealedHeader>,
     body: Option<BodyResponse>,
+    /// The number of requests that failed or returned an invalid response and were retried, see
+    /// [`Self::take_failed_requests`].
+    failed_requests: usize,
 }
 
 impl<Client> FetchFullBlockFuture<Client>
@@ -125,11 +214,22 @@ where
         &self.hash
     }
 
+    /// Returns the priority of the requests.
+    pub const fn priority(&self) -> Priority {
+        self.priority
+    }
+
     /// If the header request is already complete, this returns the block number
     pub fn block_number(&self) -> Option<u64> {
         self.header.as_ref().map(|h| h.number)
     }
 
+    /// Returns the number of requests that failed, e.g. timed out, or returned an invalid response
+    /// since the last call. Failed requests are retried.
+    pub fn take_failed_requests(&mut self) -> usize {
+        std::mem::take(&mut self.failed_requests)
+    }
+
     /// Returns the [`SealedBlock`] if the request is complete and valid.
     fn take_block(&mut self) -> Option<SealedBlock> {
         if self.header.is_none() || self.body.is_none() {
@@ -145,8 +245,10 @@ where
                 if let Err(err) = ensure_valid_body_response(&header, resp.data()) {
                     debug!(target: "downloaders", %err, hash=?header.hash(), "Received wrong body");
                     self.client.report_bad_message(resp.peer_id());
+                    self.failed_requests += 1;
                     self.header = Some(header);
-                    self.request.body = Some(self.client.get_block_body(self.hash));
+                    self.request.body =
+                        Some(self.client.get_block_body_with_priority(self.hash, self.priority));
                     return None
                 }
                 Some(SealedBlock::new(header, resp.into_data()))
@@ -211,7 +313,10 @@ where
 
                     if this.header.is_none() {
                         // received bad response
-                        this.request.header = Some(this.client.get_header(this.hash.into()));
+                        this.failed_requests += 1;
+                        this.request.header = Some(
+                            this.client.get_header_with_priority(this.hash.into(), this.priority),
+                        );
                     }
                 }
                 ResponseResult::Body(res) => {
@@ -227,7 +332,10 @@ where
                     }
                     if this.body.is_none() {
                         // received bad response
-                        this.request.body = Some(this.client.get_block_body(this.hash));
+                        this.failed_requests += 1;
+                        this.request.body = Some(
+                            this.client.get_block_body_with_priority(this.hash, this.priority),
+                        );
                     }
                 }
             }
@@ -254,6 +362,7 @@ where
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("FetchFullBlockFuture")
             .field("hash", &self.hash)
+            .field("priority", &self.priority)
             .field("header", &self.header)
             .field("body", &self.body)
             .finish()
@@ -376,6 +485,10 @@ where
     start_hash: B256,
     /// How many blocks to fetch: `len([start_hash, ..]) == count`
     count: u64,
+    /// The direction in which the blocks are fetched, starting at `start_hash`.
+    direction: HeadersDirection,
+    /// The priority of the requests.
+    priority: Priority,
     /// Requests for headers and bodies that are in progress.
     request: FullBlockRangeRequest<Client>,
     /// Fetched headers.
@@ -384,6 +497,9 @@ where
     pending_headers: VecDeque<SealedHeader>,
     /// The bodies that have been received so far.
     bodies: HashMap<SealedHeader, BodyResponse>,
+    /// The number of requests that failed or returned an invalid response and were retried, see
+    /// [`Self::take_failed_requests`].
+    failed_requests: usize,
 }
 
 impl<Client> FetchFullBlockRangeFuture<Client>
@@ -465,6 +581,8 @@ where
         }
 
         if needs_retry {
+            self.failed_requests += 1;
+
             // put response hashes back into bodies map since we aren't returning them as a
             // response
             for block in valid_responses {
@@ -477,7 +595,8 @@ where
 
             // create response for failing bodies
             let hashes = self.remaining_bodies_hashes();
-            self.request.bodies = Some(self.client.get_block_bodies(hashes));
+            self.request.bodies =
+                Some(self.client.get_block_bodies_with_priority(hashes, self.priority));
             return None
         }
 
@@ -502,8 +621,13 @@ where
             // sort headers from highest to lowest block number
             headers_falling.sort_unstable_by_key(|h| Reverse(h.number));
 
-            // check the starting hash
-            if headers_falling[0].hash() == self.start_hash {
+            // check the starting hash, which is the highest block of a falling request and the
+            // lowest block of a rising request
+            let start = match self.direction {
+                HeadersDirection::Falling => &headers_falling[0],
+                HeadersDirection::Rising => &headers_falling[headers_falling.len() - 1],
+            };
+            if start.hash() == self.start_hash {
                 let headers_rising = headers_falling.iter().rev().cloned().collect::<Vec<_>>();
                 // check if the downloaded headers are valid
                 if let Err(err) = self.consensus.validate_header_range(&headers_rising) {
@@ -520,7 +644,8 @@ where
                 // set the actual request if it hasn't been started yet
                 if !self.has_bodies_request_started() {
                     // request the bodies for the downloaded headers
-                    self.request.bodies = Some(self.client.get_block_bodies(hashes));
+                    self.request.bodies =
+                        Some(self.client.get_block_bodies_with_priority(hashes, self.priority));
                 }
 
                 // set the headers response
@@ -547,6 +672,22 @@ where
     pub const fn count(&self) -> u64 {
         self.count
     }
+
+    /// Returns the direction of the request, starting at [`Self::start_hash`].
+    pub const fn direction(&self) -> HeadersDirection {
+        self.direction
+    }
+
+    /// Returns the priority of the requests.
+    pub const fn priority(&self) -> Priority {
+        self.priority
+    }
+
+    /// Returns the number of requests that failed, e.g. timed out, or returned an invalid response
+    /// since the last call. Failed requests are retried.
+    pub fn take_failed_requests(&mut self) -> usize {
+        std::mem::take(&mut self.failed_requests)
+    }
 }
 
 impl<Client> Future for FetchFullBlockRangeFuture<Client>
@@ -580,11 +721,15 @@ where
 
                     if this.headers.is_none() {
                         // did not receive a correct response yet, retry
-                        this.request.headers = Some(this.client.get_headers(HeadersRequest {
-                            start: this.start_hash.into(),
-                            limit: this.count,
-                            direction: HeadersDirection::Falling,
-                        }));
+                        this.failed_requests += 1;
+                        this.request.headers = Some(this.client.get_headers_with_priority(
+                            HeadersRequest {
+                                start: this.start_hash.into(),
+                                limit: this.count,
+                                direction: this.direction,
+                            },
+                            this.priority,
+                        ));
                     }
                 }
                 // This branch handles block body responses from peers - it first inserts the
@@ -610,11 +755,15 @@ where
                                 let req_hashes = this.remaining_bodies_hashes();
 
                                 // set a new request
-                                this.request.bodies = Some(this.client.get_block_bodies(req_hashes))
+                                this.request.bodies = Some(
+                                    this.client
+                                        .get_block_bodies_with_priority(req_hashes, this.priority),
+                                )
                             }
                         }
                         Err(err) => {
                             debug!(target: "downloaders", %err, ?this.start_hash, "Body range download failed");
+                            this.failed_requests += 1;
                         }
                     }
                     if this.bodies.is_empty() {
@@ -632,7 +781,9 @@ where
                         // the headers request completes, so this should always be `Some` anyways.
                         let hashes = this.remaining_bodies_hashes();
                         if !hashes.is_empty() {
-                            this.request.bodies = Some(this.client.get_block_bodies(hashes));
+                            this.request.bodies = Some(
+                                this.client.get_block_bodies_with_priority(hashes, this.priority),
+                            );
                         }
                     }
                 }
@@ -758,6 +909,23 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn download_block_bodies_for_headers() {
+        let client = TestFullBlockClient::default();
+        let (header, body) = insert_headers_into_client(&client, 0..50);
+        let client = FullBlockClient::test_client(client);
+
+        let received =
+            client.get_block_bodies_for_headers(vec![header.clone()], Priority::Normal).await;
+        assert_eq!(received, vec![SealedBlock::new(header.clone(), body)]);
+
+        // headers don't have to be contiguous or sorted
+        let blocks = client.get_full_block_range(header.hash(), 30).await;
+        let headers = [25, 3, 10].map(|idx| blocks[idx].header.clone()).to_vec();
+        let received = client.get_block_bodies_for_headers(headers, Priority::High).await;
+        assert_eq!(received, vec![blocks[3].clone(), blocks[10].clone(), blocks[25].clone()]);
+    }
+
     #[tokio::test]
     async fn download_full_block_range_over_soft_limit() {
         // default soft limit is 20, so we will request 50 blocks
@@ -7,7 +7,7 @@ mod states;
 
 pub use states::*;
 
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 use crate::{
     common::WithConfigs,
@@ -16,6 +16,7 @@ use crate::{
     rpc::{RethRpcAddOns, RethRpcServerHandles, RpcContext},
     DefaultNodeLauncher, LaunchNode, Node, NodeHandle,
 };
+use alloy_primitives::B256;
 use futures::Future;
 use reth_chainspec::{EthChainSpec, EthereumHardforks, Hardforks};
 use reth_cli_util::get_secret_key;
@@ -575,6 +576,8 @@ pub struct BuilderContext<Node: FullNodeTypes> {
     pub(crate) executor: TaskExecutor,
     /// Config container
     pub(crate) config_container: WithConfigs<<Node::Types as NodeTypes>::ChainSpec>,
+    /// The genesis hash of the configured chain, computed on first access.
+    pub(crate) genesis_hash: OnceLock<B256>,
 }
 
 impl<Node: FullNodeTypes> BuilderContext<Node> {
@@ -585,7 +588,7 @@ impl<Node: FullNodeTypes> BuilderContext<Node> {
         executor: TaskExecutor,
         config_container: WithConfigs<<Node::Types as NodeTypes>::ChainSpec>,
     ) -> Self {
-        Self { head, provider, executor, config_container }
+        Self { head, provider, executor, config_container, genesis_hash: OnceLock::new() }
     }
 
     /// Returns the configured provider to interact with the blockchain.
@@ -615,11 +618,29 @@ impl<Node: FullNodeTypes> BuilderContext<Node> {
         &self.executor
     }
 
+    /// Returns a copy of this context that spawns tasks on the given executor.
+    pub fn with_task_executor(&self, executor: TaskExecutor) -> Self {
+        Self {
+            head: self.head,
+            provider: self.provider.clone(),
+            executor,
+            config_container: self.config_container.clone(),
+            genesis_hash: self.genesis_hash.clone(),
+        }
+    }
+
     /// Returns the chain spec of the node.
     pub fn chain_spec(&self) -> Arc<<Node::Types as NodeTypes>::ChainSpec> {
         self.provider().chain_spec()
     }
 
+    /// Returns the genesis hash of the configured chain.
+    ///
+    /// The hash is computed on the first call and cached afterwards.
+    pub fn genesis_hash(&self) -> B256 {
+        *self.genesis_hash.get_or_init(|| self.chain_spec().genesis_hash())
+    }
+
     /// Returns true if the node is configured as --dev
     pub const fn is_dev(&self) -> bool {
         self.config().dev.dev
@@ -1,16 +1,25 @@
 //! A generic [`NodeComponentsBuilder`]
 
-use std::{future::Future, marker::PhantomData};
+use std::{
+    future::Future,
+    marker::PhantomData,
+    time::{Duration, Instant},
+};
 
 use reth_consensus::Consensus;
 use reth_evm::execute::BlockExecutorProvider;
+use reth_network::NetworkHandle;
+use reth_node_api::NodeTypesWithEngine;
+use reth_payload_builder::PayloadBuilderHandle;
 use reth_primitives::Header;
+use reth_tasks::TaskExecutor;
 use reth_transaction_pool::TransactionPool;
+use tracing::{debug, info_span, warn, Instrument, Span};
 
 use crate::{
     components::{
-        Components, ConsensusBuilder, ExecutorBuilder, NetworkBuilder, NodeComponents,
-        PayloadServiceBuilder, PoolBuilder,
+        BuildPlan, ComponentFamily, ComponentHooks, Components, ConsensusBuilder, ExecutorBuilder,
+        NetworkBuilder, NodeComponent, NodeComponents, PayloadServiceBuilder, PoolBuilder,
     },
     BuilderContext, ConfigureEvm, FullNodeTypes,
 };
@@ -31,9 +40,12 @@ use crate::{
 ///
 /// ## Builder lifecycle:
 ///
-/// First all standalone components are built. Then the service components are spawned.
-/// All component builders are captured in the builder state and will be consumed once the node is
-/// launched.
+/// First all standalone components are built concurrently. Then the service components are
+/// spawned concurrently. All component builders are captured in the builder state and will be
+/// consumed once the node is launched.
+///
+/// The build order can be customized with a [`BuildPlan`], see
+/// [`ComponentsBuilder::with_build_plan`].
 #[derive(Debug)]
 pub struct ComponentsBuilder<Node, PoolB, PayloadB, NetworkB, ExecB, ConsB> {
     pool_builder: PoolB,
@@ -41,9 +53,39 @@ pub struct ComponentsBuilder<Node, PoolB, PayloadB, NetworkB, ExecB, ConsB> {
     network_builder: NetworkB,
     executor_builder: ExecB,
     consensus_builder: ConsB,
+    /// Maximum duration of each component build step, if any.
+    build_timeout: Option<Duration>,
+    /// The order in which the components are built.
+    build_plan: BuildPlan,
+    /// Executor that replaces the context's task executor while building the components, if any.
+    task_executor: Option<TaskExecutor>,
     _marker: PhantomData<Node>,
 }
 
+impl<Node, PoolB, PayloadB, NetworkB, ExecB, ConsB> Clone
+    for ComponentsBuilder<Node, PoolB, PayloadB, NetworkB, ExecB, ConsB>
+where
+    PoolB: Clone,
+    PayloadB: Clone,
+    NetworkB: Clone,
+    ExecB: Clone,
+    ConsB: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pool_builder: self.pool_builder.clone(),
+            payload_builder: self.payload_builder.clone(),
+            network_builder: self.network_builder.clone(),
+            executor_builder: self.executor_builder.clone(),
+            consensus_builder: self.consensus_builder.clone(),
+            build_timeout: self.build_timeout,
+            build_plan: self.build_plan.clone(),
+            task_executor: self.task_executor.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
 impl<Node, PoolB, PayloadB, NetworkB, ExecB, ConsB>
     ComponentsBuilder<Node, PoolB, PayloadB, NetworkB, ExecB, ConsB>
 {
@@ -60,6 +102,9 @@ impl<Node, PoolB, PayloadB, NetworkB, ExecB, ConsB>
             network_builder,
             executor_builder: evm_builder,
             consensus_builder,
+            build_timeout,
+            build_plan,
+            task_executor,
             _marker,
         } = self;
         ComponentsBuilder {
@@ -68,6 +113,9 @@ impl<Node, PoolB, PayloadB, NetworkB, ExecB, ConsB>
             payload_builder,
             network_builder,
             consensus_builder,
+            build_timeout,
+            build_plan,
+            task_executor,
             _marker: Default::default(),
         }
     }
@@ -80,6 +128,9 @@ impl<Node, PoolB, PayloadB, NetworkB, ExecB, ConsB>
             network_builder: self.network_builder,
             executor_builder: self.executor_builder,
             consensus_builder: self.consensus_builder,
+            build_timeout: self.build_timeout,
+            build_plan: self.build_plan,
+            task_executor: self.task_executor,
             _marker: self._marker,
         }
     }
@@ -92,6 +143,9 @@ impl<Node, PoolB, PayloadB, NetworkB, ExecB, ConsB>
             network_builder: self.network_builder,
             executor_builder: self.executor_builder,
             consensus_builder: self.consensus_builder,
+            build_timeout: self.build_timeout,
+            build_plan: self.build_plan,
+            task_executor: self.task_executor,
             _marker: self._marker,
         }
     }
@@ -104,6 +158,9 @@ impl<Node, PoolB, PayloadB, NetworkB, ExecB, ConsB>
             network_builder: f(self.network_builder),
             executor_builder: self.executor_builder,
             consensus_builder: self.consensus_builder,
+            build_timeout: self.build_timeout,
+            build_plan: self.build_plan,
+            task_executor: self.task_executor,
             _marker: self._marker,
         }
     }
@@ -116,6 +173,9 @@ impl<Node, PoolB, PayloadB, NetworkB, ExecB, ConsB>
             network_builder: self.network_builder,
             executor_builder: f(self.executor_builder),
             consensus_builder: self.consensus_builder,
+            build_timeout: self.build_timeout,
+            build_plan: self.build_plan,
+            task_executor: self.task_executor,
             _marker: self._marker,
         }
     }
@@ -128,9 +188,69 @@ impl<Node, PoolB, PayloadB, NetworkB, ExecB, ConsB>
             network_builder: self.network_builder,
             executor_builder: self.executor_builder,
             consensus_builder: f(self.consensus_builder),
+            build_timeout: self.build_timeout,
+            build_plan: self.build_plan,
+            task_executor: self.task_executor,
             _marker: self._marker,
         }
     }
+
+    /// Consumes the type and returns the configured component builders.
+    pub fn into_parts(self) -> (PoolB, PayloadB, NetworkB, ExecB, ConsB) {
+        (
+            self.pool_builder,
+            self.payload_builder,
+            self.network_builder,
+            self.executor_builder,
+            self.consensus_builder,
+        )
+    }
+
+    /// Returns a [`NodeComponentsBuilder`] that configures the given builder with access to the
+    /// [`BuilderContext`] before the components are built.
+    ///
+    /// This can be used to override individual components based on the node's configuration, e.g.
+    /// the chain spec, without wiring all component builders manually:
+    ///
+    /// ```ignore
+    /// ComponentsBuilder::from_context(EthereumNode::components(), |ctx, builder| {
+    ///     builder.pool(CustomPoolBuilder::new(ctx.chain_spec()))
+    /// })
+    /// ```
+    pub const fn from_context<F, B>(builder: Self, f: F) -> ComponentsBuilderFromContext<Self, F>
+    where
+        Node: FullNodeTypes,
+        F: FnOnce(&BuilderContext<Node>, Self) -> B + Send,
+        B: NodeComponentsBuilder<Node>,
+    {
+        ComponentsBuilderFromContext { builder, f }
+    }
+
+    /// Sets the [`BuildPlan`] that declares the order in which the components are built.
+    ///
+    /// By default, the EVM, the transaction pool and consensus are built concurrently, followed by
+    /// the network and the payload builder service, see [`BuildPlan::stages`].
+    pub fn with_build_plan(self, build_plan: BuildPlan) -> Self {
+        Self { build_plan, ..self }
+    }
+
+    /// Sets the maximum duration of each component build step.
+    ///
+    /// If a component builder doesn't complete in time, building the components fails with an
+    /// error naming the component. By default there is no timeout.
+    pub const fn with_build_timeout(mut self, timeout: Duration) -> Self {
+        self.build_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the executor that the component builders spawn their tasks on, e.g. the payload
+    /// builder service and the network.
+    ///
+    /// By default, the task executor of the [`BuilderContext`] is used. Building the components
+    /// is still cancelled by the shutdown signal of the context's task executor.
+    pub fn with_task_executor(self, task_executor: TaskExecutor) -> Self {
+        Self { task_executor: Some(task_executor), ..self }
+    }
 }
 
 impl<Node, PoolB, PayloadB, NetworkB, ExecB, ConsB>
@@ -155,6 +275,9 @@ where
             network_builder,
             executor_builder: evm_builder,
             consensus_builder,
+            build_timeout,
+            build_plan,
+            task_executor,
             _marker,
         } = self;
         ComponentsBuilder {
@@ -163,6 +286,9 @@ where
             network_builder,
             executor_builder: evm_builder,
             consensus_builder,
+            build_timeout,
+            build_plan,
+            task_executor,
             _marker,
         }
     }
@@ -191,6 +317,9 @@ where
             network_builder: _,
             executor_builder: evm_builder,
             consensus_builder,
+            build_timeout,
+            build_plan,
+            task_executor,
             _marker,
         } = self;
         ComponentsBuilder {
@@ -199,6 +328,9 @@ where
             network_builder,
             executor_builder: evm_builder,
             consensus_builder,
+            build_timeout,
+            build_plan,
+            task_executor,
             _marker,
         }
     }
@@ -220,6 +352,9 @@ where
             network_builder,
             executor_builder: evm_builder,
             consensus_builder,
+            build_timeout,
+            build_plan,
+            task_executor,
             _marker,
         } = self;
         ComponentsBuilder {
@@ -228,6 +363,9 @@ where
             network_builder,
             executor_builder: evm_builder,
             consensus_builder,
+            build_timeout,
+            build_plan,
+            task_executor,
             _marker,
         }
     }
@@ -249,6 +387,9 @@ where
             network_builder,
             executor_builder: _,
             consensus_builder,
+            build_timeout,
+            build_plan,
+            task_executor,
             _marker,
         } = self;
         ComponentsBuilder {
@@ -257,6 +398,9 @@ where
             network_builder,
             executor_builder,
             consensus_builder,
+            build_timeout,
+            build_plan,
+            task_executor,
             _marker,
         }
     }
@@ -278,7 +422,9 @@ where
             network_builder,
             executor_builder,
             consensus_builder: _,
-
+            build_timeout,
+            build_plan,
+            task_executor,
             _marker,
         } = self;
         ComponentsBuilder {
@@ -287,13 +433,16 @@ where
             network_builder,
             executor_builder,
             consensus_builder,
+            build_timeout,
+            build_plan,
+            task_executor,
             _marker,
         }
     }
 }
 
-impl<Node, PoolB, PayloadB, NetworkB, ExecB, ConsB> NodeComponentsBuilder<Node>
-    for ComponentsBuilder<Node, PoolB, PayloadB, NetworkB, ExecB, ConsB>
+impl<Node, PoolB, PayloadB, NetworkB, ExecB, ConsB>
+    ComponentsBuilder<Node, PoolB, PayloadB, NetworkB, ExecB, ConsB>
 where
     Node: FullNodeTypes,
     PoolB: PoolBuilder<Node>,
@@ -302,29 +451,173 @@ where
     ExecB: ExecutorBuilder<Node>,
     ConsB: ConsensusBuilder<Node>,
 {
-    type Components = Components<Node, PoolB::Pool, ExecB::EVM, ExecB::Executor, ConsB::Consensus>;
+    /// Checks that all component builders that are specific to a [`ComponentFamily`] belong to
+    /// the same family.
+    ///
+    /// This is part of [`NodeComponentsBuilder::validate`], so incompatible builders are rejected
+    /// before any component is built.
+    pub fn check_compatibility(&self) -> Result<(), IncompatibleComponents> {
+        let families = [
+            (NodeComponent::Evm, self.executor_builder.family()),
+            (NodeComponent::Pool, self.pool_builder.family()),
+            (NodeComponent::Network, self.network_builder.family()),
+            (NodeComponent::PayloadService, self.payload_builder.family()),
+            (NodeComponent::Consensus, self.consensus_builder.family()),
+        ];
+        let mut families = families
+            .into_iter()
+            .filter_map(|(component, family)| family.map(|family| (component, family)));
 
-    async fn build_components(
+        let Some(first) = families.next() else { return Ok(()) };
+        match families.find(|(_, family)| *family != first.1) {
+            Some(second) => Err(IncompatibleComponents { first, second }),
+            None => Ok(()),
+        }
+    }
+
+    /// Builds all components of the node.
+    ///
+    /// This first runs [`NodeComponentsBuilder::validate`]. Components that don't depend on each
+    /// other are built concurrently, see [`BuildPlan::stages`].
+    ///
+    /// Building is cancelled with a [`BuildCancelled`] error once the shutdown signal of the
+    /// context's task executor fires, e.g. if the launch is aborted. The network is shut down if
+    /// it was already spawned, other services spawned on the task executor stop on the same
+    /// signal.
+    ///
+    /// Unlike [`NodeComponentsBuilder::build_components`], this returns the components that were
+    /// already built if one of the component builders fails, together with the error.
+    pub async fn try_build_components(
         self,
         context: &BuilderContext<Node>,
-    ) -> eyre::Result<Self::Components> {
+    ) -> Result<
+        Components<Node, PoolB::Pool, ExecB::EVM, ExecB::Executor, ConsB::Consensus>,
+        PartialComponents<Node, PoolB::Pool, ExecB::EVM, ExecB::Executor, ConsB::Consensus>,
+    > {
+        if let Err(error) = self.validate(context) {
+            return Err(PartialComponents::new(error))
+        }
+
         let Self {
             pool_builder,
             payload_builder,
             network_builder,
             executor_builder: evm_builder,
             consensus_builder,
+            build_timeout,
+            build_plan,
+            task_executor,
             _marker,
         } = self;
 
-        let (evm_config, executor) = evm_builder.build_evm(context).await?;
-        let pool = pool_builder.build_pool(context).await?;
-        let network = network_builder.build_network(context, pool.clone()).await?;
-        let payload_builder = payload_builder.spawn_payload_service(context, pool.clone()).await?;
-        let consensus = consensus_builder.build_consensus(context).await?;
+        let stages = match build_plan.stages() {
+            Ok(stages) => stages,
+            Err(error) => return Err(PartialComponents::new(error.into())),
+        };
+
+        // the build plan contains every component exactly once and builds the pool in an earlier
+        // stage if it's required by another component
+        let mut pool_builder = Some(pool_builder);
+        let mut payload_builder = Some(payload_builder);
+        let mut network_builder = Some(network_builder);
+        let mut evm_builder = Some(evm_builder);
+        let mut consensus_builder = Some(consensus_builder);
+
+        let mut evm = None;
+        let mut transaction_pool = None;
+        let mut network = None;
+        let mut payload_service = None;
+        let mut consensus = None;
+
+        let cancelled = context.task_executor().on_shutdown_signal();
+        let overridden;
+        let context = match task_executor {
+            Some(task_executor) => {
+                overridden = context.with_task_executor(task_executor);
+                &overridden
+            }
+            None => context,
+        };
+        let span = build_components_span();
+        for stage in stages {
+            let pool = transaction_pool.clone();
+            let step = |component| StageStep {
+                parent: &span,
+                component,
+                timeout: build_timeout,
+                cancelled: cancelled.clone(),
+                stage: &stage,
+            };
+            // the components of a stage don't depend on each other
+            let (evm_step, pool_step, network_step, payload_step, consensus_step) = tokio::join!(
+                step(NodeComponent::Evm)
+                    .run(|| evm_builder.take().expect("evm is built once").build_evm(context)),
+                step(NodeComponent::Pool).run(|| {
+                    pool_builder.take().expect("pool is built once").build_pool(context)
+                }),
+                step(NodeComponent::Network).run(|| {
+                    let pool = pool.clone().expect("pool is built before network");
+                    network_builder
+                        .take()
+                        .expect("network is built once")
+                        .build_network(context, pool)
+                }),
+                step(NodeComponent::PayloadService).run(|| {
+                    let pool = pool.clone().expect("pool is built before payload service");
+                    payload_builder
+                        .take()
+                        .expect("payload service is built once")
+                        .spawn_payload_service(context, pool)
+                }),
+                step(NodeComponent::Consensus).run(|| {
+                    consensus_builder
+                        .take()
+                        .expect("consensus is built once")
+                        .build_consensus(context)
+                }),
+            );
+
+            // if several components of the stage fail, the error of the first one is returned
+            let mut error = None;
+            collect_built(evm_step, &mut evm, &mut error);
+            collect_built(pool_step, &mut transaction_pool, &mut error);
+            collect_built(network_step, &mut network, &mut error);
+            collect_built(payload_step, &mut payload_service, &mut error);
+            collect_built(consensus_step, &mut consensus, &mut error);
+
+            if let Some(error) = error {
+                if error.inner().is::<BuildCancelled>() {
+                    if let Some(network) = &network {
+                        // don't leave the network running without a node
+                        let _ = network.shutdown().await;
+                    }
+                }
+                let (evm_config, executor) = evm.unzip();
+                return Err(PartialComponents {
+                    transaction_pool,
+                    evm_config,
+                    executor,
+                    consensus,
+                    network,
+                    payload_builder: payload_service,
+                    error: error.into(),
+                })
+            }
+        }
+
+        let (
+            Some((evm_config, executor)),
+            Some(transaction_pool),
+            Some(network),
+            Some(payload_builder),
+            Some(consensus),
+        ) = (evm, transaction_pool, network, payload_service, consensus)
+        else {
+            unreachable!("the build plan contains all components")
+        };
 
         Ok(Components {
-            transaction_pool: pool,
+            transaction_pool,
             evm_config,
             network,
             payload_builder,
@@ -334,6 +627,269 @@ where
     }
 }
 
+impl<Node, PoolB, PayloadB, NetworkB, ExecB, ConsB> NodeComponentsBuilder<Node>
+    for ComponentsBuilder<Node, PoolB, PayloadB, NetworkB, ExecB, ConsB>
+where
+    Node: FullNodeTypes,
+    PoolB: PoolBuilder<Node>,
+    NetworkB: NetworkBuilder<Node, PoolB::Pool>,
+    PayloadB: PayloadServiceBuilder<Node, PoolB::Pool>,
+    ExecB: ExecutorBuilder<Node>,
+    ConsB: ConsensusBuilder<Node>,
+{
+    type Components = Components<Node, PoolB::Pool, ExecB::EVM, ExecB::Executor, ConsB::Consensus>;
+
+    fn validate(&self, _ctx: &BuilderContext<Node>) -> eyre::Result<()> {
+        Ok(self.check_compatibility()?)
+    }
+
+    /// Builds all components, see [`ComponentsBuilder::try_build_components`].
+    ///
+    /// If building fails, the network is shut down if it was already spawned, since the partly
+    /// built components are discarded.
+    async fn build_components(
+        self,
+        context: &BuilderContext<Node>,
+    ) -> eyre::Result<Self::Components> {
+        match self.try_build_components(context).await {
+            Ok(components) => Ok(components),
+            Err(partial) => {
+                if let Some(network) = &partial.network {
+                    let _ = network.shutdown().await;
+                }
+                Err(partial.error)
+            }
+        }
+    }
+}
+
+/// Error returned if building the components is cancelled, see
+/// [`ComponentsBuilder::try_build_components`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("building the {component} was cancelled")]
+pub struct BuildCancelled {
+    /// The component that was being built.
+    pub component: NodeComponent,
+}
+
+/// Error returned if a component builder fails.
+///
+/// Each variant wraps the error of the builder that failed. [`ComponentsBuilder`] returns this
+/// error as an [`eyre::Report`] from [`NodeComponentsBuilder::build_components`], it can be
+/// recovered with [`eyre::Report::downcast_ref`].
+#[derive(Debug, thiserror::Error)]
+pub enum ComponentBuildError {
+    /// Building the transaction pool failed.
+    #[error("failed to build the pool: {0}")]
+    Pool(eyre::Report),
+    /// Building the network failed.
+    #[error("failed to build the network: {0}")]
+    Network(eyre::Report),
+    /// Spawning the payload builder service failed.
+    #[error("failed to build the payload builder service: {0}")]
+    Payload(eyre::Report),
+    /// Building the EVM configuration and block executor failed.
+    #[error("failed to build the evm: {0}")]
+    Executor(eyre::Report),
+    /// Building the consensus implementation failed.
+    #[error("failed to build the consensus: {0}")]
+    Consensus(eyre::Report),
+    /// Building the engine validator failed.
+    #[error("failed to build the engine validator: {0}")]
+    EngineValidator(eyre::Report),
+}
+
+impl ComponentBuildError {
+    /// Wraps the error of the builder of the given component.
+    pub const fn new(component: NodeComponent, error: eyre::Report) -> Self {
+        match component {
+            NodeComponent::Evm => Self::Executor(error),
+            NodeComponent::Pool => Self::Pool(error),
+            NodeComponent::Network => Self::Network(error),
+            NodeComponent::PayloadService => Self::Payload(error),
+            NodeComponent::Consensus => Self::Consensus(error),
+        }
+    }
+
+    /// Returns the component whose builder failed, or `None` for the engine validator which is
+    /// built with the add-ons.
+    pub const fn component(&self) -> Option<NodeComponent> {
+        match self {
+            Self::Pool(_) => Some(NodeComponent::Pool),
+            Self::Network(_) => Some(NodeComponent::Network),
+            Self::Payload(_) => Some(NodeComponent::PayloadService),
+            Self::Executor(_) => Some(NodeComponent::Evm),
+            Self::Consensus(_) => Some(NodeComponent::Consensus),
+            Self::EngineValidator(_) => None,
+        }
+    }
+
+    /// Returns the error of the builder that failed.
+    pub const fn inner(&self) -> &eyre::Report {
+        match self {
+            Self::Pool(err) |
+            Self::Network(err) |
+            Self::Payload(err) |
+            Self::Executor(err) |
+            Self::Consensus(err) |
+            Self::EngineValidator(err) => err,
+        }
+    }
+
+    /// Consumes the type and returns the error of the builder that failed.
+    pub fn into_inner(self) -> eyre::Report {
+        match self {
+            Self::Pool(err) |
+            Self::Network(err) |
+            Self::Payload(err) |
+            Self::Executor(err) |
+            Self::Consensus(err) |
+            Self::EngineValidator(err) => err,
+        }
+    }
+}
+
+/// Error returned if component builders of different families are combined, see
+/// [`ComponentsBuilder::check_compatibility`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "the {} builder ({}) is incompatible with the {} builder ({})",
+    first.0, first.1, second.0, second.1
+)]
+pub struct IncompatibleComponents {
+    /// The first component that is specific to a family, and its family.
+    pub first: (NodeComponent, ComponentFamily),
+    /// The first component of a different family.
+    pub second: (NodeComponent, ComponentFamily),
+}
+
+/// Returns the `build_components` span that the `build_component` spans of all steps are nested
+/// under.
+fn build_components_span() -> Span {
+    info_span!(target: "reth::builder", "build_components")
+}
+
+/// Awaits a component build step in a `build_component` span that is a child of the given parent
+/// span.
+///
+/// Fails with an error naming the component if the step doesn't complete within the timeout, and
+/// with [`BuildCancelled`] if `cancelled` resolves first.
+async fn build_step<T>(
+    parent: &Span,
+    component: NodeComponent,
+    timeout: Option<Duration>,
+    cancelled: impl Future<Output = ()>,
+    step: impl Future<Output = eyre::Result<T>>,
+) -> eyre::Result<T> {
+    let span = info_span!(
+        target: "reth::builder",
+        parent: parent,
+        "build_component",
+        component = %component
+    );
+    async move {
+        let start = Instant::now();
+        let step = async {
+            match timeout {
+                Some(timeout) => tokio::time::timeout(timeout, step).await.map_err(|_| {
+                    eyre::eyre!("building the {component} timed out after {timeout:?}")
+                })?,
+                None => step.await,
+            }
+        };
+        let built = tokio::select! {
+            biased;
+            _ = cancelled => Err(BuildCancelled { component }.into()),
+            built = step => built,
+        };
+        if built.is_ok() {
+            debug!(target: "reth::builder", elapsed = ?start.elapsed(), "Built {component}");
+        }
+        built
+    }
+    .instrument(span)
+    .await
+}
+
+/// A build step of a component in a stage of the [`BuildPlan`].
+struct StageStep<'a, C> {
+    parent: &'a Span,
+    component: NodeComponent,
+    timeout: Option<Duration>,
+    cancelled: C,
+    stage: &'a [NodeComponent],
+}
+
+impl<C: Future<Output = ()>> StageStep<'_, C> {
+    /// Runs the [`build_step`] created by `step` if the component is built in this stage.
+    ///
+    /// A failed step is wrapped in the [`ComponentBuildError`] variant of the component.
+    async fn run<T, F>(self, step: impl FnOnce() -> F) -> Option<Result<T, ComponentBuildError>>
+    where
+        F: Future<Output = eyre::Result<T>>,
+    {
+        if !self.stage.contains(&self.component) {
+            return None
+        }
+        let built = build_step(self.parent, self.component, self.timeout, self.cancelled, step());
+        Some(built.await.map_err(|err| ComponentBuildError::new(self.component, err)))
+    }
+}
+
+/// Stores the built component in `slot`, or the error in `error` unless it already holds one.
+fn collect_built<T>(
+    built: Option<Result<T, ComponentBuildError>>,
+    slot: &mut Option<T>,
+    error: &mut Option<ComponentBuildError>,
+) {
+    match built {
+        Some(Ok(built)) => *slot = Some(built),
+        Some(Err(err)) => {
+            error.get_or_insert(err);
+        }
+        None => {}
+    }
+}
+
+/// The components that were built by [`ComponentsBuilder::try_build_components`] before one of
+/// the component builders failed.
+#[derive(Debug)]
+pub struct PartialComponents<Node: FullNodeTypes, Pool, EVM, Executor, Consensus> {
+    /// The transaction pool of the node, if it was built.
+    pub transaction_pool: Option<Pool>,
+    /// The node's EVM configuration, if it was built.
+    pub evm_config: Option<EVM>,
+    /// The node's executor type, if it was built.
+    pub executor: Option<Executor>,
+    /// The consensus implementation of the node, if it was built.
+    pub consensus: Option<Consensus>,
+    /// The network implementation of the node, if it was built.
+    pub network: Option<NetworkHandle>,
+    /// The handle to the payload builder service, if it was spawned.
+    pub payload_builder: Option<PayloadBuilderHandle<<Node::Types as NodeTypesWithEngine>::Engine>>,
+    /// The error of the component builder that failed.
+    ///
+    /// This is a [`ComponentBuildError`] if one of the component builders failed.
+    pub error: eyre::Report,
+}
+
+impl<Node: FullNodeTypes, Pool, EVM, Executor, Consensus>
+    PartialComponents<Node, Pool, EVM, Executor, Consensus>
+{
+    /// Creates a new instance without any built components.
+    const fn new(error: eyre::Report) -> Self {
+        Self {
+            transaction_pool: None,
+            evm_config: None,
+            executor: None,
+            consensus: None,
+            network: None,
+            payload_builder: None,
+            error,
+        }
+    }
+}
+
 impl Default for ComponentsBuilder<(), (), (), (), (), ()> {
     fn default() -> Self {
         Self {
@@ -342,6 +898,9 @@ impl Default for ComponentsBuilder<(), (), (), (), (), ()> {
             network_builder: (),
             executor_builder: (),
             consensus_builder: (),
+            build_timeout: None,
+            build_plan: BuildPlan::default(),
+            task_executor: None,
             _marker: Default::default(),
         }
     }
@@ -360,6 +919,24 @@ pub trait NodeComponentsBuilder<Node: FullNodeTypes>: Send {
     /// The components for the node with the given types
     type Components: NodeComponents<Node>;
 
+    /// Performs cheap checks of the configuration before any component is built, e.g. that
+    /// required directories exist.
+    ///
+    /// By default this does nothing.
+    fn validate(&self, _ctx: &BuilderContext<Node>) -> eyre::Result<()> {
+        Ok(())
+    }
+
+    /// Registers hooks that run once the node has started, see [`ComponentHooks`].
+    ///
+    /// By default this registers no hooks.
+    fn on_started(&mut self, _hooks: &mut ComponentHooks) {}
+
+    /// Registers hooks that run when the node shuts down, see [`ComponentHooks`].
+    ///
+    /// By default this registers no hooks.
+    fn on_shutdown(&mut self, _hooks: &mut ComponentHooks) {}
+
     /// Consumes the type and returns the created components.
     fn build_components(
         self,
@@ -367,6 +944,64 @@ pub trait NodeComponentsBuilder<Node: FullNodeTypes>: Send {
     ) -> impl Future<Output = eyre::Result<Self::Components>> + Send;
 }
 
+/// Extension trait for [`NodeComponentsBuilder`] combinators.
+pub trait NodeComponentsBuilderExt<Node: FullNodeTypes>:
+    NodeComponentsBuilder<Node> + Sized
+{
+    /// Calls `f` with the built components before they are returned, analogous to
+    /// [`Iterator::inspect`].
+    ///
+    /// This is useful for debugging, e.g. to log the types of the built components.
+    fn inspect_components<F>(self, f: F) -> InspectComponents<Self, F>
+    where
+        F: FnOnce(&Self::Components) + Send,
+    {
+        InspectComponents { builder: self, f }
+    }
+}
+
+impl<Node, B> NodeComponentsBuilderExt<Node> for B
+where
+    Node: FullNodeTypes,
+    B: NodeComponentsBuilder<Node>,
+{
+}
+
+/// A [`NodeComponentsBuilder`] that calls a closure with the built components, see
+/// [`NodeComponentsBuilderExt::inspect_components`].
+#[derive(Debug)]
+pub struct InspectComponents<B, F> {
+    builder: B,
+    f: F,
+}
+
+impl<Node, B, F> NodeComponentsBuilder<Node> for InspectComponents<B, F>
+where
+    Node: FullNodeTypes,
+    B: NodeComponentsBuilder<Node>,
+    F: FnOnce(&B::Components) + Send,
+{
+    type Components = B::Components;
+
+    fn validate(&self, ctx: &BuilderContext<Node>) -> eyre::Result<()> {
+        self.builder.validate(ctx)
+    }
+
+    fn on_started(&mut self, hooks: &mut ComponentHooks) {
+        self.builder.on_started(hooks)
+    }
+
+    fn on_shutdown(&mut self, hooks: &mut ComponentHooks) {
+        self.builder.on_shutdown(hooks)
+    }
+
+    async fn build_components(self, ctx: &BuilderContext<Node>) -> eyre::Result<Self::Components> {
+        let components = self.builder.build_components(ctx).await?;
+        (self.f)(&components);
+        Ok(components)
+    }
+}
+
 impl<Node, F, Fut, Pool, EVM, Executor, Cons> NodeComponentsBuilder<Node> for F
 where
     Node: FullNodeTypes,
@@ -386,3 +1021,359 @@ where
         self(ctx)
     }
 }
+
+/// A [`NodeComponentsBuilder`] that configures a builder with access to the [`BuilderContext`],
+/// see [`ComponentsBuilder::from_context`].
+#[derive(Debug)]
+pub struct ComponentsBuilderFromContext<B, F> {
+    builder: B,
+    f: F,
+}
+
+impl<Node, B, F, Out> NodeComponentsBuilder<Node> for ComponentsBuilderFromContext<B, F>
+where
+    Node: FullNodeTypes,
+    B: Send,
+    F: FnOnce(&BuilderContext<Node>, B) -> Out + Send,
+    Out: NodeComponentsBuilder<Node>,
+{
+    type Components = Out::Components;
+
+    async fn build_components(self, ctx: &BuilderContext<Node>) -> eyre::Result<Self::Components> {
+        (self.f)(ctx, self.builder).build_components(ctx).await
+    }
+}
+
+/// A [`NodeComponentsBuilder`] that retries building the components if the build fails with a
+/// transient error.
+///
+/// The builder is cloned for every attempt. After the retries are exhausted, the error of the last
+/// attempt is returned.
+///
+/// Every attempt must release the components it already built before it fails, so that the next
+/// attempt can build them again, e.g. [`ComponentsBuilder`] shuts down the network of a failed
+/// attempt.
+#[derive(Debug, Clone)]
+pub struct RetryingComponentsBuilder<B, P> {
+    builder: B,
+    max_retries: usize,
+    backoff: Duration,
+    is_transient: P,
+}
+
+impl<B, P> RetryingComponentsBuilder<B, P> {
+    /// Creates a new builder that retries building the components of `builder` up to
+    /// `max_retries` times if the error matches `is_transient`.
+    ///
+    /// The delay between two attempts starts at `backoff` and doubles after every failed attempt.
+    pub const fn new(builder: B, max_retries: usize, backoff: Duration, is_transient: P) -> Self {
+        Self { builder, max_retries, backoff, is_transient }
+    }
+}
+
+impl<Node, B, P> NodeComponentsBuilder<Node> for RetryingComponentsBuilder<B, P>
+where
+    Node: FullNodeTypes,
+    B: NodeComponentsBuilder<Node> + Clone,
+    P: Fn(&eyre::Report) -> bool + Send,
+{
+    type Components = B::Components;
+
+    fn validate(&self, ctx: &BuilderContext<Node>) -> eyre::Result<()> {
+        self.builder.validate(ctx)
+    }
+
+    fn on_started(&mut self, hooks: &mut ComponentHooks) {
+        self.builder.on_started(hooks)
+    }
+
+    fn on_shutdown(&mut self, hooks: &mut ComponentHooks) {
+        self.builder.on_shutdown(hooks)
+    }
+
+    fn build_components(
+        self,
+        ctx: &BuilderContext<Node>,
+    ) -> impl Future<Output = eyre::Result<Self::Components>> + Send {
+        let Self { builder, max_retries, backoff, is_transient } = self;
+        retry(max_retries, backoff, is_transient, move || builder.clone().build_components(ctx))
+    }
+}
+
+/// Runs `f` until it succeeds, retrying up to `max_retries` times if the error matches
+/// `is_transient`.
+async fn retry<T, F, Fut>(
+    max_retries: usize,
+    mut backoff: Duration,
+    is_transient: impl Fn(&eyre::Report) -> bool,
+    mut f: F,
+) -> eyre::Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = eyre::Result<T>>,
+{
+    let mut attempt = 0;
+    loop {
+        match f().await {
+            Ok(value) => return Ok(value),
+            Err(err) if attempt < max_retries && is_transient(&err) => {
+                attempt += 1;
+                warn!(
+                    target: "reth::builder",
+                    %err,
+                    attempt,
+                    ?backoff,
+                    "Failed to build components, retrying"
+                );
+                tokio::time::sleep(backoff).await;
+                backoff = backoff.saturating_mul(2);
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        fmt,
+        future::pending,
+        sync::{Arc, Mutex},
+    };
+    use tracing::{
+        field::{Field, Visit},
+        span::{Attributes, Id, Record},
+        Event, Metadata, Subscriber,
+    };
+
+    /// A span recorded by the [`RecordingSubscriber`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct RecordedSpan {
+        name: &'static str,
+        parent: Option<u64>,
+        component: Option<String>,
+    }
+
+    #[derive(Debug, Default)]
+    struct Recorded {
+        /// The recorded spans, span `n` is stored at index `n - 1`.
+        spans: Vec<RecordedSpan>,
+        /// The currently entered spans.
+        entered: Vec<u64>,
+        /// The span of every event that reports an `elapsed` time.
+        elapsed_events: Vec<Option<u64>>,
+    }
+
+    /// A [`Subscriber`] that records all spans with their parent and `component` field.
+    #[derive(Debug, Default, Clone)]
+    struct RecordingSubscriber(Arc<Mutex<Recorded>>);
+
+    /// Visits the `component` field of a span, or the `elapsed` field of an event.
+    struct FieldVisitor<'a> {
+        name: &'static str,
+        value: &'a mut Option<String>,
+    }
+
+    impl Visit for FieldVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            if field.name() == self.name {
+                *self.value = Some(format!("{value:?}"));
+            }
+        }
+    }
+
+    impl Subscriber for RecordingSubscriber {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+            let mut recorded = self.0.lock().unwrap();
+            let parent = if attrs.is_contextual() {
+                recorded.entered.last().copied()
+            } else {
+                attrs.parent().map(Id::into_u64)
+            };
+            let mut component = None;
+            attrs.record(&mut FieldVisitor { name: "component", value: &mut component });
+            recorded.spans.push(RecordedSpan { name: attrs.metadata().name(), parent, component });
+            Id::from_u64(recorded.spans.len() as u64)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut recorded = self.0.lock().unwrap();
+            let span = if event.is_contextual() {
+                recorded.entered.last().copied()
+            } else {
+                event.parent().map(Id::into_u64)
+            };
+            let mut elapsed = None;
+            event.record(&mut FieldVisitor { name: "elapsed", value: &mut elapsed });
+            if elapsed.is_some() {
+                recorded.elapsed_events.push(span);
+            }
+        }
+
+        fn enter(&self, span: &Id) {
+            self.0.lock().unwrap().entered.push(span.into_u64());
+        }
+
+        fn exit(&self, span: &Id) {
+            let mut recorded = self.0.lock().unwrap();
+            if let Some(idx) = recorded.entered.iter().rposition(|id| *id == span.into_u64()) {
+                recorded.entered.remove(idx);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn build_steps_are_traced_in_nested_spans() {
+        let subscriber = RecordingSubscriber::default();
+        let _guard = tracing::subscriber::set_default(subscriber.clone());
+
+        let span = build_components_span();
+        let stage = NodeComponent::ALL;
+        let step = |component| StageStep {
+            parent: &span,
+            component,
+            timeout: None,
+            cancelled: pending(),
+            stage: &stage,
+        };
+        let (evm, pool, network, payload, consensus) = tokio::join!(
+            step(NodeComponent::Evm).run(|| async { Ok(()) }),
+            step(NodeComponent::Pool).run(|| async { Ok(()) }),
+            step(NodeComponent::Network).run(|| async { Ok(()) }),
+            step(NodeComponent::PayloadService).run(|| async { Ok(()) }),
+            step(NodeComponent::Consensus).run(|| async { Ok(()) }),
+        );
+        for built in [evm, pool, network, payload, consensus] {
+            built.unwrap().unwrap();
+        }
+
+        let recorded = subscriber.0.lock().unwrap();
+        let (parent, children) = recorded.spans.split_first().unwrap();
+        assert_eq!(
+            *parent,
+            RecordedSpan { name: "build_components", parent: None, component: None }
+        );
+
+        // every component is built in its own span, nested under the `build_components` span
+        let components: Vec<_> = children
+            .iter()
+            .map(|span| {
+                assert_eq!(span.name, "build_component");
+                assert_eq!(span.parent, Some(1));
+                span.component.clone().unwrap()
+            })
+            .collect();
+        assert_eq!(
+            components,
+            stage.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            "{components:?}"
+        );
+
+        // the elapsed time of every step is reported in the span of the component
+        let mut elapsed_spans: Vec<_> =
+            recorded.elapsed_events.iter().map(|s| s.unwrap()).collect();
+        elapsed_spans.sort_unstable();
+        assert_eq!(elapsed_spans, (2..=children.len() as u64 + 1).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn build_step_timeout_names_component() {
+        let timeout = Some(Duration::from_millis(10));
+
+        let slow_step = async {
+            tokio::time::sleep(Duration::from_secs(10)).await;
+            Ok(())
+        };
+        let err = build_step(&Span::none(), NodeComponent::Network, timeout, pending(), slow_step)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("building the network timed out"), "{err}");
+
+        let step =
+            build_step(&Span::none(), NodeComponent::Pool, timeout, pending(), async { Ok(1) })
+                .await
+                .unwrap();
+        assert_eq!(step, 1);
+
+        // without a timeout the step is awaited until completion
+        let slow_step = async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Ok(())
+        };
+        build_step(&Span::none(), NodeComponent::Consensus, None, pending(), slow_step)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn build_step_cancelled() {
+        let (signal, cancelled) = reth_tasks::shutdown::signal();
+        let slow_step = async {
+            tokio::time::sleep(Duration::from_secs(10)).await;
+            Ok(())
+        };
+        let step =
+            build_step(&Span::none(), NodeComponent::PayloadService, None, cancelled, slow_step);
+
+        let cancel = async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            signal.fire();
+        };
+        let (built, ()) = tokio::join!(step, cancel);
+        let err = built.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<BuildCancelled>(),
+            Some(&BuildCancelled { component: NodeComponent::PayloadService })
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_step_names_component() {
+        let span = Span::none();
+        let stage = NodeComponent::ALL;
+        let run = |component| {
+            let step = StageStep {
+                parent: &span,
+                component,
+                timeout: None,
+                cancelled: pending(),
+                stage: &stage,
+            };
+            step.run(|| async { Err::<(), _>(eyre::eyre!("failed")) })
+        };
+
+        let err = run(NodeComponent::Pool).await.unwrap().unwrap_err();
+        assert!(matches!(err, ComponentBuildError::Pool(_)), "{err:?}");
+        let err = run(NodeComponent::Network).await.unwrap().unwrap_err();
+        assert!(matches!(err, ComponentBuildError::Network(_)), "{err:?}");
+        let err = run(NodeComponent::PayloadService).await.unwrap().unwrap_err();
+        assert!(matches!(err, ComponentBuildError::Payload(_)), "{err:?}");
+        let err = run(NodeComponent::Evm).await.unwrap().unwrap_err();
+        assert!(matches!(err, ComponentBuildError::Executor(_)), "{err:?}");
+        let err = run(NodeComponent::Consensus).await.unwrap().unwrap_err();
+        assert!(matches!(err, ComponentBuildError::Consensus(_)), "{err:?}");
+        assert_eq!(err.component(), Some(NodeComponent::Consensus));
+        assert_eq!(err.inner().to_string(), "failed");
+
+        // the first error of a stage is kept and can be recovered from the report
+        let mut slot = None;
+        let mut error = None;
+        collect_built(run(NodeComponent::Pool).await, &mut slot, &mut error);
+        collect_built(run(NodeComponent::Network).await, &mut slot, &mut error);
+        let report = eyre::Report::from(error.unwrap());
+        assert_eq!(report.to_string(), "failed to build the pool: failed");
+        assert!(matches!(
+            report.downcast_ref::<ComponentBuildError>(),
+            Some(ComponentBuildError::Pool(_))
+        ));
+    }
+}
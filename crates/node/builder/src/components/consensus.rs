@@ -1,5 +1,5 @@
 //! Consensus component for the node builder.
-use crate::{BuilderContext, FullNodeTypes};
+use crate::{components::ComponentFamily, BuilderContext, FullNodeTypes};
 use std::future::Future;
 
 /// A type that knows how to build the consensus implementation.
@@ -12,6 +12,16 @@ pub trait ConsensusBuilder<Node: FullNodeTypes>: Send {
         self,
         ctx: &BuilderContext<Node>,
     ) -> impl Future<Output = eyre::Result<Self::Consensus>> + Send;
+
+    /// Returns the family of the built consensus implementation, if it only works with components
+    /// of the same family.
+    ///
+    /// The [`ComponentsBuilder`](super::ComponentsBuilder) rejects builders of different families
+    /// before building any component. By default the consensus implementation is compatible with
+    /// all families.
+    fn family(&self) -> Option<ComponentFamily> {
+        None
+    }
 }
 
 impl<Node, F, Fut, Consensus> ConsensusBuilder<Node> for F
@@ -1,5 +1,5 @@
 //! EVM component for the node builder.
-use crate::{BuilderContext, FullNodeTypes};
+use crate::{components::ComponentFamily, BuilderContext, FullNodeTypes};
 use reth_evm::execute::BlockExecutorProvider;
 use reth_node_api::ConfigureEvm;
 use reth_primitives::Header;
@@ -20,6 +20,15 @@ pub trait ExecutorBuilder<Node: FullNodeTypes>: Send {
         self,
         ctx: &BuilderContext<Node>,
     ) -> impl Future<Output = eyre::Result<(Self::EVM, Self::Executor)>> + Send;
+
+    /// Returns the family of the built executor, if it only works with components of the same
+    /// family.
+    ///
+    /// The [`ComponentsBuilder`](super::ComponentsBuilder) rejects builders of different families
+    /// before building any component. By default the executor is compatible with all families.
+    fn family(&self) -> Option<ComponentFamily> {
+        None
+    }
 }
 
 impl<Node, F, Fut, EVM, Executor> ExecutorBuilder<Node> for F
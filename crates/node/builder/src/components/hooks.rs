@@ -0,0 +1,133 @@
+//! Lifecycle hooks of the node's components.
+
+use std::{fmt, future::Future};
+
+use futures::{future::BoxFuture, FutureExt};
+use reth_tasks::TaskExecutor;
+use tracing::{debug, error};
+
+/// An async hook registered in [`ComponentHooks`].
+type ComponentHook = Box<dyn FnOnce() -> BoxFuture<'static, eyre::Result<()>> + Send + Sync>;
+
+/// Async hooks that a [`NodeComponentsBuilder`](super::NodeComponentsBuilder) registers to take
+/// part in the lifecycle of the node.
+///
+/// Hooks that run once the node has started are run in the order they were registered. Hooks that
+/// run on shutdown are run in reverse order when the node receives the graceful shutdown signal,
+/// e.g. on `SIGINT`.
+#[derive(Default)]
+pub struct ComponentHooks {
+    on_started: Vec<ComponentHook>,
+    on_shutdown: Vec<ComponentHook>,
+}
+
+impl ComponentHooks {
+    /// Registers a hook that is run once the node has started.
+    pub fn add_on_started<F, Fut>(&mut self, hook: F) -> &mut Self
+    where
+        F: FnOnce() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = eyre::Result<()>> + Send + 'static,
+    {
+        self.on_started.push(Box::new(move || hook().boxed()));
+        self
+    }
+
+    /// Registers a hook that is run when the node shuts down.
+    pub fn add_on_shutdown<F, Fut>(&mut self, hook: F) -> &mut Self
+    where
+        F: FnOnce() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = eyre::Result<()>> + Send + 'static,
+    {
+        self.on_shutdown.push(Box::new(move || hook().boxed()));
+        self
+    }
+
+    /// Returns `true` if no hooks are registered.
+    pub fn is_empty(&self) -> bool {
+        self.on_started.is_empty() && self.on_shutdown.is_empty()
+    }
+
+    /// Spawns the registered hooks on the given executor.
+    ///
+    /// This runs the started hooks right away and the shutdown hooks once the graceful shutdown
+    /// signal is received.
+    pub(crate) fn spawn(self, executor: &TaskExecutor) {
+        let Self { on_started, on_shutdown } = self;
+
+        if !on_started.is_empty() {
+            executor.spawn(async move {
+                debug!(target: "reth::cli", "running component started hooks");
+                run_hooks(on_started, "started").await
+            });
+        }
+
+        if !on_shutdown.is_empty() {
+            executor.spawn_with_graceful_shutdown_signal(|shutdown| async move {
+                let guard = shutdown.await;
+                debug!(target: "reth::cli", "running component shutdown hooks");
+                run_hooks(on_shutdown.into_iter().rev(), "shutdown").await;
+                drop(guard);
+            });
+        }
+    }
+}
+
+impl fmt::Debug for ComponentHooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ComponentHooks")
+            .field("on_started", &self.on_started.len())
+            .field("on_shutdown", &self.on_shutdown.len())
+            .finish()
+    }
+}
+
+/// Runs the hooks one after another, logging failed hooks.
+async fn run_hooks(hooks: impl IntoIterator<Item = ComponentHook>, kind: &'static str) {
+    for hook in hooks {
+        if let Err(err) = hook().await {
+            error!(target: "reth::cli", %err, "Component {kind} hook failed");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[tokio::test]
+    async fn hooks_run_in_order() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let mut hooks = ComponentHooks::default();
+        for id in 0..3 {
+            let started = calls.clone();
+            let shutdown = calls.clone();
+            hooks
+                .add_on_started(move || async move {
+                    started.lock().unwrap().push(("started", id));
+                    Ok(())
+                })
+                .add_on_shutdown(move || async move {
+                    shutdown.lock().unwrap().push(("shutdown", id));
+                    eyre::bail!("failed hooks don't stop the remaining hooks")
+                });
+        }
+        assert!(!hooks.is_empty());
+
+        let ComponentHooks { on_started, on_shutdown } = hooks;
+        run_hooks(on_started, "started").await;
+        run_hooks(on_shutdown.into_iter().rev(), "shutdown").await;
+
+        assert_eq!(
+            *calls.lock().unwrap(),
+            [
+                ("started", 0),
+                ("started", 1),
+                ("started", 2),
+                ("shutdown", 2),
+                ("shutdown", 1),
+                ("shutdown", 0)
+            ]
+        );
+    }
+}
@@ -10,15 +10,19 @@
 mod builder;
 mod consensus;
 mod execute;
+mod hooks;
 mod network;
 mod payload;
+mod plan;
 mod pool;
 
 pub use builder::*;
 pub use consensus::*;
 pub use execute::*;
+pub use hooks::*;
 pub use network::*;
 pub use payload::*;
+pub use plan::*;
 pub use pool::*;
 
 use reth_consensus::Consensus;
@@ -5,7 +5,7 @@ use std::future::Future;
 use reth_network::NetworkHandle;
 use reth_transaction_pool::TransactionPool;
 
-use crate::{BuilderContext, FullNodeTypes};
+use crate::{components::ComponentFamily, BuilderContext, FullNodeTypes};
 
 /// A type that knows how to build the network implementation.
 pub trait NetworkBuilder<Node: FullNodeTypes, Pool: TransactionPool>: Send {
@@ -15,6 +15,15 @@ pub trait NetworkBuilder<Node: FullNodeTypes, Pool: TransactionPool>: Send {
         ctx: &BuilderContext<Node>,
         pool: Pool,
     ) -> impl Future<Output = eyre::Result<NetworkHandle>> + Send;
+
+    /// Returns the family of the built network, if it only works with components of the same
+    /// family.
+    ///
+    /// The [`ComponentsBuilder`](super::ComponentsBuilder) rejects builders of different families
+    /// before building any component. By default the network is compatible with all families.
+    fn family(&self) -> Option<ComponentFamily> {
+        None
+    }
 }
 
 impl<Node, F, Fut, Pool> NetworkBuilder<Node, Pool> for F
@@ -1,12 +1,14 @@
 //! Payload service component for the node builder.
 
-use std::future::Future;
+use std::{future::Future, sync::Arc};
 
 use reth_node_api::NodeTypesWithEngine;
 use reth_payload_builder::PayloadBuilderHandle;
+use reth_payload_primitives::InclusionListProvider;
 use reth_transaction_pool::TransactionPool;
+use tracing::warn;
 
-use crate::{BuilderContext, FullNodeTypes};
+use crate::{components::ComponentFamily, BuilderContext, FullNodeTypes};
 
 /// A type that knows how to spawn the payload service.
 pub trait PayloadServiceBuilder<Node: FullNodeTypes, Pool: TransactionPool>: Send {
@@ -20,6 +22,31 @@ pub trait PayloadServiceBuilder<Node: FullNodeTypes, Pool: TransactionPool>: Sen
     ) -> impl Future<
         Output = eyre::Result<PayloadBuilderHandle<<Node::Types as NodeTypesWithEngine>::Engine>>,
     > + Send;
+
+    /// Configures the source of the [EIP-7547](https://eips.ethereum.org/EIPS/eip-7547) inclusion
+    /// lists that built payloads must satisfy.
+    ///
+    /// By default inclusion lists are not supported and the provider is ignored.
+    fn with_inclusion_lists(self, _provider: Arc<dyn InclusionListProvider>) -> Self
+    where
+        Self: Sized,
+    {
+        warn!(
+            target: "reth::builder",
+            "Payload builder doesn't support inclusion lists, ignoring them"
+        );
+        self
+    }
+
+    /// Returns the family of the built payload builder service, if it only works with components of
+    /// the same family.
+    ///
+    /// The [`ComponentsBuilder`](super::ComponentsBuilder) rejects builders of different families
+    /// before building any component. By default the payload builder service is compatible with all
+    /// families.
+    fn family(&self) -> Option<ComponentFamily> {
+        None
+    }
 }
 
 impl<Node, F, Fut, Pool> PayloadServiceBuilder<Node, Pool> for F
@@ -0,0 +1,248 @@
+//! Build order of the node's components.
+
+use std::fmt;
+
+/// A component of the node that is built by the [`ComponentsBuilder`](super::ComponentsBuilder).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NodeComponent {
+    /// The EVM configuration and block executor.
+    Evm,
+    /// The transaction pool.
+    Pool,
+    /// The network.
+    Network,
+    /// The payload builder service.
+    PayloadService,
+    /// The consensus implementation.
+    Consensus,
+}
+
+impl NodeComponent {
+    /// All components in their default build order.
+    pub const ALL: [Self; 5] =
+        [Self::Evm, Self::Pool, Self::Network, Self::PayloadService, Self::Consensus];
+
+    /// Returns the name of the component.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Evm => "evm",
+            Self::Pool => "pool",
+            Self::Network => "network",
+            Self::PayloadService => "payload builder service",
+            Self::Consensus => "consensus",
+        }
+    }
+
+    /// Returns the components that must always be built before this component.
+    ///
+    /// The network and the payload builder service are built with the transaction pool.
+    pub const fn required_dependencies(&self) -> &'static [Self] {
+        match self {
+            Self::Network | Self::PayloadService => &[Self::Pool],
+            Self::Evm | Self::Pool | Self::Consensus => &[],
+        }
+    }
+}
+
+impl fmt::Display for NodeComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The family of a component builder, e.g. the chain the built component is specific to.
+///
+/// Builders of different families can't be combined, e.g. an Optimism executor with Ethereum
+/// consensus, see
+/// [`ComponentsBuilder::check_compatibility`](super::ComponentsBuilder::check_compatibility).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ComponentFamily(&'static str);
+
+impl ComponentFamily {
+    /// Components of Ethereum nodes.
+    pub const ETHEREUM: Self = Self::new("ethereum");
+
+    /// Components of Optimism nodes.
+    pub const OPTIMISM: Self = Self::new("optimism");
+
+    /// Creates a new family with the given name.
+    pub const fn new(name: &'static str) -> Self {
+        Self(name)
+    }
+
+    /// Returns the name of the family.
+    pub const fn name(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl fmt::Display for ComponentFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+/// Declares the order in which the [`ComponentsBuilder`](super::ComponentsBuilder) builds the
+/// components of the node.
+///
+/// Components are built in their default order ([`NodeComponent::ALL`]) unless a declared
+/// dependency requires another order. The network and the payload builder service are always
+/// built after the transaction pool, see [`NodeComponent::required_dependencies`].
+///
+/// Components that don't depend on each other are built concurrently, see [`BuildPlan::stages`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BuildPlan {
+    /// Declared `(component, dependency)` pairs.
+    dependencies: Vec<(NodeComponent, NodeComponent)>,
+}
+
+impl BuildPlan {
+    /// Declares that `component` must be built after `dependency`.
+    pub fn depends_on(mut self, component: NodeComponent, dependency: NodeComponent) -> Self {
+        self.dependencies.push((component, dependency));
+        self
+    }
+
+    /// Returns the dependencies of the component, including the required ones.
+    fn dependencies_of(
+        &self,
+        component: NodeComponent,
+    ) -> impl Iterator<Item = NodeComponent> + '_ {
+        component.required_dependencies().iter().copied().chain(
+            self.dependencies
+                .iter()
+                .filter(move |(c, _)| *c == component)
+                .map(|(_, dependency)| *dependency),
+        )
+    }
+
+    /// Returns the order in which the components are built.
+    ///
+    /// Returns an error if the declared dependencies are cyclic.
+    pub fn order(&self) -> Result<Vec<NodeComponent>, BuildPlanError> {
+        let mut order = Vec::with_capacity(NodeComponent::ALL.len());
+        let mut remaining = NodeComponent::ALL.to_vec();
+        while !remaining.is_empty() {
+            // build the first remaining component whose dependencies are already built
+            let Some(idx) = remaining.iter().position(|component| {
+                self.dependencies_of(*component).all(|dependency| order.contains(&dependency))
+            }) else {
+                return Err(BuildPlanError::Cycle(remaining))
+            };
+            order.push(remaining.remove(idx));
+        }
+
+        Ok(order)
+    }
+
+    /// Returns the stages in which the components are built.
+    ///
+    /// Each stage contains the components whose dependencies are built in earlier stages, so the
+    /// components of a stage are built concurrently.
+    ///
+    /// Returns an error if the declared dependencies are cyclic.
+    pub fn stages(&self) -> Result<Vec<Vec<NodeComponent>>, BuildPlanError> {
+        let mut stages = Vec::new();
+        let mut built = Vec::with_capacity(NodeComponent::ALL.len());
+        let mut remaining = NodeComponent::ALL.to_vec();
+        while !remaining.is_empty() {
+            let (stage, rest): (Vec<_>, Vec<_>) = remaining.into_iter().partition(|component| {
+                self.dependencies_of(*component).all(|dependency| built.contains(&dependency))
+            });
+            if stage.is_empty() {
+                return Err(BuildPlanError::Cycle(rest))
+            }
+            built.extend_from_slice(&stage);
+            stages.push(stage);
+            remaining = rest;
+        }
+
+        Ok(stages)
+    }
+}
+
+/// Error returned by [`BuildPlan::order`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum BuildPlanError {
+    /// The dependencies of these components are cyclic.
+    #[error("cyclic build dependencies, unable to build components: {}", display_components(.0))]
+    Cycle(Vec<NodeComponent>),
+}
+
+fn display_components(components: &[NodeComponent]) -> String {
+    components.iter().map(NodeComponent::name).collect::<Vec<_>>().join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_order() {
+        assert_eq!(BuildPlan::default().order().unwrap(), NodeComponent::ALL);
+    }
+
+    #[test]
+    fn custom_order() {
+        let plan = BuildPlan::default()
+            .depends_on(NodeComponent::Consensus, NodeComponent::Evm)
+            .depends_on(NodeComponent::Pool, NodeComponent::Consensus);
+        assert_eq!(
+            plan.order().unwrap(),
+            [
+                NodeComponent::Evm,
+                NodeComponent::Consensus,
+                NodeComponent::Pool,
+                NodeComponent::Network,
+                NodeComponent::PayloadService,
+            ]
+        );
+    }
+
+    #[test]
+    fn stages() {
+        assert_eq!(
+            BuildPlan::default().stages().unwrap(),
+            [
+                vec![NodeComponent::Evm, NodeComponent::Pool, NodeComponent::Consensus],
+                vec![NodeComponent::Network, NodeComponent::PayloadService],
+            ]
+        );
+
+        let plan = BuildPlan::default()
+            .depends_on(NodeComponent::Pool, NodeComponent::Evm)
+            .depends_on(NodeComponent::Consensus, NodeComponent::Network);
+        assert_eq!(
+            plan.stages().unwrap(),
+            [
+                vec![NodeComponent::Evm],
+                vec![NodeComponent::Pool],
+                vec![NodeComponent::Network, NodeComponent::PayloadService],
+                vec![NodeComponent::Consensus],
+            ]
+        );
+    }
+
+    #[test]
+    fn cyclic_dependencies() {
+        // the network always depends on the pool
+        let plan = BuildPlan::default().depends_on(NodeComponent::Pool, NodeComponent::Network);
+        let err = plan.order().unwrap_err();
+        assert_eq!(
+            err,
+            BuildPlanError::Cycle(vec![
+                NodeComponent::Pool,
+                NodeComponent::Network,
+                NodeComponent::PayloadService
+            ])
+        );
+        assert_eq!(
+            err.to_string(),
+            "cyclic build dependencies, unable to build components: pool, network, payload builder service"
+        );
+
+        let plan = BuildPlan::default().depends_on(NodeComponent::Evm, NodeComponent::Evm);
+        assert!(plan.order().is_err());
+        assert_eq!(plan.stages().unwrap_err(), BuildPlanError::Cycle(vec![NodeComponent::Evm]));
+    }
+}
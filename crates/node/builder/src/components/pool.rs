@@ -4,7 +4,7 @@ use alloy_primitives::Address;
 use reth_transaction_pool::{PoolConfig, SubPoolLimit, TransactionPool};
 use std::{collections::HashSet, future::Future};
 
-use crate::{BuilderContext, FullNodeTypes};
+use crate::{components::ComponentFamily, BuilderContext, FullNodeTypes};
 
 /// A type that knows how to build the transaction pool.
 pub trait PoolBuilder<Node: FullNodeTypes>: Send {
@@ -16,6 +16,15 @@ pub trait PoolBuilder<Node: FullNodeTypes>: Send {
         self,
         ctx: &BuilderContext<Node>,
     ) -> impl Future<Output = eyre::Result<Self::Pool>> + Send;
+
+    /// Returns the family of the built pool, if it only works with components of the same
+    /// family.
+    ///
+    /// The [`ComponentsBuilder`](super::ComponentsBuilder) rejects builders of different families
+    /// before building any component. By default the pool is compatible with all families.
+    fn family(&self) -> Option<ComponentFamily> {
+        None
+    }
 }
 
 impl<Node, F, Fut, Pool> PoolBuilder<Node> for F
@@ -58,7 +58,7 @@ use tokio::sync::{
 };
 
 use crate::{
-    components::{NodeComponents, NodeComponentsBuilder},
+    components::{ComponentHooks, NodeComponents, NodeComponentsBuilder},
     hooks::OnComponentInitializedHook,
     BuilderContext, NodeAdapter,
 };
@@ -112,9 +112,10 @@ impl LaunchContext {
     /// Attaches both the `NodeConfig` and the loaded `reth.toml` config to the launch context.
     pub fn with_loaded_toml_config<ChainSpec: EthChainSpec>(
         self,
-        config: NodeConfig<ChainSpec>,
+        mut config: NodeConfig<ChainSpec>,
     ) -> eyre::Result<LaunchContextWith<WithConfigs<ChainSpec>>> {
         let toml_config = self.load_toml_config(&config)?;
+        config.rpc.rate_limits = toml_config.rpc.rate_limits.clone();
         Ok(self.with(WithConfigs { config, toml_config }))
     }
 
@@ -665,7 +666,7 @@ where
     /// Creates a `NodeAdapter` and attaches it to the launch context.
     pub async fn with_components<CB>(
         self,
-        components_builder: CB,
+        mut components_builder: CB,
         on_component_initialized: Box<
             dyn OnComponentInitializedHook<NodeAdapter<T, CB::Components>>,
         >,
@@ -687,6 +688,10 @@ where
             self.configs().clone(),
         );
 
+        let mut component_hooks = ComponentHooks::default();
+        components_builder.on_started(&mut component_hooks);
+        components_builder.on_shutdown(&mut component_hooks);
+
         debug!(target: "reth::cli", "creating components");
         let components = components_builder.build_components(&builder_ctx).await?;
 
@@ -730,6 +735,7 @@ where
             node_adapter,
             head,
             consensus,
+            component_hooks,
         };
 
         let ctx = LaunchContextWith {
@@ -786,6 +792,14 @@ where
         &self.right().node_adapter
     }
 
+    /// Spawns the hooks registered by the components builder, see [`ComponentHooks`].
+    ///
+    /// This should be called once the node has started.
+    pub fn spawn_component_hooks(&mut self) {
+        let hooks = std::mem::take(&mut self.right_mut().component_hooks);
+        hooks.spawn(self.task_executor());
+    }
+
     /// Returns a reference to the blockchain provider.
     pub const fn blockchain_db(&self) -> &T::Provider {
         &self.right().blockchain_db
@@ -1048,6 +1062,7 @@ where
     node_adapter: NodeAdapter<T, CB::Components>,
     head: Head,
     consensus: Arc<dyn Consensus>,
+    component_hooks: ComponentHooks,
 }
 
 #[cfg(test)]
@@ -103,7 +103,7 @@ where
             tokio::sync::broadcast::channel(tree_config.max_reorg_depth() as usize * 2);
 
         // setup the launch context
-        let ctx = ctx
+        let mut ctx = ctx
             .with_configured_globals()
             // load the toml config
             .with_loaded_toml_config(config)?
@@ -341,69 +341,90 @@ where
         let terminate_after_backfill = ctx.terminate_after_initial_backfill();
 
         info!(target: "reth::cli", "Starting consensus engine");
-        ctx.task_executor().spawn_critical("consensus engine", async move {
-            if let Some(initial_target) = initial_target {
-                debug!(target: "reth::cli", %initial_target,  "start backfill sync");
-                if let Either::Right(eth_service) = &mut engine_service {
-                    eth_service.orchestrator_mut().start_backfill_sync(initial_target);
+        ctx.task_executor().spawn_critical_with_graceful_shutdown_signal(
+            "consensus engine",
+            |mut shutdown| async move {
+                if let Some(initial_target) = initial_target {
+                    debug!(target: "reth::cli", %initial_target,  "start backfill sync");
+                    if let Either::Right(eth_service) = &mut engine_service {
+                        eth_service.orchestrator_mut().start_backfill_sync(initial_target);
+                    }
                 }
-            }
 
-            let mut res = Ok(());
+                let mut res = Ok(());
+                let mut shutdown_guard = None;
 
-            // advance the chain and await payloads built locally to add into the engine api tree handler to prevent re-execution if that block is received as payload from the CL
-            loop {
-                tokio::select! {
-                    payload = built_payloads.select_next_some() => {
-                        if let Some(executed_block) = payload.executed_block() {
-                            debug!(target: "reth::cli", block=?executed_block.block().num_hash(),  "inserting built payload");
+                // advance the chain and await payloads built locally to add into the engine api tree handler to prevent re-execution if that block is received as payload from the CL
+                loop {
+                    tokio::select! {
+                        guard = &mut shutdown, if shutdown_guard.is_none() => {
+                            shutdown_guard = Some(guard);
                             if let Either::Right(eth_service) = &mut engine_service {
-                                eth_service.orchestrator_mut().handler_mut().handler_mut().on_event(EngineApiRequest::InsertExecutedBlock(executed_block).into());
+                                // wait for the engine to finish the accepted requests
+                                debug!(target: "reth::cli", "Shutting down consensus engine");
+                                eth_service.orchestrator_mut().shutdown();
+                            } else {
+                                break
                             }
                         }
-                    }
-                    event = engine_service.next() => {
-                        let Some(event) = event else { break };
-                        debug!(target: "reth::cli", "Event: {event}");
-                        match event {
-                            ChainEvent::BackfillSyncFinished => {
-                                if terminate_after_backfill {
-                                    debug!(target: "reth::cli", "Terminating after initial backfill");
-                                    break
+                        payload = built_payloads.select_next_some() => {
+                            if let Some(executed_block) = payload.executed_block() {
+                                debug!(target: "reth::cli", block=?executed_block.block().num_hash(),  "inserting built payload");
+                                if let Either::Right(eth_service) = &mut engine_service {
+                                    eth_service.orchestrator_mut().handler_mut().handler_mut().on_event(EngineApiRequest::InsertExecutedBlock(executed_block).into());
                                 }
-
-                                network_handle.update_sync_state(SyncState::Idle);
                             }
-                            ChainEvent::BackfillSyncStarted => {
-                                network_handle.update_sync_state(SyncState::Syncing);
-                            }
-                            ChainEvent::FatalError => {
-                                error!(target: "reth::cli", "Fatal error in consensus engine");
-                                res = Err(eyre::eyre!("Fatal error in consensus engine"));
-                                break
-                            }
-                            ChainEvent::Handler(ev) => {
-                                if let Some(head) = ev.canonical_header() {
-                                    let head_block = Head {
-                                        number: head.number,
-                                        hash: head.hash(),
-                                        difficulty: head.difficulty,
-                                        timestamp: head.timestamp,
-                                        total_difficulty: chainspec
-                                            .final_paris_total_difficulty(head.number)
-                                            .unwrap_or_default(),
-                                    };
-                                    network_handle.update_status(head_block);
+                        }
+                        event = engine_service.next() => {
+                            let Some(event) = event else { break };
+                            debug!(target: "reth::cli", "Event: {event}");
+                            match event {
+                                ChainEvent::BackfillSyncFinished => {
+                                    if terminate_after_backfill {
+                                        debug!(target: "reth::cli", "Terminating after initial backfill");
+                                        break
+                                    }
+
+                                    network_handle.update_sync_state(SyncState::Idle);
+                                }
+                                ChainEvent::BackfillSyncStarted => {
+                                    network_handle.update_sync_state(SyncState::Syncing);
+                                }
+                                ChainEvent::FatalError => {
+                                    error!(target: "reth::cli", "Fatal error in consensus engine");
+                                    res = Err(eyre::eyre!("Fatal error in consensus engine"));
+                                    break
+                                }
+                                ChainEvent::Shutdown => {
+                                    debug!(target: "reth::cli", "Consensus engine shut down");
+                                    break
+                                }
+                                ChainEvent::Handler(ev) => {
+                                    if let Some(head) = ev.canonical_header() {
+                                        let head_block = Head {
+                                            number: head.number,
+                                            hash: head.hash(),
+                                            difficulty: head.difficulty,
+                                            timestamp: head.timestamp,
+                                            total_difficulty: chainspec
+                                                .final_paris_total_difficulty(head.number)
+                                                .unwrap_or_default(),
+                                        };
+                                        network_handle.update_status(head_block);
+                                    }
+                                    if let Some(ev) = ev.into_beacon_consensus_event() {
+                                        event_sender.notify(ev);
+                                    }
                                 }
-                                event_sender.notify(ev);
                             }
                         }
                     }
                 }
-            }
 
-            let _ = exit.send(res);
-        });
+                let _ = exit.send(res);
+                drop(shutdown_guard);
+            },
+        );
 
         let full_node = FullNode {
             evm_config: ctx.components().evm_config().clone(),
@@ -419,6 +440,7 @@ where
         };
         // Notify on node started
         on_node_started.on_event(FullNode::clone(&full_node))?;
+        ctx.spawn_component_hooks();
 
         let handle = NodeHandle {
             node_exit_future: NodeExitFuture::new(
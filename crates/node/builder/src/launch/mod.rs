@@ -134,7 +134,7 @@ where
         ));
 
         // setup the launch context
-        let ctx = ctx
+        let mut ctx = ctx
             .with_configured_globals()
             // load the toml config
             .with_loaded_toml_config(config)?
@@ -402,6 +402,7 @@ where
         };
         // Notify on node started
         on_node_started.on_event(FullNode::clone(&full_node))?;
+        ctx.spawn_component_hooks();
 
         let handle = NodeHandle {
             node_exit_future: NodeExitFuture::new(
@@ -32,7 +32,7 @@ use reth_rpc_engine_api::{capabilities::EngineCapabilities, EngineApi};
 use reth_tasks::TaskExecutor;
 use reth_tracing::tracing::{debug, info};
 
-use crate::EthApiBuilderCtx;
+use crate::{components::ComponentBuildError, EthApiBuilderCtx};
 
 /// Contains the handles to the spawned RPC servers.
 ///
@@ -368,6 +368,16 @@ impl<Node: FullNodeComponents, EthApi: EthApiTypes, EV> RpcAddOns<Node, EthApi,
         }
     }
 
+    /// Apply a function to the engine validator builder.
+    pub fn map_engine_validator(self, f: impl FnOnce(EV) -> EV) -> Self {
+        Self {
+            hooks: self.hooks,
+            eth_api_builder: self.eth_api_builder,
+            engine_validator_builder: f(self.engine_validator_builder),
+            _pd: self._pd,
+        }
+    }
+
     /// Sets the hook that is run once the rpc server is started.
     pub fn on_rpc_started<F>(mut self, hook: F) -> Self
     where
@@ -411,7 +421,10 @@ where
     async fn launch_add_ons(self, ctx: AddOnsContext<'_, N>) -> eyre::Result<Self::Handle> {
         let Self { eth_api_builder, engine_validator_builder, hooks, _pd: _ } = self;
 
-        let engine_validator = engine_validator_builder.build(&ctx).await?;
+        let engine_validator = engine_validator_builder
+            .build(&ctx)
+            .await
+            .map_err(ComponentBuildError::EngineValidator)?;
         let AddOnsContext { node, config, beacon_engine_handle, jwt_secret } = ctx;
 
         let client = ClientVersionV1 {
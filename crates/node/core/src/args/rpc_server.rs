@@ -14,6 +14,7 @@ use clap::{
     Arg, Args, Command,
 };
 use rand::Rng;
+use reth_config::config::RpcRateLimitConfig;
 use reth_rpc_server_types::{constants, RethRpcModule, RpcModuleSelection};
 
 use crate::args::{
@@ -197,6 +198,12 @@ pub struct RpcServerArgs {
     /// Gas price oracle configuration.
     #[command(flatten)]
     pub gas_price_oracle: GasPriceOracleArgs,
+
+    /// Rate limits of the http and ws servers.
+    ///
+    /// These are configured in the `[rpc.rate_limits]` section of the config file.
+    #[arg(skip)]
+    pub rate_limits: RpcRateLimitConfig,
 }
 
 impl RpcServerArgs {
@@ -332,6 +339,7 @@ impl Default for RpcServerArgs {
             rpc_state_cache: RpcStateCacheArgs::default(),
             rpc_proof_permits: constants::DEFAULT_PROOF_PERMITS,
             builder_disallow: Default::default(),
+            rate_limits: Default::default(),
         }
     }
 }
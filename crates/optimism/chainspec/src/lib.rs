@@ -33,7 +33,9 @@ use reth_chainspec::{
     BaseFeeParams, BaseFeeParamsKind, ChainSpec, ChainSpecBuilder, DepositContract, EthChainSpec,
     EthereumHardforks, ForkFilter, ForkId, Hardforks, Head,
 };
-use reth_ethereum_forks::{ChainHardforks, EthereumHardfork, ForkCondition, Hardfork};
+use reth_ethereum_forks::{
+    ChainHardforks, ConfigureHardforks, EthereumHardfork, ForkCondition, Hardfork,
+};
 use reth_network_peers::NodeRecord;
 use reth_optimism_forks::OptimismHardforks;
 use reth_primitives_traits::Header;
@@ -346,8 +348,6 @@ impl From<Genesis> for OpChainSpec {
     fn from(genesis: Genesis) -> Self {
         use reth_optimism_forks::OptimismHardfork;
         let optimism_genesis_info = OpGenesisInfo::extract_from(&genesis);
-        let genesis_info =
-            optimism_genesis_info.optimism_chain_info.genesis_info.unwrap_or_default();
 
         // Block-based hardforks
         let hardfork_opts = [
@@ -363,11 +363,14 @@ impl From<Genesis> for OpChainSpec {
             (EthereumHardfork::London.boxed(), genesis.config.london_block),
             (EthereumHardfork::ArrowGlacier.boxed(), genesis.config.arrow_glacier_block),
             (EthereumHardfork::GrayGlacier.boxed(), genesis.config.gray_glacier_block),
-            (OptimismHardfork::Bedrock.boxed(), genesis_info.bedrock_block),
         ];
         let mut block_hardforks = hardfork_opts
             .into_iter()
             .filter_map(|(hardfork, opt)| opt.map(|block| (hardfork, ForkCondition::Block(block))))
+            .chain(
+                OptimismHardfork::init_block_hardforks(&genesis.config)
+                    .map(|(hardfork, condition)| (hardfork.boxed(), condition)),
+            )
             .collect::<Vec<_>>();
 
         // Paris
@@ -391,11 +394,6 @@ impl From<Genesis> for OpChainSpec {
             (EthereumHardfork::Shanghai.boxed(), genesis.config.shanghai_time),
             (EthereumHardfork::Cancun.boxed(), genesis.config.cancun_time),
             (EthereumHardfork::Prague.boxed(), genesis.config.prague_time),
-            (OptimismHardfork::Regolith.boxed(), genesis_info.regolith_time),
-            (OptimismHardfork::Canyon.boxed(), genesis_info.canyon_time),
-            (OptimismHardfork::Ecotone.boxed(), genesis_info.ecotone_time),
-            (OptimismHardfork::Fjord.boxed(), genesis_info.fjord_time),
-            (OptimismHardfork::Granite.boxed(), genesis_info.granite_time),
         ];
 
         let mut time_hardforks = time_hardfork_opts
@@ -403,6 +401,10 @@ impl From<Genesis> for OpChainSpec {
             .filter_map(|(hardfork, opt)| {
                 opt.map(|time| (hardfork, ForkCondition::Timestamp(time)))
             })
+            .chain(
+                OptimismHardfork::init_time_hardforks(&genesis.config)
+                    .map(|(hardfork, condition)| (hardfork.boxed(), condition)),
+            )
             .collect::<Vec<_>>();
 
         block_hardforks.append(&mut time_hardforks);
@@ -576,7 +578,15 @@ mod tests {
                 ),
                 (
                     Head { number: 0, timestamp: 1723478400, ..Default::default() },
-                    ForkId { hash: ForkHash([0x75, 0xde, 0xa4, 0x1e]), next: 0 },
+                    ForkId { hash: ForkHash([0x75, 0xde, 0xa4, 0x1e]), next: 1732633200 },
+                ),
+                (
+                    Head { number: 0, timestamp: 1732633199, ..Default::default() },
+                    ForkId { hash: ForkHash([0x75, 0xde, 0xa4, 0x1e]), next: 1732633200 },
+                ),
+                (
+                    Head { number: 0, timestamp: 1732633200, ..Default::default() },
+                    ForkId { hash: ForkHash([0x4a, 0x1c, 0x79, 0x2e]), next: 0 },
                 ),
             ],
         );
@@ -725,6 +735,7 @@ mod tests {
         "ecotoneTime": 40,
         "fjordTime": 50,
         "graniteTime": 51,
+        "holoceneTime": 52,
         "optimism": {
           "eip1559Elasticity": 60,
           "eip1559Denominator": 70
@@ -746,6 +757,8 @@ mod tests {
         assert_eq!(actual_fjord_timestamp, Some(serde_json::Value::from(50)).as_ref());
         let actual_granite_timestamp = genesis.config.extra_fields.get("graniteTime");
         assert_eq!(actual_granite_timestamp, Some(serde_json::Value::from(51)).as_ref());
+        let actual_holocene_timestamp = genesis.config.extra_fields.get("holoceneTime");
+        assert_eq!(actual_holocene_timestamp, Some(serde_json::Value::from(52)).as_ref());
 
         let optimism_object = genesis.config.extra_fields.get("optimism").unwrap();
         assert_eq!(
@@ -769,6 +782,7 @@ mod tests {
         assert!(!chain_spec.is_fork_active_at_timestamp(OptimismHardfork::Ecotone, 0));
         assert!(!chain_spec.is_fork_active_at_timestamp(OptimismHardfork::Fjord, 0));
         assert!(!chain_spec.is_fork_active_at_timestamp(OptimismHardfork::Granite, 0));
+        assert!(!chain_spec.is_fork_active_at_timestamp(OptimismHardfork::Holocene, 0));
 
         assert!(chain_spec.is_fork_active_at_block(OptimismHardfork::Bedrock, 10));
         assert!(chain_spec.is_fork_active_at_timestamp(OptimismHardfork::Regolith, 20));
@@ -776,6 +790,7 @@ mod tests {
         assert!(chain_spec.is_fork_active_at_timestamp(OptimismHardfork::Ecotone, 40));
         assert!(chain_spec.is_fork_active_at_timestamp(OptimismHardfork::Fjord, 50));
         assert!(chain_spec.is_fork_active_at_timestamp(OptimismHardfork::Granite, 51));
+        assert!(chain_spec.is_fork_active_at_timestamp(OptimismHardfork::Holocene, 52));
     }
 
     #[test]
@@ -959,6 +974,7 @@ mod tests {
                     (String::from("ecotoneTime"), 0.into()),
                     (String::from("fjordTime"), 0.into()),
                     (String::from("graniteTime"), 0.into()),
+                    (String::from("holoceneTime"), 0.into()),
                 ]
                 .into_iter()
                 .collect(),
@@ -992,6 +1008,7 @@ mod tests {
             OptimismHardfork::Ecotone.boxed(),
             OptimismHardfork::Fjord.boxed(),
             OptimismHardfork::Granite.boxed(),
+            OptimismHardfork::Holocene.boxed(),
         ];
 
         assert!(expected_hardforks
@@ -1,6 +1,6 @@
 //! Hard forks of optimism protocol.
 
-use alloc::{boxed::Box, format, string::String, vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 use core::{
     any::Any,
     fmt::{self, Display, Formatter},
@@ -8,10 +8,11 @@ use core::{
 };
 
 use alloy_chains::Chain;
+use alloy_genesis::ChainConfig;
 use alloy_primitives::U256;
-use reth_ethereum_forks::{hardfork, ChainHardforks, EthereumHardfork, ForkCondition, Hardfork};
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use reth_ethereum_forks::{
+    hardfork, ChainHardforks, ConfigureHardforks, EthereumHardfork, ForkCondition, Hardfork,
+};
 
 hardfork!(
     /// The name of an optimism hardfork.
@@ -34,8 +35,23 @@ hardfork!(
         /// Holocene: <https://github.com/ethereum-optimism/specs/blob/main/specs/protocol/superchain-upgrades.md#holocene>
         Holocene,
     }
+    aliases = &[],
+    configured
 );
 
+/// Downcasts a [`dyn Hardfork`](Hardfork) to an [`OptimismHardfork`], like the `as_ethereum`
+/// method of `dyn Hardfork` does for [`EthereumHardfork`].
+pub trait AsOptimismHardfork {
+    /// Returns the [`OptimismHardfork`] if this is an optimism hardfork.
+    fn as_optimism(&self) -> Option<OptimismHardfork>;
+}
+
+impl AsOptimismHardfork for dyn Hardfork + 'static {
+    fn as_optimism(&self) -> Option<OptimismHardfork> {
+        OptimismHardfork::from_dyn(self)
+    }
+}
+
 impl OptimismHardfork {
     /// Retrieves the activation block for the specified hardfork on the given chain.
     pub fn activation_block<H: Hardfork>(self, fork: H, chain: Chain) -> Option<u64> {
@@ -61,6 +77,18 @@ impl OptimismHardfork {
         None
     }
 
+    /// Returns the [`EthereumHardfork`] whose EVM changes are incorporated by this hardfork, if
+    /// any.
+    ///
+    /// Returns `None` for hardforks that only introduce OP-stack specific changes.
+    pub const fn ethereum_equivalent(&self) -> Option<EthereumHardfork> {
+        match self {
+            Self::Canyon => Some(EthereumHardfork::Shanghai),
+            Self::Ecotone => Some(EthereumHardfork::Cancun),
+            Self::Bedrock | Self::Regolith | Self::Fjord | Self::Granite | Self::Holocene => None,
+        }
+    }
+
     /// Retrieves the activation block for the specified hardfork on the Base Sepolia testnet.
     pub fn base_sepolia_activation_block<H: Hardfork>(fork: H) -> Option<u64> {
         match_hardfork(
@@ -226,6 +254,7 @@ impl OptimismHardfork {
             (Self::Ecotone.boxed(), ForkCondition::Timestamp(1710374401)),
             (Self::Fjord.boxed(), ForkCondition::Timestamp(1720627201)),
             (Self::Granite.boxed(), ForkCondition::Timestamp(1726070401)),
+            (Self::Holocene.boxed(), ForkCondition::Timestamp(1736445601)),
         ])
     }
 
@@ -257,6 +286,7 @@ impl OptimismHardfork {
             (Self::Ecotone.boxed(), ForkCondition::Timestamp(1708534800)),
             (Self::Fjord.boxed(), ForkCondition::Timestamp(1716998400)),
             (Self::Granite.boxed(), ForkCondition::Timestamp(1723478400)),
+            (Self::Holocene.boxed(), ForkCondition::Timestamp(1732633200)),
         ])
     }
 
@@ -321,6 +351,63 @@ impl OptimismHardfork {
             (Self::Granite.boxed(), ForkCondition::Timestamp(1726070401)),
         ])
     }
+
+    /// Returns the canonical optimism hardforks of the superchain member with the given chain id.
+    ///
+    /// Returns `None` if the chain id is not a known superchain member.
+    pub fn super_chain_hardforks(
+        chain_id: u64,
+    ) -> Option<impl Iterator<Item = (Self, ForkCondition)>> {
+        let chain = Chain::from_id(chain_id);
+        let hardforks = if chain == Chain::optimism_mainnet() {
+            Self::op_mainnet()
+        } else if chain == Chain::optimism_sepolia() {
+            Self::op_sepolia()
+        } else if chain == Chain::base_mainnet() {
+            Self::base_mainnet()
+        } else if chain == Chain::base_sepolia() {
+            Self::base_sepolia()
+        } else {
+            return None
+        };
+
+        let forks: Vec<_> = hardforks
+            .forks_iter()
+            .filter_map(|(fork, condition)| Self::from_dyn(fork).map(|fork| (fork, condition)))
+            .collect();
+        Some(forks.into_iter())
+    }
+}
+
+impl ConfigureHardforks for OptimismHardfork {
+    fn init_block_hardforks(config: &ChainConfig) -> impl Iterator<Item = (Self, ForkCondition)> {
+        op_genesis_field(config, "bedrockBlock")
+            .map(|block| (Self::Bedrock, ForkCondition::Block(block)))
+            .into_iter()
+    }
+
+    fn init_time_hardforks(config: &ChainConfig) -> impl Iterator<Item = (Self, ForkCondition)> {
+        [
+            (Self::Regolith, "regolithTime"),
+            (Self::Canyon, "canyonTime"),
+            (Self::Ecotone, "ecotoneTime"),
+            (Self::Fjord, "fjordTime"),
+            (Self::Granite, "graniteTime"),
+            (Self::Holocene, "holoceneTime"),
+        ]
+        .into_iter()
+        .filter_map(|(fork, key)| {
+            op_genesis_field(config, key).map(|time| (fork, ForkCondition::Timestamp(time)))
+        })
+    }
+}
+
+/// Extracts an optimism specific activation block or timestamp from the [`ChainConfig`]'s extra
+/// fields, e.g. `regolithTime`.
+///
+/// Values that are missing or malformed are treated as not configured.
+fn op_genesis_field(config: &ChainConfig, key: &str) -> Option<u64> {
+    config.extra_fields.get_deserialized::<u64>(key).and_then(Result::ok)
 }
 
 /// Match helper method since it's not possible to match on `dyn Hardfork`
@@ -343,6 +430,20 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_downcast_hardfork() {
+        let fork = OptimismHardfork::Ecotone.boxed();
+        assert!(fork.is::<OptimismHardfork>());
+        assert_eq!(OptimismHardfork::from_dyn(&*fork), Some(OptimismHardfork::Ecotone));
+        assert_eq!(fork.as_optimism(), Some(OptimismHardfork::Ecotone));
+        assert_eq!(fork.as_ethereum(), None);
+
+        let fork = EthereumHardfork::Cancun.boxed();
+        assert_eq!(OptimismHardfork::from_dyn(&*fork), None);
+        assert_eq!(fork.as_optimism(), None);
+        assert_eq!(fork.as_ethereum(), Some(EthereumHardfork::Cancun));
+    }
+
     #[test]
     fn test_match_hardfork() {
         assert_eq!(
@@ -356,25 +457,228 @@ mod tests {
     }
 
     #[test]
-    fn check_op_hardfork_from_str() {
-        let hardfork_str = ["beDrOck", "rEgOlITH", "cAnYoN", "eCoToNe", "FJorD", "GRaNiTe"];
-        let expected_hardforks = [
-            OptimismHardfork::Bedrock,
-            OptimismHardfork::Regolith,
-            OptimismHardfork::Canyon,
-            OptimismHardfork::Ecotone,
-            OptimismHardfork::Fjord,
-            OptimismHardfork::Granite,
+    fn check_base_ecotone_fjord_activation() {
+        // <https://docs.base.org/base-chain/network-information/network-upgrades>
+        let base_mainnet = OptimismHardfork::base_mainnet();
+        assert_eq!(
+            base_mainnet.fork(OptimismHardfork::Ecotone),
+            ForkCondition::Timestamp(1710374401)
+        );
+        assert_eq!(
+            base_mainnet.fork(OptimismHardfork::Fjord),
+            ForkCondition::Timestamp(1720627201)
+        );
+
+        let base_sepolia = OptimismHardfork::base_sepolia();
+        assert_eq!(
+            base_sepolia.fork(OptimismHardfork::Ecotone),
+            ForkCondition::Timestamp(1708534800)
+        );
+        assert_eq!(
+            base_sepolia.fork(OptimismHardfork::Fjord),
+            ForkCondition::Timestamp(1716998400)
+        );
+
+        // the activation helpers agree with the hardfork lists
+        for fork in [OptimismHardfork::Ecotone, OptimismHardfork::Fjord] {
+            assert_eq!(
+                base_mainnet.fork(fork).as_timestamp(),
+                OptimismHardfork::base_mainnet_activation_timestamp(fork)
+            );
+            assert_eq!(
+                base_sepolia.fork(fork).as_timestamp(),
+                OptimismHardfork::base_sepolia_activation_timestamp(fork)
+            );
+        }
+    }
+
+    #[test]
+    fn check_ethereum_equivalent() {
+        let expected = [
+            (OptimismHardfork::Bedrock, None),
+            (OptimismHardfork::Regolith, None),
+            (OptimismHardfork::Canyon, Some(EthereumHardfork::Shanghai)),
+            (OptimismHardfork::Ecotone, Some(EthereumHardfork::Cancun)),
+            (OptimismHardfork::Fjord, None),
+            (OptimismHardfork::Granite, None),
+            (OptimismHardfork::Holocene, None),
         ];
+        assert_eq!(expected.len(), OptimismHardfork::all().len());
+
+        for (fork, ethereum_fork) in expected {
+            assert_eq!(fork.ethereum_equivalent(), ethereum_fork, "{fork}");
+        }
+    }
+
+    #[test]
+    fn check_merged_ethereum_optimism_hardforks() {
+        let config: ChainConfig = serde_json::from_str(
+            r#"{
+                "chainId": 10,
+                "homesteadBlock": 0,
+                "eip150Block": 0,
+                "eip155Block": 0,
+                "byzantiumBlock": 0,
+                "constantinopleBlock": 0,
+                "petersburgBlock": 0,
+                "istanbulBlock": 0,
+                "muirGlacierBlock": 0,
+                "berlinBlock": 3950000,
+                "londonBlock": 105235063,
+                "arrowGlacierBlock": 105235063,
+                "grayGlacierBlock": 105235063,
+                "mergeNetsplitBlock": 105235063,
+                "terminalTotalDifficulty": 0,
+                "shanghaiTime": 1704992401,
+                "cancunTime": 1710374401,
+                "bedrockBlock": 105235063,
+                "regolithTime": 0,
+                "canyonTime": 1704992401,
+                "ecotoneTime": 1710374401,
+                "fjordTime": 1720627201,
+                "graniteTime": 1726070401,
+                "holoceneTime": 1736445601
+            }"#,
+        )
+        .unwrap();
+
+        let forks = EthereumHardfork::init_merged::<OptimismHardfork>(&config);
+        assert_eq!(
+            forks.len(),
+            EthereumHardfork::init(&config).count() + OptimismHardfork::init(&config).count()
+        );
+
+        let names: Vec<_> = forks.iter().map(|(fork, _)| fork.name()).collect();
+        assert_eq!(
+            names,
+            [
+                "Homestead",
+                "Tangerine",
+                "SpuriousDragon",
+                "Byzantium",
+                "Constantinople",
+                "Petersburg",
+                "Istanbul",
+                "MuirGlacier",
+                "Berlin",
+                "London",
+                "ArrowGlacier",
+                "GrayGlacier",
+                "Bedrock",
+                "Paris",
+                "Regolith",
+                "Shanghai",
+                "Canyon",
+                "Cancun",
+                "Ecotone",
+                "Fjord",
+                "Granite",
+                "Holocene",
+            ]
+        );
+
+        // every optimism fork is ordered after the ethereum fork it incorporates
+        for fork in OptimismHardfork::all() {
+            let Some(ethereum_fork) = fork.ethereum_equivalent() else { continue };
+            let position = |name: &str| names.iter().position(|n| *n == name).unwrap();
+            assert!(position(ethereum_fork.name()) < position(fork.name()), "{fork}");
+        }
+
+        // the activation of each fork can be resolved through the `dyn Hardfork`
+        for (fork, condition) in &forks {
+            assert_eq!(fork.activation(&config), Some(*condition), "{}", fork.name());
+        }
+    }
+
+    /// Hardfork names in mixed case, they're parsed as `EXPECTED_HARDFORKS`.
+    const HARDFORK_STR: [&str; 7] =
+        ["beDrOck", "rEgOlITH", "cAnYoN", "eCoToNe", "FJorD", "GRaNiTe", "hOlOcEnE"];
+    const EXPECTED_HARDFORKS: [OptimismHardfork; 7] = [
+        OptimismHardfork::Bedrock,
+        OptimismHardfork::Regolith,
+        OptimismHardfork::Canyon,
+        OptimismHardfork::Ecotone,
+        OptimismHardfork::Fjord,
+        OptimismHardfork::Granite,
+        OptimismHardfork::Holocene,
+    ];
 
+    #[test]
+    fn check_op_hardfork_from_str() {
         let hardforks: Vec<OptimismHardfork> =
-            hardfork_str.iter().map(|h| OptimismHardfork::from_str(h).unwrap()).collect();
+            HARDFORK_STR.iter().map(|h| OptimismHardfork::from_str(h).unwrap()).collect();
+
+        assert_eq!(hardforks, EXPECTED_HARDFORKS);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn check_op_hardfork_serde() {
+        for (name, fork) in HARDFORK_STR.iter().zip(EXPECTED_HARDFORKS) {
+            let parsed: OptimismHardfork = serde_json::from_value(serde_json::json!(name)).unwrap();
+            assert_eq!(parsed, fork);
+
+            let json = serde_json::to_value(fork).unwrap();
+            assert_eq!(json, serde_json::json!(name.to_lowercase()));
+            assert_eq!(serde_json::from_value::<OptimismHardfork>(json).unwrap(), fork);
+        }
 
-        assert_eq!(hardforks, expected_hardforks);
+        let err =
+            serde_json::from_value::<OptimismHardfork>(serde_json::json!("london")).unwrap_err();
+        assert!(err.to_string().contains("london"), "{err}");
+    }
+
+    #[test]
+    fn check_op_hardfork_ordering() {
+        assert_eq!(
+            OptimismHardfork::all(),
+            [
+                OptimismHardfork::Bedrock,
+                OptimismHardfork::Regolith,
+                OptimismHardfork::Canyon,
+                OptimismHardfork::Ecotone,
+                OptimismHardfork::Fjord,
+                OptimismHardfork::Granite,
+                OptimismHardfork::Holocene,
+            ]
+        );
+        assert!(OptimismHardfork::Bedrock < OptimismHardfork::Regolith);
+        assert!(OptimismHardfork::Ecotone < OptimismHardfork::Fjord);
+        assert!(OptimismHardfork::Granite < OptimismHardfork::Holocene);
+        assert!(OptimismHardfork::all().windows(2).all(|pair| pair[0] < pair[1]));
+
+        // the activation schedule of op mainnet follows the same order
+        let op_mainnet: Vec<_> =
+            OptimismHardfork::super_chain_hardforks(Chain::optimism_mainnet().id())
+                .unwrap()
+                .map(|(fork, _)| fork)
+                .collect();
+        assert!(op_mainnet.windows(2).all(|pair| pair[0] < pair[1]));
     }
 
     #[test]
     fn check_nonexistent_hardfork_from_str() {
         assert!(OptimismHardfork::from_str("not a hardfork").is_err());
     }
+
+    #[test]
+    fn check_super_chain_hardforks() {
+        let op_mainnet: Vec<_> =
+            OptimismHardfork::super_chain_hardforks(Chain::optimism_mainnet().id())
+                .unwrap()
+                .collect();
+        assert_eq!(op_mainnet.len(), OptimismHardfork::all().len());
+        assert_eq!(op_mainnet[0], (OptimismHardfork::Bedrock, ForkCondition::Block(105235063)));
+        assert!(op_mainnet
+            .contains(&(OptimismHardfork::Holocene, ForkCondition::Timestamp(1736445601))));
+
+        let base_mainnet: Vec<_> =
+            OptimismHardfork::super_chain_hardforks(Chain::base_mainnet().id()).unwrap().collect();
+        assert_eq!(base_mainnet[0], (OptimismHardfork::Bedrock, ForkCondition::Block(0)));
+        assert!(base_mainnet
+            .contains(&(OptimismHardfork::Granite, ForkCondition::Timestamp(1726070401))));
+        assert!(!base_mainnet.iter().any(|(fork, _)| *fork == OptimismHardfork::Holocene));
+
+        assert!(OptimismHardfork::super_chain_hardforks(Chain::mainnet().id()).is_none());
+    }
 }
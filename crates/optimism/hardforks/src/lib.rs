@@ -14,7 +14,7 @@ pub mod hardfork;
 mod dev;
 
 pub use dev::DEV_HARDFORKS;
-pub use hardfork::OptimismHardfork;
+pub use hardfork::{AsOptimismHardfork, OptimismHardfork};
 
 use reth_ethereum_forks::EthereumHardforks;
 
@@ -11,7 +11,7 @@ use reth_node_api::{
 };
 use reth_node_builder::{
     components::{
-        ComponentsBuilder, ConsensusBuilder, ExecutorBuilder, NetworkBuilder,
+        ComponentFamily, ComponentsBuilder, ConsensusBuilder, ExecutorBuilder, NetworkBuilder,
         PayloadServiceBuilder, PoolBuilder, PoolBuilderConfigOverrides,
     },
     node::{FullNodeTypes, NodeTypes, NodeTypesWithEngine},
@@ -196,6 +196,10 @@ where
 
         Ok((evm_config, executor))
     }
+
+    fn family(&self) -> Option<ComponentFamily> {
+        Some(ComponentFamily::OPTIMISM)
+    }
 }
 
 /// A basic optimism transaction pool.
@@ -281,6 +285,10 @@ where
 
         Ok(transaction_pool)
     }
+
+    fn family(&self) -> Option<ComponentFamily> {
+        Some(ComponentFamily::OPTIMISM)
+    }
 }
 
 /// A basic optimism payload service builder
@@ -377,6 +385,10 @@ where
     ) -> eyre::Result<PayloadBuilderHandle<OpEngineTypes>> {
         self.spawn(OpEvmConfig::new(ctx.chain_spec()), ctx, pool)
     }
+
+    fn family(&self) -> Option<ComponentFamily> {
+        Some(ComponentFamily::OPTIMISM)
+    }
 }
 
 /// A basic optimism network builder.
@@ -473,6 +485,10 @@ where
             Ok(Arc::new(OpBeaconConsensus::new(ctx.chain_spec())))
         }
     }
+
+    fn family(&self) -> Option<ComponentFamily> {
+        Some(ComponentFamily::OPTIMISM)
+    }
 }
 
 /// Builder for [`OpEngineValidator`].
@@ -31,7 +31,10 @@ use std::{
     future::Future,
     ops::Deref,
     pin::Pin,
-    sync::{atomic::AtomicBool, Arc},
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Arc,
+    },
     task::{Context, Poll},
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
@@ -177,6 +180,7 @@ where
             interval: tokio::time::interval(self.config.interval),
             best_payload: PayloadState::Missing,
             pending_block: None,
+            cancelled: false,
             cached_reads,
             payload_task_guard: self.payload_task_guard.clone(),
             metrics: Default::default(),
@@ -325,6 +329,8 @@ where
     best_payload: PayloadState<Builder::BuiltPayload>,
     /// Receiver for the block that is currently being built.
     pending_block: Option<PendingPayload<Builder::BuiltPayload>>,
+    /// Whether the job was cancelled, see [`PayloadJob::cancel`].
+    cancelled: bool,
     /// Restricts how many generator tasks can be executed at once.
     payload_task_guard: PayloadTaskGuard,
     /// Caches all disk reads for the state the new payloads builds on
@@ -356,7 +362,7 @@ where
         let client = self.client.clone();
         let pool = self.pool.clone();
         let cancel = Cancelled::default();
-        let _cancel = cancel.clone();
+        let pending_cancel = cancel.clone();
         let guard = self.payload_task_guard.clone();
         let payload_config = self.config.clone();
         let best_payload = self.best_payload.payload().cloned();
@@ -378,7 +384,7 @@ where
             let _ = tx.send(result);
         }));
 
-        self.pending_block = Some(PendingPayload { _cancel, payload: rx });
+        self.pending_block = Some(PendingPayload::new(pending_cancel, rx));
     }
 }
 
@@ -405,8 +411,8 @@ where
         // check if the interval is reached
         while this.interval.poll_tick(cx).is_ready() {
             // start a new job if there is no pending block, we haven't reached the deadline,
-            // and the payload isn't frozen
-            if this.pending_block.is_none() && !this.best_payload.is_frozen() {
+            // and the payload isn't frozen or cancelled
+            if this.pending_block.is_none() && !this.best_payload.is_frozen() && !this.cancelled {
                 this.spawn_build_job();
             }
         }
@@ -429,7 +435,7 @@ where
                         trace!(target: "payload_builder", worse_fees = %fees, "skipped payload build of worse block");
                     }
                     BuildOutcome::Cancelled => {
-                        unreachable!("the cancel signal never fired")
+                        trace!(target: "payload_builder", "payload build cancelled");
                     }
                 },
                 Poll::Ready(Err(error)) => {
@@ -545,6 +551,13 @@ where
 
         (fut, KeepPayloadJobAlive::No)
     }
+
+    fn cancel(&mut self) {
+        if let Some(pending) = &self.pending_block {
+            pending.cancel();
+        }
+        self.cancelled = true;
+    }
 }
 
 /// Represents the current state of a payload being built.
@@ -654,7 +667,7 @@ where
 #[derive(Debug)]
 pub struct PendingPayload<P> {
     /// The marker to cancel the job on drop
-    _cancel: Cancelled,
+    cancel: Cancelled,
     /// The channel to send the result to.
     payload: oneshot::Receiver<Result<BuildOutcome<P>, PayloadBuilderError>>,
 }
@@ -665,7 +678,12 @@ impl<P> PendingPayload<P> {
         cancel: Cancelled,
         payload: oneshot::Receiver<Result<BuildOutcome<P>, PayloadBuilderError>>,
     ) -> Self {
-        Self { _cancel: cancel, payload }
+        Self { cancel, payload }
+    }
+
+    /// Cancels the build job, see [`Cancelled::cancel`].
+    pub fn cancel(&self) {
+        self.cancel.cancel();
     }
 }
 
@@ -680,22 +698,50 @@ impl<P> Future for PendingPayload<P> {
 
 /// A marker that can be used to cancel a job.
 ///
-/// If dropped, it will set the `cancelled` flag to true.
+/// If dropped, it will set the `cancelled` flag to true and the result of the job is discarded.
+/// The job can also be cancelled explicitly with [`Cancelled::cancel`], in which case the job may
+/// still return the payload with the transactions it included so far.
 #[derive(Default, Clone, Debug)]
-pub struct Cancelled(Arc<AtomicBool>);
+pub struct Cancelled(Arc<AtomicU8>);
 
 // === impl Cancelled ===
 
 impl Cancelled {
+    /// The job is not cancelled.
+    const ACTIVE: u8 = 0;
+    /// The job was cancelled with [`Cancelled::cancel`].
+    const CANCELLED: u8 = 1;
+    /// The marker was dropped, nobody awaits the result of the job anymore.
+    const DROPPED: u8 = 2;
+
     /// Returns true if the job was cancelled.
     pub fn is_cancelled(&self) -> bool {
-        self.0.load(std::sync::atomic::Ordering::Relaxed)
+        self.0.load(Ordering::Relaxed) != Self::ACTIVE
+    }
+
+    /// Returns true if the job was cancelled because the marker was dropped, so the result of the
+    /// job is discarded.
+    pub fn is_dropped(&self) -> bool {
+        self.0.load(Ordering::Relaxed) == Self::DROPPED
+    }
+
+    /// Cancels the job.
+    ///
+    /// Unlike dropping the marker, the result of the job is still awaited, so the job can return
+    /// the partial payload it built so far.
+    pub fn cancel(&self) {
+        let _ = self.0.compare_exchange(
+            Self::ACTIVE,
+            Self::CANCELLED,
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        );
     }
 }
 
 impl Drop for Cancelled {
     fn drop(&mut self) {
-        self.0.store(true, std::sync::atomic::Ordering::Relaxed);
+        self.0.store(Self::DROPPED, Ordering::Relaxed);
     }
 }
 
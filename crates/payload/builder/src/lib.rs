@@ -112,7 +112,9 @@ pub mod noop;
 pub mod test_utils;
 
 pub use alloy_rpc_types::engine::PayloadId;
-pub use reth_payload_primitives::{PayloadBuilderError, PayloadKind};
+pub use reth_payload_primitives::{
+    InclusionList, InclusionListProvider, PayloadBuilderError, PayloadKind,
+};
 pub use service::{
     PayloadBuilderHandle, PayloadBuilderService, PayloadServiceCommand, PayloadStore,
 };
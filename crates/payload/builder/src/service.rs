@@ -156,6 +156,14 @@ where
         let _ = self.to_service.send(PayloadServiceCommand::Subscribe(tx));
         Ok(PayloadEvents { receiver: rx.await? })
     }
+
+    async fn cancel(&self, id: PayloadId) -> bool {
+        let (tx, rx) = oneshot::channel();
+        if self.to_service.send(PayloadServiceCommand::Cancel(id, tx)).is_err() {
+            return false
+        }
+        rx.await.unwrap_or_default()
+    }
 }
 
 impl<T> PayloadBuilderHandle<T>
@@ -318,6 +326,20 @@ where
 
         Some(Box::pin(fut))
     }
+
+    /// Cancels the job for the given payload, see [`PayloadJob::cancel`].
+    ///
+    /// The job is kept until it resolves, so the payload it built so far can still be resolved.
+    /// Returns `false` if there is no job for the payload.
+    fn cancel(&mut self, id: PayloadId) -> bool {
+        let Some((job, _)) = self.payload_jobs.iter_mut().find(|(_, job_id)| *job_id == id) else {
+            trace!(%id, "no matching payload job found to cancel");
+            return false
+        };
+        job.cancel();
+        debug!(%id, "cancelled payload job");
+        true
+    }
 }
 
 impl<Gen, St, T> PayloadBuilderService<Gen, St, T>
@@ -437,6 +459,9 @@ where
                         let new_rx = this.payload_events.subscribe();
                         let _ = tx.send(new_rx);
                     }
+                    PayloadServiceCommand::Cancel(id, tx) => {
+                        let _ = tx.send(this.cancel(id));
+                    }
                 }
             }
 
@@ -469,6 +494,8 @@ pub enum PayloadServiceCommand<T: PayloadTypes> {
     ),
     /// Payload service events
     Subscribe(oneshot::Sender<broadcast::Receiver<Events<T>>>),
+    /// Cancel the job for the given payload
+    Cancel(PayloadId, oneshot::Sender<bool>),
 }
 
 impl<T> fmt::Debug for PayloadServiceCommand<T>
@@ -488,6 +515,7 @@ where
             }
             Self::Resolve(f0, f1, _f2) => f.debug_tuple("Resolve").field(&f0).field(&f1).finish(),
             Self::Subscribe(f0) => f.debug_tuple("Subscribe").field(&f0).finish(),
+            Self::Cancel(f0, f1) => f.debug_tuple("Cancel").field(&f0).field(&f1).finish(),
         }
     }
 }
@@ -70,6 +70,16 @@ pub trait PayloadJob: Future<Output = Result<(), PayloadBuilderError>> + Send +
     fn resolve(&mut self) -> (Self::ResolvePayloadFuture, KeepPayloadJobAlive) {
         self.resolve_kind(PayloadKind::Earliest)
     }
+
+    /// Called when the payload is no longer needed, e.g. because the CL sent a forkchoice update
+    /// with new payload attributes.
+    ///
+    /// The job is expected to stop building new payloads. A build that already included
+    /// transactions may still finish the partial payload, so the job should still be resolvable
+    /// until it is resolved or its deadline is reached.
+    ///
+    /// By default, this does nothing and the job keeps building until it resolves on its own.
+    fn cancel(&mut self) {}
 }
 
 /// Whether the payload job should be kept alive or terminated after the payload was requested by
@@ -36,6 +36,9 @@ pub enum PayloadBuilderError {
     /// Thrown if the payload requests withdrawals before Shanghai activation.
     #[error("withdrawals set before Shanghai activation")]
     WithdrawalsBeforeShanghai,
+    /// Thrown if a valid transaction of the inclusion list can't be included in the payload.
+    #[error("inclusion list transaction {0} can't be included")]
+    InclusionListUnsatisfied(B256),
     /// Any other payload building errors.
     #[error(transparent)]
     Other(Box<dyn core::error::Error + Send + Sync>),
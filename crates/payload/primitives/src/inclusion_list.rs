@@ -0,0 +1,66 @@
+//! [EIP-7547](https://eips.ethereum.org/EIPS/eip-7547) inclusion lists.
+
+use alloy_primitives::B256;
+use reth_primitives::TransactionSignedEcRecovered;
+use std::sync::Arc;
+
+/// Transactions that must be included in a block if they are still valid when the block is built,
+/// see [EIP-7547](https://eips.ethereum.org/EIPS/eip-7547).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InclusionList {
+    transactions: Vec<TransactionSignedEcRecovered>,
+}
+
+impl InclusionList {
+    /// Creates a new inclusion list with the given transactions, in the order they are included.
+    pub const fn new(transactions: Vec<TransactionSignedEcRecovered>) -> Self {
+        Self { transactions }
+    }
+
+    /// Returns the transactions of the inclusion list.
+    pub fn transactions(&self) -> &[TransactionSignedEcRecovered] {
+        &self.transactions
+    }
+
+    /// Returns the number of transactions in the inclusion list.
+    pub fn len(&self) -> usize {
+        self.transactions.len()
+    }
+
+    /// Returns `true` if the inclusion list has no transactions.
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+}
+
+impl FromIterator<TransactionSignedEcRecovered> for InclusionList {
+    fn from_iter<T: IntoIterator<Item = TransactionSignedEcRecovered>>(iter: T) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+/// Provides the [`InclusionList`] that a payload must satisfy.
+pub trait InclusionListProvider: Send + Sync + core::fmt::Debug {
+    /// Returns the inclusion list for the block that is built on top of the given parent block,
+    /// if there is one.
+    fn inclusion_list(&self, parent_hash: B256) -> Option<InclusionList>;
+}
+
+/// No inclusion lists.
+impl InclusionListProvider for () {
+    fn inclusion_list(&self, _parent_hash: B256) -> Option<InclusionList> {
+        None
+    }
+}
+
+impl<T: InclusionListProvider> InclusionListProvider for Option<T> {
+    fn inclusion_list(&self, parent_hash: B256) -> Option<InclusionList> {
+        self.as_ref()?.inclusion_list(parent_hash)
+    }
+}
+
+impl<T: InclusionListProvider + ?Sized> InclusionListProvider for Arc<T> {
+    fn inclusion_list(&self, parent_hash: B256) -> Option<InclusionList> {
+        (**self).inclusion_list(parent_hash)
+    }
+}
@@ -15,6 +15,9 @@ pub use error::{EngineObjectValidationError, PayloadBuilderError, VersionSpecifi
 mod events;
 pub use crate::events::{Events, PayloadEvents};
 
+mod inclusion_list;
+pub use inclusion_list::{InclusionList, InclusionListProvider};
+
 /// Contains traits to abstract over payload attributes types and default implementations of the
 /// [`PayloadAttributes`] trait for ethereum mainnet and optimism types.
 mod traits;
@@ -51,6 +51,15 @@ pub trait PayloadBuilder: Send + Unpin {
     /// Sends a message to the service to subscribe to payload events.
     /// Returns a receiver that will receive them.
     async fn subscribe(&self) -> Result<PayloadEvents<Self::PayloadType>, Self::Error>;
+
+    /// Cancels the in-progress build of the payload with the given identifier, e.g. because the
+    /// CL requested a payload with different attributes.
+    ///
+    /// Returns `true` if there was a payload job to cancel. By default, this cancels nothing and
+    /// returns `false`.
+    async fn cancel(&self, _id: PayloadId) -> bool {
+        false
+    }
 }
 
 /// Represents a built payload type that contains a built [`SealedBlock`] and can be converted into
@@ -7,9 +7,12 @@ use crate::{
 use alloy_primitives::BlockNumber;
 use reth_exex_types::FinishedExExHeight;
 use reth_provider::{
-    DBProvider, DatabaseProviderFactory, PruneCheckpointReader, PruneCheckpointWriter,
+    BlockReader, DBProvider, DatabaseProviderFactory, PruneCheckpointReader, PruneCheckpointWriter,
+    TableSizeProvider,
+};
+use reth_prune_types::{
+    PruneDryRunReport, PruneLimiter, PruneProgress, PruneSegment, PrunerOutput,
 };
-use reth_prune_types::{PruneLimiter, PruneProgress, PruneSegment, PrunerOutput};
 use reth_tokio_util::{EventSender, EventStream};
 use std::time::{Duration, Instant};
 use tokio::sync::watch;
@@ -326,13 +329,109 @@ where
         provider.commit()?;
         result
     }
+
+    /// Reports what [`Pruner::run`] would prune for the given segment at the provided tip block
+    /// number, without writing to the database.
+    ///
+    /// The entries of the segment up to its prune target are counted in a read-only transaction,
+    /// and the bytes freed are estimated from the average entry size of the pruned tables, as
+    /// reported by [`TableSizeProvider`]. The pruned tables are the [`Segment::tables`] of the
+    /// segment. The pruner limits are not applied, so the report covers all pruner runs until the
+    /// prune target is reached.
+    ///
+    /// Returns `None` if the segment is not configured, or there's nothing to prune for it yet. If
+    /// the segment is configured more than once, the first one is used.
+    pub fn dry_run(
+        &self,
+        segment: PruneSegment,
+        tip_block_number: BlockNumber,
+    ) -> Result<Option<PruneDryRunReport>, PrunerError>
+    where
+        PF: TableSizeProvider,
+        PF::Provider: BlockReader + PruneCheckpointReader,
+    {
+        let Some(tip_block_number) =
+            self.adjust_tip_block_number_to_finished_exex_height(tip_block_number)
+        else {
+            return Ok(None)
+        };
+        let Some(segment) = self.segments.iter().find(|s| s.segment() == segment) else {
+            return Ok(None)
+        };
+        let Some((to_block, _)) = segment
+            .mode()
+            .map(|mode| {
+                mode.prune_target_block(tip_block_number, segment.segment(), segment.purpose())
+            })
+            .transpose()?
+            .flatten()
+        else {
+            return Ok(None)
+        };
+
+        let provider = self.provider_factory.database_provider_ro()?;
+        let input = PruneInput {
+            previous_checkpoint: provider.get_prune_checkpoint(segment.segment())?,
+            to_block,
+            limiter: PruneLimiter::default(),
+        };
+        let block_range = input.get_next_block_range();
+        let table_entries = segment
+            .tables()
+            .iter()
+            .map(|table| Ok((table.name(), table.count_entries(&provider, &input)?)))
+            .collect::<Result<Vec<_>, PrunerError>>()?;
+        drop(provider);
+
+        let table_sizes = self.provider_factory.table_sizes()?;
+        let entries = table_entries.iter().map(|(_, entries)| entries).sum();
+        let estimated_bytes_freed = table_entries
+            .iter()
+            .map(|(table, entries)| {
+                let average_entry_size = table_sizes
+                    .get(*table)
+                    .and_then(|size| size.total_bytes.checked_div(size.row_count))
+                    .unwrap_or_default();
+                average_entry_size.saturating_mul(*entries as u64)
+            })
+            .sum();
+
+        debug!(
+            target: "pruner",
+            segment = ?segment.segment(),
+            %tip_block_number,
+            %to_block,
+            %entries,
+            %estimated_bytes_freed,
+            ?block_range,
+            "Segment pruning dry run finished"
+        );
+
+        Ok(Some(PruneDryRunReport {
+            segment: segment.segment(),
+            entries,
+            estimated_bytes_freed,
+            block_range,
+        }))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::Pruner;
+    use crate::{
+        segments::{SenderRecovery, TransactionLookup},
+        Pruner,
+    };
+    use alloy_primitives::B256;
+    use reth_db::tables;
+    use reth_db_api::table::Table;
     use reth_exex_types::FinishedExExHeight;
-    use reth_provider::test_utils::create_test_provider_factory;
+    use reth_provider::{
+        test_utils::create_test_provider_factory, PruneCheckpointReader, TableSizeProvider,
+    };
+    use reth_prune_types::{PruneMode, PruneSegment};
+    use reth_stages::test_utils::{StorageKind, TestStageDB};
+    use reth_testing_utils::generators::{self, random_block_range, BlockRangeParams};
 
     #[test]
     fn is_pruning_needed() {
@@ -373,4 +472,124 @@ mod tests {
         finished_exex_height_tx.send(FinishedExExHeight::Height(third_block_number)).unwrap();
         assert!(pruner.is_pruning_needed(third_block_number));
     }
+
+    #[test]
+    fn dry_run() {
+        let db = TestStageDB::default();
+        let mut rng = generators::rng();
+
+        let blocks = random_block_range(
+            &mut rng,
+            1..=10,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 2..3, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Database(None)).expect("insert blocks");
+
+        let mut transaction_senders = Vec::new();
+        for block in &blocks {
+            for transaction in &block.body.transactions {
+                transaction_senders.push((
+                    transaction_senders.len() as u64,
+                    transaction.recover_signer().expect("recover signer"),
+                ));
+            }
+        }
+        let transaction_senders_len = transaction_senders.len();
+        db.insert_transaction_senders(transaction_senders).expect("insert transaction senders");
+
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let pruner = Pruner::new_with_factory(
+            db.factory.clone(),
+            vec![Box::new(SenderRecovery::new(PruneMode::Before(6)))],
+            5,
+            usize::MAX,
+            None,
+            finished_exex_height_rx,
+        );
+
+        // Not configured segment
+        assert_eq!(pruner.dry_run(PruneSegment::TransactionLookup, 10).unwrap(), None);
+        // Nothing to prune yet
+        assert_eq!(pruner.dry_run(PruneSegment::SenderRecovery, 5).unwrap(), None);
+
+        let report = pruner.dry_run(PruneSegment::SenderRecovery, 10).unwrap().unwrap();
+        let expected_entries =
+            blocks.iter().take(5).map(|block| block.body.transactions.len()).sum::<usize>();
+        let table_size = db.factory.table_sizes().unwrap()[tables::TransactionSenders::NAME];
+        assert_eq!(report.segment, PruneSegment::SenderRecovery);
+        assert_eq!(report.entries, expected_entries);
+        assert_eq!(report.block_range, Some(0..=5));
+        assert!(report.estimated_bytes_freed > 0);
+        assert_eq!(
+            report.estimated_bytes_freed,
+            table_size.total_bytes / table_size.row_count * expected_entries as u64
+        );
+
+        // Nothing was deleted
+        assert_eq!(
+            db.table::<tables::TransactionSenders>().unwrap().len(),
+            transaction_senders_len
+        );
+        assert_eq!(
+            db.factory
+                .provider()
+                .unwrap()
+                .get_prune_checkpoint(PruneSegment::SenderRecovery)
+                .unwrap(),
+            None
+        );
+
+        // Pruner limits are not applied
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let pruner = Pruner::new_with_factory(
+            db.factory.clone(),
+            vec![Box::new(SenderRecovery::new(PruneMode::Before(6)))],
+            5,
+            1,
+            None,
+            finished_exex_height_rx,
+        );
+        let report = pruner.dry_run(PruneSegment::SenderRecovery, 10).unwrap().unwrap();
+        assert_eq!(report.entries, expected_entries);
+    }
+
+    #[test]
+    fn dry_run_transaction_lookup() {
+        let db = TestStageDB::default();
+        let mut rng = generators::rng();
+
+        let blocks = random_block_range(
+            &mut rng,
+            1..=10,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 2..3, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Database(None)).expect("insert blocks");
+
+        let mut tx_hash_numbers = Vec::new();
+        for block in &blocks {
+            for transaction in &block.body.transactions {
+                tx_hash_numbers.push((transaction.hash, tx_hash_numbers.len() as u64));
+            }
+        }
+        // The lookup entry of the first transaction is missing, so it's not counted
+        db.insert_tx_hash_numbers(tx_hash_numbers.into_iter().skip(1))
+            .expect("insert tx hash numbers");
+
+        let (_, finished_exex_height_rx) = tokio::sync::watch::channel(FinishedExExHeight::NoExExs);
+        let pruner = Pruner::new_with_factory(
+            db.factory.clone(),
+            vec![Box::new(TransactionLookup::new(PruneMode::Before(6)))],
+            5,
+            usize::MAX,
+            None,
+            finished_exex_height_rx,
+        );
+
+        let report = pruner.dry_run(PruneSegment::TransactionLookup, 10).unwrap().unwrap();
+        let transactions =
+            blocks.iter().take(5).map(|block| block.body.transactions.len()).sum::<usize>();
+        assert_eq!(report.segment, PruneSegment::TransactionLookup);
+        assert_eq!(report.entries, transactions - 1);
+        assert_eq!(report.block_range, Some(0..=5));
+    }
 }
@@ -5,7 +5,12 @@ mod user;
 
 use crate::PrunerError;
 use alloy_primitives::{BlockNumber, TxNumber};
-use reth_provider::{errors::provider::ProviderResult, BlockReader, PruneCheckpointWriter};
+use reth_db::{cursor::DbCursorRO, table::Table, tables, transaction::DbTx, DatabaseError};
+use reth_db_api::models::BlockNumberAddress;
+use reth_provider::{
+    errors::provider::ProviderResult, BlockReader, DBProvider, PruneCheckpointWriter,
+    TransactionsProvider,
+};
 use reth_prune_types::{
     PruneCheckpoint, PruneLimiter, PruneMode, PrunePurpose, PruneSegment, SegmentOutput,
 };
@@ -14,7 +19,10 @@ pub use static_file::{
     Headers as StaticFileHeaders, Receipts as StaticFileReceipts,
     Transactions as StaticFileTransactions,
 };
-use std::{fmt::Debug, ops::RangeInclusive};
+use std::{
+    fmt::Debug,
+    ops::{RangeBounds, RangeInclusive},
+};
 use tracing::error;
 pub use user::{
     AccountHistory, Receipts as UserReceipts, ReceiptsByLogs, SenderRecovery, StorageHistory,
@@ -41,6 +49,10 @@ pub trait Segment<Provider>: Debug + Send + Sync {
     /// Prune data for [`Self::segment`] using the provided input.
     fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError>;
 
+    /// Tables that [`Self::prune`] deletes entries from, whose entries are counted by
+    /// [`Pruner::dry_run`](crate::Pruner::dry_run).
+    fn tables(&self) -> &'static [PrunedTable];
+
     /// Save checkpoint for [`Self::segment`] to the database.
     fn save_checkpoint(
         &self,
@@ -144,6 +156,123 @@ impl PruneInput {
     }
 }
 
+/// A database table that a [`Segment`] deletes entries from, see [`Segment::tables`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrunedTable {
+    /// [`tables::Headers`], keyed by block number.
+    Headers,
+    /// [`tables::HeaderTerminalDifficulties`], keyed by block number.
+    HeaderTerminalDifficulties,
+    /// [`tables::CanonicalHeaders`], keyed by block number.
+    CanonicalHeaders,
+    /// [`tables::Transactions`], keyed by transaction number.
+    Transactions,
+    /// [`tables::TransactionSenders`], keyed by transaction number.
+    TransactionSenders,
+    /// [`tables::TransactionHashNumbers`], keyed by transaction hash.
+    TransactionHashNumbers,
+    /// [`tables::Receipts`], keyed by transaction number.
+    Receipts,
+    /// [`tables::AccountChangeSets`], keyed by block number.
+    AccountChangeSets,
+    /// [`tables::StorageChangeSets`], keyed by block number and address.
+    StorageChangeSets,
+}
+
+impl PrunedTable {
+    /// Returns the name of the table.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Headers => tables::Headers::NAME,
+            Self::HeaderTerminalDifficulties => tables::HeaderTerminalDifficulties::NAME,
+            Self::CanonicalHeaders => tables::CanonicalHeaders::NAME,
+            Self::Transactions => tables::Transactions::NAME,
+            Self::TransactionSenders => tables::TransactionSenders::NAME,
+            Self::TransactionHashNumbers => tables::TransactionHashNumbers::NAME,
+            Self::Receipts => tables::Receipts::NAME,
+            Self::AccountChangeSets => tables::AccountChangeSets::NAME,
+            Self::StorageChangeSets => tables::StorageChangeSets::NAME,
+        }
+    }
+
+    /// Counts the entries of the table that are pruned up to the `to_block` of the input, without
+    /// deleting them. The limiter of the input is not applied.
+    pub(crate) fn count_entries<Provider>(
+        &self,
+        provider: &Provider,
+        input: &PruneInput,
+    ) -> Result<usize, PrunerError>
+    where
+        Provider: DBProvider + BlockReader,
+    {
+        let tx = provider.tx_ref();
+        let entries = match self {
+            Self::Headers => count_entries::<tables::Headers>(tx, input.get_next_block_range())?,
+            Self::HeaderTerminalDifficulties => {
+                count_entries::<tables::HeaderTerminalDifficulties>(
+                    tx,
+                    input.get_next_block_range(),
+                )?
+            }
+            Self::CanonicalHeaders => {
+                count_entries::<tables::CanonicalHeaders>(tx, input.get_next_block_range())?
+            }
+            Self::Transactions => {
+                count_entries::<tables::Transactions>(tx, input.get_next_tx_num_range(provider)?)?
+            }
+            Self::TransactionSenders => count_entries::<tables::TransactionSenders>(
+                tx,
+                input.get_next_tx_num_range(provider)?,
+            )?,
+            Self::TransactionHashNumbers => {
+                let Some(tx_range) = input.get_next_tx_num_range(provider)? else { return Ok(0) };
+
+                // The table is keyed by hash, so look up the hash of every transaction in the
+                // range. The transactions are read in chunks to bound the memory usage.
+                let mut cursor = tx.cursor_read::<tables::TransactionHashNumbers>()?;
+                let mut entries = 0;
+                for start in tx_range.clone().step_by(TRANSACTION_LOOKUP_DRY_RUN_CHUNK_SIZE) {
+                    let end = (start + TRANSACTION_LOOKUP_DRY_RUN_CHUNK_SIZE as u64 - 1)
+                        .min(*tx_range.end());
+                    for transaction in provider.transactions_by_tx_range(start..=end)? {
+                        if cursor.seek_exact(transaction.hash())?.is_some() {
+                            entries += 1;
+                        }
+                    }
+                }
+                entries
+            }
+            Self::Receipts => {
+                count_entries::<tables::Receipts>(tx, input.get_next_tx_num_range(provider)?)?
+            }
+            Self::AccountChangeSets => {
+                count_entries::<tables::AccountChangeSets>(tx, input.get_next_block_range())?
+            }
+            Self::StorageChangeSets => count_entries::<tables::StorageChangeSets>(
+                tx,
+                input.get_next_block_range().map(BlockNumberAddress::range),
+            )?,
+        };
+        Ok(entries)
+    }
+}
+
+/// Number of transactions that are read at once to count the
+/// [`PrunedTable::TransactionHashNumbers`] entries.
+const TRANSACTION_LOOKUP_DRY_RUN_CHUNK_SIZE: usize = 10_000;
+
+/// Counts the entries of the table with keys in the given range. If there's no range, no entries
+/// are counted.
+fn count_entries<T: Table>(
+    tx: &impl DbTx,
+    range: Option<impl RangeBounds<T::Key>>,
+) -> Result<usize, DatabaseError> {
+    let Some(range) = range else { return Ok(0) };
+    tx.cursor_read::<T>()?
+        .walk_range(range)?
+        .try_fold(0, |entries, entry| entry.map(|_| entries + 1))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
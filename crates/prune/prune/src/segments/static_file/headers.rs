@@ -2,7 +2,7 @@ use std::num::NonZeroUsize;
 
 use crate::{
     db_ext::DbTxPruneExt,
-    segments::{PruneInput, Segment},
+    segments::{PruneInput, PrunedTable, Segment},
     PrunerError,
 };
 use alloy_primitives::BlockNumber;
@@ -49,6 +49,14 @@ impl<Provider: DBProvider<Tx: DbTxMut>> Segment<Provider> for Headers {
         PrunePurpose::StaticFile
     }
 
+    fn tables(&self) -> &'static [PrunedTable] {
+        &[
+            PrunedTable::Headers,
+            PrunedTable::HeaderTerminalDifficulties,
+            PrunedTable::CanonicalHeaders,
+        ]
+    }
+
     fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
         let (block_range_start, block_range_end) = match input.get_next_block_range() {
             Some(range) => (*range.start(), *range.end()),
@@ -1,5 +1,5 @@
 use crate::{
-    segments::{PruneInput, Segment},
+    segments::{PruneInput, PrunedTable, Segment},
     PrunerError,
 };
 use reth_db::transaction::DbTxMut;
@@ -39,6 +39,10 @@ where
         PrunePurpose::StaticFile
     }
 
+    fn tables(&self) -> &'static [PrunedTable] {
+        &[PrunedTable::Receipts]
+    }
+
     fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
         crate::segments::receipts::prune(provider, input)
     }
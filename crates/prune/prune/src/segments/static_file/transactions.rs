@@ -1,6 +1,6 @@
 use crate::{
     db_ext::DbTxPruneExt,
-    segments::{PruneInput, Segment},
+    segments::{PruneInput, PrunedTable, Segment},
     PrunerError,
 };
 use reth_db::{tables, transaction::DbTxMut};
@@ -40,6 +40,10 @@ where
         PrunePurpose::StaticFile
     }
 
+    fn tables(&self) -> &'static [PrunedTable] {
+        &[PrunedTable::Transactions]
+    }
+
     fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
         let tx_range = match input.get_next_tx_num_range(provider)? {
             Some(range) => range,
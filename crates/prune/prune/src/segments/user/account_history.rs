@@ -1,6 +1,6 @@
 use crate::{
     db_ext::DbTxPruneExt,
-    segments::{user::history::prune_history_indices, PruneInput, Segment},
+    segments::{user::history::prune_history_indices, PruneInput, PrunedTable, Segment},
     PrunerError,
 };
 use itertools::Itertools;
@@ -47,6 +47,12 @@ where
         PrunePurpose::User
     }
 
+    /// Only the changesets are counted, not the entries of the history indices that are pruned
+    /// along with them.
+    fn tables(&self) -> &'static [PrunedTable] {
+        &[PrunedTable::AccountChangeSets]
+    }
+
     #[instrument(level = "trace", target = "pruner", skip(self, provider), ret)]
     fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
         let range = match input.get_next_block_range() {
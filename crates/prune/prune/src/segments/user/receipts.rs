@@ -1,5 +1,5 @@
 use crate::{
-    segments::{PruneInput, Segment},
+    segments::{PruneInput, PrunedTable, Segment},
     PrunerError,
 };
 use reth_db::transaction::DbTxMut;
@@ -37,6 +37,10 @@ where
         PrunePurpose::User
     }
 
+    fn tables(&self) -> &'static [PrunedTable] {
+        &[PrunedTable::Receipts]
+    }
+
     #[instrument(level = "trace", target = "pruner", skip(self, provider), ret)]
     fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
         crate::segments::receipts::prune(provider, input)
@@ -1,6 +1,6 @@
 use crate::{
     db_ext::DbTxPruneExt,
-    segments::{PruneInput, Segment},
+    segments::{PruneInput, PrunedTable, Segment},
     PrunerError,
 };
 use reth_db::{tables, transaction::DbTxMut};
@@ -38,6 +38,12 @@ where
         PrunePurpose::User
     }
 
+    /// Receipts are pruned according to the log filter rather than a range, so they're not
+    /// counted.
+    fn tables(&self) -> &'static [PrunedTable] {
+        &[]
+    }
+
     #[instrument(level = "trace", target = "pruner", skip(self, provider), ret)]
     fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
         // Contract log filtering removes every receipt possible except the ones in the list. So,
@@ -1,6 +1,6 @@
 use crate::{
     db_ext::DbTxPruneExt,
-    segments::{PruneInput, Segment},
+    segments::{PruneInput, PrunedTable, Segment},
     PrunerError,
 };
 use reth_db::{tables, transaction::DbTxMut};
@@ -37,6 +37,10 @@ where
         PrunePurpose::User
     }
 
+    fn tables(&self) -> &'static [PrunedTable] {
+        &[PrunedTable::TransactionSenders]
+    }
+
     #[instrument(level = "trace", target = "pruner", skip(self, provider), ret)]
     fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
         let tx_range = match input.get_next_tx_num_range(provider)? {
@@ -1,6 +1,8 @@
 use crate::{
     db_ext::DbTxPruneExt,
-    segments::{user::history::prune_history_indices, PruneInput, Segment, SegmentOutput},
+    segments::{
+        user::history::prune_history_indices, PruneInput, PrunedTable, Segment, SegmentOutput,
+    },
     PrunerError,
 };
 use itertools::Itertools;
@@ -47,6 +49,12 @@ where
         PrunePurpose::User
     }
 
+    /// Only the changesets are counted, not the entries of the history indices that are pruned
+    /// along with them.
+    fn tables(&self) -> &'static [PrunedTable] {
+        &[PrunedTable::StorageChangeSets]
+    }
+
     #[instrument(level = "trace", target = "pruner", skip(self, provider), ret)]
     fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
         let range = match input.get_next_block_range() {
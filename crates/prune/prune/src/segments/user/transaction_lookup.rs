@@ -1,6 +1,6 @@
 use crate::{
     db_ext::DbTxPruneExt,
-    segments::{PruneInput, Segment, SegmentOutput},
+    segments::{PruneInput, PrunedTable, Segment, SegmentOutput},
     PrunerError,
 };
 use rayon::prelude::*;
@@ -38,6 +38,10 @@ where
         PrunePurpose::User
     }
 
+    fn tables(&self) -> &'static [PrunedTable] {
+        &[PrunedTable::TransactionHashNumbers]
+    }
+
     #[instrument(level = "trace", target = "pruner", skip(self, provider), ret)]
     fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
         let (start, end) = match input.get_next_tx_num_range(provider)? {
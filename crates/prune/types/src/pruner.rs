@@ -1,4 +1,5 @@
 use alloy_primitives::{BlockNumber, TxNumber};
+use std::ops::RangeInclusive;
 
 use crate::{PruneCheckpoint, PruneLimiter, PruneMode, PruneSegment};
 
@@ -45,6 +46,20 @@ impl SegmentOutput {
     }
 }
 
+/// Report of what pruning a segment would delete from the database.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PruneDryRunReport {
+    /// Segment that would be pruned.
+    pub segment: PruneSegment,
+    /// Number of entries that would be pruned, i.e. deleted from the database.
+    pub entries: usize,
+    /// Estimated number of bytes that would be freed, based on the average entry size of the
+    /// pruned tables.
+    pub estimated_bytes_freed: u64,
+    /// Range of blocks that would be pruned, if any.
+    pub block_range: Option<RangeInclusive<BlockNumber>>,
+}
+
 /// Segment pruning checkpoint.
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
 pub struct SegmentOutputCheckpoint {
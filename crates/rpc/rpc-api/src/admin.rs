@@ -1,6 +1,8 @@
 use alloy_rpc_types_admin::{NodeInfo, PeerInfo};
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_db_api::database::TableSizeEstimate;
 use reth_network_peers::{AnyNode, NodeRecord};
+use std::collections::BTreeMap;
 
 /// Admin namespace rpc interface that gives access to several non-standard RPC methods.
 #[cfg_attr(not(feature = "client"), rpc(server, namespace = "admin"))]
@@ -45,4 +47,8 @@ pub trait AdminApi {
     /// Returns the ENR of the node.
     #[method(name = "nodeInfo")]
     async fn node_info(&self) -> RpcResult<NodeInfo>;
+
+    /// Returns an estimate of the storage size of every database table, keyed by table name.
+    #[method(name = "dbStats")]
+    fn db_stats(&self) -> RpcResult<BTreeMap<String, TableSizeEstimate>>;
 }
@@ -2,6 +2,7 @@ use alloy_json_rpc::RpcObject;
 use alloy_primitives::Address;
 use alloy_rpc_types_txpool::{TxpoolContent, TxpoolContentFrom, TxpoolInspect, TxpoolStatus};
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_transaction_pool::PoolSnapshot;
 
 /// Txpool rpc interface.
 #[cfg_attr(not(feature = "client"), rpc(server, namespace = "txpool"))]
@@ -34,4 +35,12 @@ pub trait TxPoolApi<T: RpcObject> {
     /// See [here](https://geth.ethereum.org/docs/rpc/ns-txpool#txpool_content) for more details
     #[method(name = "content")]
     async fn txpool_content(&self) -> RpcResult<TxpoolContent<T>>;
+
+    /// Returns a snapshot of all transactions in the txpool, grouped by sender, including their
+    /// sub-pool and why they are not pending.
+    ///
+    /// The snapshot contains the encoded transactions, so it can be used to reproduce the state
+    /// of the pool when debugging.
+    #[method(name = "snapshot")]
+    async fn txpool_snapshot(&self) -> RpcResult<PoolSnapshot>;
 }
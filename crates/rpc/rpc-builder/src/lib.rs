@@ -185,7 +185,7 @@ use reth_network_api::{noop::NoopNetwork, NetworkInfo, Peers};
 use reth_primitives::Header;
 use reth_provider::{
     AccountReader, BlockReader, CanonStateSubscriptions, ChainSpecProvider, ChangeSetReader,
-    EvmEnvProvider, FullRpcProvider, StateProviderFactory,
+    EvmEnvProvider, FullRpcProvider, StateProviderFactory, TableSizeProvider,
 };
 use reth_rpc::{
     AdminApi, DebugApi, EngineEthApi, EthBundle, NetApi, OtterscanApi, RPCApi, RethApi, TraceApi,
@@ -204,7 +204,12 @@ use serde::{Deserialize, Serialize};
 use tower::Layer;
 use tower_http::cors::CorsLayer;
 
-use crate::{auth::AuthRpcModule, error::WsHttpSamePortError, metrics::RpcRequestMetrics};
+use crate::{
+    auth::AuthRpcModule,
+    error::WsHttpSamePortError,
+    metrics::RpcRequestMetrics,
+    rate_limiter::{MaybeRateLimitedServer, RpcMethodRateLimitingService},
+};
 
 pub use cors::CorsDomainError;
 
@@ -235,6 +240,7 @@ pub use eth::EthHandlers;
 // Rpc server metrics
 mod metrics;
 pub use metrics::{MeteredRequestFuture, RpcRequestMetricsService};
+pub use rate_limiter::RpcMethodRateLimiter;
 
 // Rpc rate limiter
 pub mod rate_limiter;
@@ -1033,11 +1039,12 @@ where
     BlockExecutor: BlockExecutorProvider,
 {
     /// Instantiates `AdminApi`
-    pub fn admin_api(&self) -> AdminApi<Network, Provider::ChainSpec>
+    pub fn admin_api(&self) -> AdminApi<Network, Provider::ChainSpec, Provider>
     where
         Network: Peers,
+        Provider: Clone,
     {
-        AdminApi::new(self.network.clone(), self.provider.chain_spec())
+        AdminApi::new(self.network.clone(), self.provider.chain_spec(), self.provider.clone())
     }
 
     /// Instantiates `Web3Api`
@@ -1049,6 +1056,7 @@ where
     pub fn register_admin(&mut self) -> &mut Self
     where
         Network: Peers,
+        Provider: TableSizeProvider + Clone + 'static,
     {
         let adminapi = self.admin_api();
         self.modules.insert(RethRpcModule::Admin, adminapi.into_rpc().into());
@@ -1351,11 +1359,13 @@ where
                 self.modules
                     .entry(namespace)
                     .or_insert_with(|| match namespace {
-                        RethRpcModule::Admin => {
-                            AdminApi::new(self.network.clone(), self.provider.chain_spec())
-                                .into_rpc()
-                                .into()
-                        }
+                        RethRpcModule::Admin => AdminApi::new(
+                            self.network.clone(),
+                            self.provider.chain_spec(),
+                            self.provider.clone(),
+                        )
+                        .into_rpc()
+                        .into(),
                         RethRpcModule::Debug => DebugApi::new(
                             self.provider.clone(),
                             eth_api.clone(),
@@ -1460,6 +1470,8 @@ pub struct RpcServerConfig<RpcMiddleware = Identity> {
     jwt_secret: Option<JwtSecret>,
     /// Configurable RPC middleware
     rpc_middleware: RpcServiceBuilder<RpcMiddleware>,
+    /// Rate limits of the http and ws servers
+    rate_limiter: RpcMethodRateLimiter,
 }
 
 // === impl RpcServerConfig ===
@@ -1478,6 +1490,7 @@ impl Default for RpcServerConfig<Identity> {
             ipc_endpoint: None,
             jwt_secret: None,
             rpc_middleware: RpcServiceBuilder::new(),
+            rate_limiter: Default::default(),
         }
     }
 }
@@ -1541,9 +1554,16 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
             ipc_endpoint: self.ipc_endpoint,
             jwt_secret: self.jwt_secret,
             rpc_middleware,
+            rate_limiter: self.rate_limiter,
         }
     }
 
+    /// Configure the rate limits of the http and ws servers
+    pub fn with_rate_limiter(mut self, rate_limiter: RpcMethodRateLimiter) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
     /// Configure the cors domains for http _and_ ws
     pub fn with_cors(self, cors_domain: Option<String>) -> Self {
         self.with_http_cors(cors_domain.clone()).with_ws_cors(cors_domain)
@@ -1654,9 +1674,13 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
     /// Returns the [`RpcServerHandle`] with the handle to the started servers.
     pub async fn start(self, modules: &TransportRpcModules) -> Result<RpcServerHandle, RpcError>
     where
-        RpcMiddleware: Layer<RpcRequestMetricsService<RpcService>> + Clone + Send + 'static,
-        for<'a> <RpcMiddleware as Layer<RpcRequestMetricsService<RpcService>>>::Service:
-            Send + Sync + 'static + RpcServiceT<'a>,
+        RpcMiddleware: Layer<RpcRequestMetricsService<RpcMethodRateLimitingService<RpcService>>>
+            + Clone
+            + Send
+            + 'static,
+        for<'a> <RpcMiddleware as Layer<
+            RpcRequestMetricsService<RpcMethodRateLimitingService<RpcService>>,
+        >>::Service: Send + Sync + 'static + RpcServiceT<'a>,
     {
         let mut http_handle = None;
         let mut ws_handle = None;
@@ -1683,6 +1707,11 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
             ipc_handle = Some(ipc.start(modules.ipc.clone().expect("ipc server error")).await?);
         }
 
+        let start_server = |server, module: RpcModule<()>| match server {
+            MaybeRateLimitedServer::Server(server) => server.start(module),
+            MaybeRateLimitedServer::RateLimited(server) => server.start(module),
+        };
+
         // If both are configured on the same port, we combine them into one server.
         if self.http_addr == self.ws_addr &&
             self.http_server_config.is_some() &&
@@ -1707,23 +1736,27 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
             modules.config.ensure_ws_http_identical()?;
 
             if let Some(builder) = self.http_server_config {
-                let server = builder
+                let builder = builder
                     .set_http_middleware(
                         tower::ServiceBuilder::new()
                             .option_layer(Self::maybe_cors_layer(cors)?)
                             .option_layer(Self::maybe_jwt_layer(self.jwt_secret)),
                     )
                     .set_rpc_middleware(
-                        self.rpc_middleware.clone().layer(
-                            modules
-                                .http
-                                .as_ref()
-                                .or(modules.ws.as_ref())
-                                .map(RpcRequestMetrics::same_port)
-                                .unwrap_or_default(),
-                        ),
-                    )
-                    .build(http_socket_addr)
+                        self.rpc_middleware
+                            .clone()
+                            .layer(
+                                modules
+                                    .http
+                                    .as_ref()
+                                    .or(modules.ws.as_ref())
+                                    .map(RpcRequestMetrics::same_port)
+                                    .unwrap_or_default(),
+                            )
+                            .layer(self.rate_limiter.clone()),
+                    );
+                let rate_limiter = self.rate_limiter.clone();
+                let server = MaybeRateLimitedServer::bind(http_socket_addr, builder, rate_limiter)
                     .await
                     .map_err(|err| {
                         RpcError::server_error(err, ServerKind::WsHttp(http_socket_addr))
@@ -1732,7 +1765,7 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                     RpcError::server_error(err, ServerKind::WsHttp(http_socket_addr))
                 })?;
                 if let Some(module) = modules.http.as_ref().or(modules.ws.as_ref()) {
-                    let handle = server.start(module.clone());
+                    let handle = start_server(server, module.clone());
                     http_handle = Some(handle.clone());
                     ws_handle = Some(handle);
                 }
@@ -1754,7 +1787,7 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         let mut http_server = None;
 
         if let Some(builder) = self.ws_server_config {
-            let server = builder
+            let builder = builder
                 .ws_only()
                 .set_http_middleware(
                     tower::ServiceBuilder::new()
@@ -1764,11 +1797,13 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                 .set_rpc_middleware(
                     self.rpc_middleware
                         .clone()
-                        .layer(modules.ws.as_ref().map(RpcRequestMetrics::ws).unwrap_or_default()),
-                )
-                .build(ws_socket_addr)
-                .await
-                .map_err(|err| RpcError::server_error(err, ServerKind::WS(ws_socket_addr)))?;
+                        .layer(modules.ws.as_ref().map(RpcRequestMetrics::ws).unwrap_or_default())
+                        .layer(self.rate_limiter.clone()),
+                );
+            let server =
+                MaybeRateLimitedServer::bind(ws_socket_addr, builder, self.rate_limiter.clone())
+                    .await
+                    .map_err(|err| RpcError::server_error(err, ServerKind::WS(ws_socket_addr)))?;
 
             let addr = server
                 .local_addr()
@@ -1779,7 +1814,7 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         }
 
         if let Some(builder) = self.http_server_config {
-            let server = builder
+            let builder = builder
                 .http_only()
                 .set_http_middleware(
                     tower::ServiceBuilder::new()
@@ -1787,13 +1822,19 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                         .option_layer(Self::maybe_jwt_layer(self.jwt_secret)),
                 )
                 .set_rpc_middleware(
-                    self.rpc_middleware.clone().layer(
-                        modules.http.as_ref().map(RpcRequestMetrics::http).unwrap_or_default(),
-                    ),
-                )
-                .build(http_socket_addr)
-                .await
-                .map_err(|err| RpcError::server_error(err, ServerKind::Http(http_socket_addr)))?;
+                    self.rpc_middleware
+                        .clone()
+                        .layer(
+                            modules.http.as_ref().map(RpcRequestMetrics::http).unwrap_or_default(),
+                        )
+                        .layer(self.rate_limiter.clone()),
+                );
+            let server =
+                MaybeRateLimitedServer::bind(http_socket_addr, builder, self.rate_limiter.clone())
+                    .await
+                    .map_err(|err| {
+                        RpcError::server_error(err, ServerKind::Http(http_socket_addr))
+                    })?;
             let local_addr = server
                 .local_addr()
                 .map_err(|err| RpcError::server_error(err, ServerKind::Http(http_socket_addr)))?;
@@ -1802,9 +1843,9 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         }
 
         http_handle = http_server
-            .map(|http_server| http_server.start(modules.http.clone().expect("http server error")));
+            .map(|server| start_server(server, modules.http.clone().expect("http server error")));
         ws_handle = ws_server
-            .map(|ws_server| ws_server.start(modules.ws.clone().expect("ws server error")));
+            .map(|server| start_server(server, modules.ws.clone().expect("ws server error")));
         Ok(RpcServerHandle {
             http_local_addr,
             ws_local_addr,
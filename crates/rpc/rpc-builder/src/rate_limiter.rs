@@ -1,15 +1,43 @@
 //! [`jsonrpsee`] helper layer for rate limiting certain methods.
 
-use jsonrpsee::{server::middleware::rpc::RpcServiceT, types::Request, MethodResponse};
+use http::{header::RETRY_AFTER, HeaderMap, HeaderValue};
+use hyper::body::Incoming;
+use jsonrpsee::{
+    core::BoxError,
+    server::{
+        middleware::rpc::RpcServiceT, serve_with_graceful_shutdown, stop_channel, HttpResponse,
+        Server, ServerBuilder, ServerHandle, TowerService, TowerServiceBuilder,
+    },
+    types::{ErrorObject, Request},
+    MethodResponse, Methods,
+};
+use reth_config::config::{RateLimit, RpcRateLimitConfig};
 use std::{
+    collections::HashMap,
     future::Future,
+    io,
+    net::{IpAddr, SocketAddr},
     pin::Pin,
-    sync::Arc,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
     task::{ready, Context, Poll},
+    time::{Duration, Instant},
+};
+use tokio::{
+    net::TcpListener,
+    sync::{OwnedSemaphorePermit, Semaphore},
 };
-use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 use tokio_util::sync::PollSemaphore;
-use tower::Layer;
+use tower::{Layer, Service};
+use tracing::debug;
+
+/// JSON-RPC error code of calls that are rejected by the [`RpcMethodRateLimiter`].
+pub const RATE_LIMITED_ERROR_CODE: i32 = -32005;
+
+/// Number of tracked clients above which the buckets of idle clients are dropped.
+const MAX_TRACKED_CLIENTS: usize = 10_000;
 
 /// Rate limiter for the RPC server.
 ///
@@ -114,3 +142,495 @@ impl<F: Future<Output = MethodResponse>> Future for RateLimitingRequestFuture<F>
         res
     }
 }
+
+/// Rate limiter for the RPC server that enforces the token bucket limits of a
+/// [`RpcRateLimitConfig`].
+///
+/// Every client has its own bucket for each configured method pattern, and all calls share the
+/// global bucket. A rejected call is answered with a [`RATE_LIMITED_ERROR_CODE`] error whose data
+/// is the number of seconds after which the call can be retried.
+///
+/// Clients are identified by the address of their connection, see
+/// [`RpcMethodRateLimiter::client_addr`]. Calls of servers that are not started with
+/// [`RateLimitedServer`] are attributed to the same client.
+///
+/// The auth server is not rate limited. Its only client is the consensus client, which
+/// authenticates with the single jwt secret of the node, so there are no keys to limit separately.
+#[derive(Debug, Clone, Default)]
+pub struct RpcMethodRateLimiter {
+    config: Arc<RpcRateLimitConfig>,
+    state: Arc<Mutex<RateLimiterState>>,
+}
+
+impl RpcMethodRateLimiter {
+    /// Creates a new rate limiter with the given limits.
+    pub fn new(config: RpcRateLimitConfig) -> Self {
+        Self { config: Arc::new(config), state: Default::default() }
+    }
+
+    /// Returns `true` if no limits are configured, so no call is ever rejected.
+    pub fn is_empty(&self) -> bool {
+        self.config.is_empty()
+    }
+
+    /// Returns the address of the client of a request that was received from the given peer.
+    ///
+    /// The `X-Forwarded-For` and `X-Real-IP` headers are only honoured if the peer is one of the
+    /// trusted proxies of the config, because any other client could set them to pick its own
+    /// bucket. The client is the last forwarded address that is not a trusted proxy.
+    pub fn client_addr(&self, peer: IpAddr, headers: &HeaderMap) -> IpAddr {
+        let trusted_proxies = &self.config.trusted_proxies;
+        if !trusted_proxies.contains(&peer) {
+            return peer
+        }
+
+        let forwarded_ip = |name: &str| {
+            headers
+                .get_all(name)
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .flat_map(|value| value.split(','))
+                .filter_map(|addr| addr.trim().parse::<IpAddr>().ok())
+                .rfind(|addr| !trusted_proxies.contains(addr))
+        };
+        forwarded_ip("x-forwarded-for").or_else(|| forwarded_ip("x-real-ip")).unwrap_or(peer)
+    }
+
+    /// Takes a token for a call of the method by the client from all buckets the call is subject
+    /// to.
+    ///
+    /// Returns the time until the call can be retried if one of the buckets is empty.
+    fn check(&self, method: &str, client: Option<IpAddr>) -> Result<(), Duration> {
+        self.check_at(method, client, Instant::now())
+    }
+
+    fn check_at(&self, method: &str, client: Option<IpAddr>, now: Instant) -> Result<(), Duration> {
+        if self.is_empty() {
+            return Ok(())
+        }
+
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+        let RateLimiterState { global, clients } = &mut *state;
+
+        let method_limit =
+            self.config.methods.iter().enumerate().find(|(_, limit)| limit.matches(method));
+        if let Some((idx, limit)) = method_limit {
+            if clients.len() >= MAX_TRACKED_CLIENTS {
+                let methods = &self.config.methods;
+                clients.retain(|(idx, _), bucket| !bucket.is_full(methods[*idx].limit, now));
+            }
+            clients
+                .entry((idx, client))
+                .or_insert_with(|| TokenBucket::full(limit.limit, now))
+                .available(limit.limit, now)?;
+        }
+        if let Some(limit) = self.config.global {
+            global.get_or_insert_with(|| TokenBucket::full(limit, now)).available(limit, now)?;
+            global.as_mut().expect("bucket was inserted").take();
+        }
+        if let Some((idx, _)) = method_limit {
+            clients.get_mut(&(idx, client)).expect("bucket was inserted").take();
+        }
+        Ok(())
+    }
+}
+
+impl<S> Layer<S> for RpcMethodRateLimiter {
+    type Service = RpcMethodRateLimitingService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcMethodRateLimitingService { inner, rate_limiter: self.clone() }
+    }
+}
+
+/// The token buckets of a [`RpcMethodRateLimiter`].
+#[derive(Debug, Default)]
+struct RateLimiterState {
+    global: Option<TokenBucket>,
+    /// The buckets of each client, by the index of the method limit in the config.
+    clients: HashMap<(usize, Option<IpAddr>), TokenBucket>,
+}
+
+/// A token bucket that is refilled continuously at the rate of its [`RateLimit`].
+#[derive(Debug, Clone, Copy)]
+struct TokenBucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    fn full(limit: RateLimit, now: Instant) -> Self {
+        Self { tokens: limit.burst as f64, updated_at: now }
+    }
+
+    fn refill(&mut self, limit: RateLimit, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * limit.rate as f64).min(limit.burst as f64);
+        self.updated_at = now;
+    }
+
+    fn is_full(&mut self, limit: RateLimit, now: Instant) -> bool {
+        self.refill(limit, now);
+        self.tokens >= limit.burst as f64
+    }
+
+    /// Returns the time until a token is available if the bucket is empty.
+    fn available(&mut self, limit: RateLimit, now: Instant) -> Result<(), Duration> {
+        self.refill(limit, now);
+        if self.tokens >= 1. {
+            return Ok(())
+        }
+        Err(Duration::from_secs_f64((1. - self.tokens) / limit.rate.max(1) as f64))
+    }
+
+    fn take(&mut self) {
+        self.tokens -= 1.;
+    }
+}
+
+/// A [`RpcServiceT`] middleware that rejects calls that exceed the limits of a
+/// [`RpcMethodRateLimiter`].
+#[derive(Debug, Clone)]
+pub struct RpcMethodRateLimitingService<S> {
+    inner: S,
+    rate_limiter: RpcMethodRateLimiter,
+}
+
+impl<'a, S> RpcServiceT<'a> for RpcMethodRateLimitingService<S>
+where
+    S: RpcServiceT<'a> + Send + Sync + Clone + 'static,
+{
+    type Future = RateLimitedResponseFuture<S::Future>;
+
+    fn call(&self, req: Request<'a>) -> Self::Future {
+        let client = req.extensions.get::<RateLimitClient>();
+        match self.rate_limiter.check(req.method_name(), client.map(|client| client.addr)) {
+            Ok(()) => RateLimitedResponseFuture::Call(self.inner.call(req)),
+            Err(retry_after) => {
+                let retry_after = retry_after.as_secs_f64().ceil().max(1.) as u64;
+                if let Some(client) = client {
+                    client.retry_after.fetch_max(retry_after, Ordering::Relaxed);
+                }
+                let err = ErrorObject::owned(
+                    RATE_LIMITED_ERROR_CODE,
+                    "limit exceeded",
+                    Some(retry_after),
+                );
+                let rp = MethodResponse::error(req.id().clone(), err);
+                RateLimitedResponseFuture::Rejected(Some(rp))
+            }
+        }
+    }
+}
+
+/// Response future of the [`RpcMethodRateLimitingService`].
+#[pin_project::pin_project(project = RateLimitedResponseFutureProj)]
+pub enum RateLimitedResponseFuture<F> {
+    /// The call is within the limits.
+    Call(#[pin] F),
+    /// The call was rejected.
+    Rejected(Option<MethodResponse>),
+}
+
+impl<F> std::fmt::Debug for RateLimitedResponseFuture<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("RateLimitedResponseFuture")
+    }
+}
+
+impl<F: Future<Output = MethodResponse>> Future for RateLimitedResponseFuture<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.project() {
+            RateLimitedResponseFutureProj::Call(fut) => fut.poll(cx),
+            RateLimitedResponseFutureProj::Rejected(rp) => {
+                Poll::Ready(rp.take().expect("polled after completion"))
+            }
+        }
+    }
+}
+
+/// The client of a http request, inserted into the request extensions by the
+/// [`RateLimitClientService`].
+#[derive(Debug, Clone)]
+struct RateLimitClient {
+    addr: IpAddr,
+    /// The largest retry delay in seconds of the rejected calls of the request.
+    retry_after: Arc<AtomicU64>,
+}
+
+/// A http request of a connection of the [`RateLimitedServer`].
+type IncomingRequest = http::Request<Incoming>;
+
+/// A http or ws server that identifies the client of each request for the
+/// [`RpcMethodRateLimiter`] by the address of its connection.
+///
+/// The jsonrpsee [`Server`] doesn't expose the address of a connection to middleware, so this
+/// server accepts the connections itself and serves each of them with its own [`TowerService`] of
+/// the configured server.
+#[derive(Debug)]
+pub struct RateLimitedServer<RpcMiddleware, HttpMiddleware> {
+    listener: TcpListener,
+    builder: TowerServiceBuilder<RpcMiddleware, HttpMiddleware>,
+    rate_limiter: RpcMethodRateLimiter,
+}
+
+impl<RpcMiddleware, HttpMiddleware> RateLimitedServer<RpcMiddleware, HttpMiddleware> {
+    /// Binds a server to the given address.
+    pub async fn bind(
+        addr: SocketAddr,
+        builder: TowerServiceBuilder<RpcMiddleware, HttpMiddleware>,
+        rate_limiter: RpcMethodRateLimiter,
+    ) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr).await?;
+        Ok(Self { listener, builder, rate_limiter })
+    }
+
+    /// Returns the address the server is bound to.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Starts serving the methods on a new task.
+    ///
+    /// The server runs until it is stopped with the returned [`ServerHandle`].
+    pub fn start(self, methods: impl Into<Methods>) -> ServerHandle
+    where
+        RpcMiddleware: Clone + Send + 'static,
+        HttpMiddleware: Clone + Send + 'static,
+        TowerService<RpcMiddleware, HttpMiddleware>:
+            Service<IncomingRequest, Response = HttpResponse, Error = BoxError> + Send + 'static,
+        <TowerService<RpcMiddleware, HttpMiddleware> as Service<IncomingRequest>>::Future: Send,
+    {
+        let Self { listener, builder, rate_limiter } = self;
+        let methods = methods.into();
+        let (stop_handle, server_handle) = stop_channel();
+
+        tokio::spawn(async move {
+            let stopped = stop_handle.clone().shutdown();
+            tokio::pin!(stopped);
+            loop {
+                let (stream, peer) = tokio::select! {
+                    _ = &mut stopped => break,
+                    conn = listener.accept() => match conn {
+                        Ok(conn) => conn,
+                        Err(err) => {
+                            debug!(target: "rpc", %err, "Failed to accept connection");
+                            continue
+                        }
+                    },
+                };
+                if let Err(err) = stream.set_nodelay(true) {
+                    debug!(target: "rpc", %err, "Failed to set TCP_NODELAY");
+                }
+
+                // every connection needs its own service, which assigns it a new connection id
+                let service = RateLimitClientService {
+                    inner: builder.clone().build(methods.clone(), stop_handle.clone()),
+                    peer: peer.ip(),
+                    rate_limiter: rate_limiter.clone(),
+                };
+                let stopped = stop_handle.clone().shutdown();
+                tokio::spawn(async move {
+                    if let Err(err) = serve_with_graceful_shutdown(stream, service, stopped).await {
+                        debug!(target: "rpc", %err, %peer, "Failed to serve connection");
+                    }
+                });
+            }
+        });
+
+        server_handle
+    }
+}
+
+/// A http or ws server that is a [`RateLimitedServer`] only if rate limits are configured.
+///
+/// Otherwise the jsonrpsee [`Server`] is used, because the clients don't need to be identified.
+#[derive(Debug)]
+pub enum MaybeRateLimitedServer<HttpMiddleware, RpcMiddleware> {
+    /// The jsonrpsee server.
+    Server(Server<HttpMiddleware, RpcMiddleware>),
+    /// The server that identifies the clients for the rate limiter.
+    RateLimited(RateLimitedServer<RpcMiddleware, HttpMiddleware>),
+}
+
+impl<HttpMiddleware, RpcMiddleware> MaybeRateLimitedServer<HttpMiddleware, RpcMiddleware> {
+    /// Binds the server configured by the builder to the given address.
+    pub async fn bind(
+        addr: SocketAddr,
+        builder: ServerBuilder<HttpMiddleware, RpcMiddleware>,
+        rate_limiter: RpcMethodRateLimiter,
+    ) -> io::Result<Self> {
+        if rate_limiter.is_empty() {
+            return Ok(Self::Server(builder.build(addr).await?))
+        }
+        let builder = builder.to_service_builder();
+        Ok(Self::RateLimited(RateLimitedServer::bind(addr, builder, rate_limiter).await?))
+    }
+
+    /// Returns the address the server is bound to.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        match self {
+            Self::Server(server) => server.local_addr(),
+            Self::RateLimited(server) => server.local_addr(),
+        }
+    }
+}
+
+/// Http service of a connection of the [`RateLimitedServer`], which identifies the client of each
+/// request for the [`RpcMethodRateLimiter`] and sets the `Retry-After` header if calls of the
+/// request were rejected.
+#[derive(Debug, Clone)]
+pub struct RateLimitClientService<S> {
+    inner: S,
+    /// The address of the connection.
+    peer: IpAddr,
+    rate_limiter: RpcMethodRateLimiter,
+}
+
+impl<S, ReqBody, ResBody> Service<http::Request<ReqBody>> for RateLimitClientService<S>
+where
+    S: Service<http::Request<ReqBody>, Response = http::Response<ResBody>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = RetryAfterFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: http::Request<ReqBody>) -> Self::Future {
+        let retry_after = Arc::new(AtomicU64::new(0));
+        let addr = self.rate_limiter.client_addr(self.peer, req.headers());
+        req.extensions_mut().insert(RateLimitClient { addr, retry_after: retry_after.clone() });
+        RetryAfterFuture { fut: self.inner.call(req), retry_after }
+    }
+}
+
+/// Response future of the [`RateLimitClientService`].
+#[pin_project::pin_project]
+pub struct RetryAfterFuture<F> {
+    #[pin]
+    fut: F,
+    retry_after: Arc<AtomicU64>,
+}
+
+impl<F> std::fmt::Debug for RetryAfterFuture<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("RetryAfterFuture")
+    }
+}
+
+impl<F, B, E> Future for RetryAfterFuture<F>
+where
+    F: Future<Output = Result<http::Response<B>, E>>,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut res = ready!(this.fut.poll(cx));
+        if let Ok(response) = &mut res {
+            let retry_after = this.retry_after.load(Ordering::Relaxed);
+            if retry_after > 0 {
+                response.headers_mut().insert(RETRY_AFTER, HeaderValue::from(retry_after));
+            }
+        }
+        Poll::Ready(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpsee::{
+        core::client::ClientT, rpc_params, ws_client::WsClientBuilder, ConnectionId, RpcModule,
+    };
+    use reth_config::config::MethodRateLimit;
+
+    #[test]
+    fn method_rate_limits() {
+        let limit = |method: &str, rate, burst| MethodRateLimit {
+            method: method.to_string(),
+            limit: RateLimit { rate, burst },
+        };
+        let limiter = RpcMethodRateLimiter::new(RpcRateLimitConfig {
+            global: Some(RateLimit { rate: 10, burst: 3 }),
+            methods: vec![limit("eth_getLogs", 1, 2)],
+            trusted_proxies: vec![],
+        });
+        let alice = Some([10, 0, 0, 1].into());
+        let bob = Some([10, 0, 0, 2].into());
+        let now = Instant::now();
+
+        // each client has its own burst
+        assert!(limiter.check_at("eth_getLogs", alice, now).is_ok());
+        assert!(limiter.check_at("eth_getLogs", alice, now).is_ok());
+        assert_eq!(limiter.check_at("eth_getLogs", alice, now), Err(Duration::from_secs(1)));
+        assert!(limiter.check_at("eth_getLogs", bob, now).is_ok());
+
+        // the global burst is used up
+        assert!(limiter.check_at("eth_blockNumber", bob, now).is_err());
+
+        // the buckets are refilled over time
+        let later = now + Duration::from_secs(1);
+        assert!(limiter.check_at("eth_getLogs", alice, later).is_ok());
+        assert!(limiter.check_at("eth_getLogs", alice, later).is_err());
+    }
+
+    #[test]
+    fn client_addr() {
+        let proxy = IpAddr::from([127, 0, 0, 1]);
+        let limiter = RpcMethodRateLimiter::new(RpcRateLimitConfig {
+            trusted_proxies: vec![proxy],
+            ..Default::default()
+        });
+        let peer = IpAddr::from([10, 0, 0, 1]);
+        let mut headers = HeaderMap::new();
+        assert_eq!(limiter.client_addr(peer, &headers), peer);
+        assert_eq!(limiter.client_addr(proxy, &headers), proxy);
+
+        // the forwarding headers of untrusted peers are ignored
+        headers.insert("x-real-ip", HeaderValue::from_static("10.0.0.2"));
+        assert_eq!(limiter.client_addr(peer, &headers), peer);
+        assert_eq!(limiter.client_addr(proxy, &headers), IpAddr::from([10, 0, 0, 2]));
+
+        // the client is the last forwarded address that is not a trusted proxy
+        headers
+            .insert("x-forwarded-for", HeaderValue::from_static("10.0.0.3, 10.0.0.4, 127.0.0.1"));
+        assert_eq!(limiter.client_addr(peer, &headers), peer);
+        assert_eq!(limiter.client_addr(proxy, &headers), IpAddr::from([10, 0, 0, 4]));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn rate_limited_server_connection_ids() {
+        let mut module = RpcModule::new(());
+        module
+            .register_method("conn_id", |_, _, ext| {
+                ext.get::<ConnectionId>().expect("connection id is set").0
+            })
+            .unwrap();
+        let rate_limiter = RpcMethodRateLimiter::new(RpcRateLimitConfig {
+            global: Some(RateLimit { rate: 100, burst: 100 }),
+            ..Default::default()
+        });
+        let builder = ServerBuilder::default().to_service_builder();
+        let server = RateLimitedServer::bind(([127, 0, 0, 1], 0).into(), builder, rate_limiter)
+            .await
+            .unwrap();
+        let url = format!("ws://{}", server.local_addr().unwrap());
+        let handle = server.start(module);
+
+        // every connection has its own id, which subscriptions are tracked by
+        let first = WsClientBuilder::default().build(&url).await.unwrap();
+        let second = WsClientBuilder::default().build(&url).await.unwrap();
+        let first_id: usize = first.request("conn_id", rpc_params![]).await.unwrap();
+        let second_id: usize = second.request("conn_id", rpc_params![]).await.unwrap();
+        assert_ne!(first_id, second_id);
+        assert_eq!(first.request::<usize, _>("conn_id", rpc_params![]).await.unwrap(), first_id);
+
+        handle.stop().unwrap();
+    }
+}
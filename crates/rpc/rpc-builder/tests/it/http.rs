@@ -5,8 +5,8 @@ use crate::utils::{launch_http, launch_http_ws, launch_ws};
 use alloy_eips::{BlockId, BlockNumberOrTag};
 use alloy_primitives::{hex_literal::hex, Address, Bytes, TxHash, B256, B64, U256, U64};
 use alloy_rpc_types_eth::{
-    transaction::TransactionRequest, Block, FeeHistory, Filter, Index, Log,
-    PendingTransactionFilterKind, SyncStatus, Transaction, TransactionReceipt,
+    transaction::TransactionRequest, Block, EIP1186AccountProofResponse, FeeHistory, Filter, Index,
+    Log, PendingTransactionFilterKind, SyncStatus, Transaction, TransactionReceipt,
 };
 use alloy_rpc_types_trace::filter::TraceFilter;
 use jsonrpsee::{
@@ -1465,6 +1465,27 @@ async fn test_eth_get_storage_at_rpc_call() {
     .await;
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_eth_get_account_proof_rpc_call() {
+    reth_tracing::init_test_tracing();
+
+    // Launch HTTP server with the specified RPC module
+    let handle = launch_http(vec![RethRpcModule::Eth]).await;
+    let client = handle.http_client().unwrap();
+
+    // Requesting the proof of an account with the alias of `eth_getProof`
+    test_rpc_call_ok::<EIP1186AccountProofResponse>(
+        &client,
+        "eth_getAccountProof",
+        rpc_params![
+            "0x295a70b2de5e3953354a6a8344e616ed314d7251", // Address
+            ["0x0"],                                      // Storage keys
+            "latest"                                      // Block number or tag
+        ],
+    )
+    .await;
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_eth_get_transaction_count_rpc_call() {
     reth_tracing::init_test_tracing();
@@ -351,7 +351,7 @@ pub trait EthApi<T: RpcObject, B: RpcObject, R: RpcObject> {
 
     /// Returns the account and storage values of the specified account including the Merkle-proof.
     /// This call can be used to verify that the data you are pulling from is not tampered with.
-    #[method(name = "getProof")]
+    #[method(name = "getProof", aliases = ["eth_getAccountProof"])]
     async fn get_proof(
         &self,
         address: Address,
@@ -789,7 +789,7 @@ where
         Ok(EthTransactions::sign_typed_data(self, &data, address)?)
     }
 
-    /// Handler for: `eth_getProof`
+    /// Handler for: `eth_getProof` and `eth_getAccountProof`
     async fn get_proof(
         &self,
         address: Address,
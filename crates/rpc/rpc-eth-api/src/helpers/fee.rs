@@ -4,10 +4,10 @@ use alloy_primitives::U256;
 use alloy_rpc_types::{BlockNumberOrTag, FeeHistory};
 use futures::Future;
 use reth_chainspec::EthChainSpec;
-use reth_provider::{BlockIdReader, ChainSpecProvider, HeaderProvider};
+use reth_provider::{BlockIdReader, BlockNumReader, ChainSpecProvider, HeaderProvider};
 use reth_rpc_eth_types::{
     fee_history::calculate_reward_percentiles_for_block, EthApiError, FeeHistoryCache,
-    FeeHistoryEntry, GasPriceOracle, RpcInvalidTransactionError,
+    FeeHistoryEntry, FeeHistoryResultKey, GasPriceOracle, RpcInvalidTransactionError,
 };
 use tracing::debug;
 
@@ -112,6 +112,17 @@ pub trait EthFees: LoadFee {
             // NOTE: We ensured that block count is capped
             let start_block = end_block_plus - block_count;
 
+            // the result for a range below the head can't change anymore, so it's cached
+            let head = self.provider().best_block_number().map_err(Self::Error::from_eth_err)?;
+            let cache_key = (end_block_plus < head).then(|| {
+                FeeHistoryResultKey::new(start_block, block_count, reward_percentiles.as_deref())
+            });
+            if let Some(fee_history) =
+                cache_key.as_ref().and_then(|key| self.fee_history_cache().get_result(key))
+            {
+                return Ok(fee_history)
+            }
+
             // Collect base fees, gas usage ratios and (optionally) reward percentile data
             let mut base_fee_per_gas: Vec<u128> = Vec::new();
             let mut gas_used_ratio: Vec<f64> = Vec::new();
@@ -212,14 +223,19 @@ pub trait EthFees: LoadFee {
                 base_fee_per_blob_gas.push(last_header.next_block_blob_fee().unwrap_or_default());
             };
 
-            Ok(FeeHistory {
+            let fee_history = FeeHistory {
                 base_fee_per_gas,
                 gas_used_ratio,
                 base_fee_per_blob_gas,
                 blob_gas_used_ratio,
                 oldest_block: start_block,
                 reward: reward_percentiles.map(|_| rewards),
-            })
+            };
+            if let Some(key) = cache_key {
+                self.fee_history_cache().insert_result(key, fee_history.clone());
+            }
+
+            Ok(fee_history)
         }
     }
 
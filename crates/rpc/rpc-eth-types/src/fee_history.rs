@@ -2,23 +2,28 @@
 
 use std::{
     collections::{BTreeMap, VecDeque},
-    fmt::Debug,
+    fmt::{self, Debug, Formatter},
     sync::{atomic::Ordering::SeqCst, Arc},
+    time::{Duration, Instant},
 };
 
 use alloy_eips::eip1559::calc_next_block_base_fee;
 use alloy_primitives::B256;
-use alloy_rpc_types::TxGasAndReward;
+use alloy_rpc_types::{FeeHistory, TxGasAndReward};
+use derive_more::{Deref, DerefMut};
 use futures::{
     future::{Fuse, FusedFuture},
     FutureExt, Stream, StreamExt,
 };
-use metrics::atomics::AtomicU64;
+use metrics::{atomics::AtomicU64, Counter};
+use parking_lot::Mutex;
 use reth_chain_state::CanonStateNotification;
 use reth_chainspec::{ChainSpecProvider, EthChainSpec};
+use reth_metrics::Metrics;
 use reth_primitives::{Receipt, SealedBlock, TransactionSigned};
 use reth_storage_api::BlockReaderIdExt;
 use revm_primitives::{calc_blob_gasprice, calc_excess_blob_gas};
+use schnellru::{ByLength, LruMap};
 use serde::{Deserialize, Serialize};
 use tracing::trace;
 
@@ -26,6 +31,15 @@ use reth_rpc_server_types::constants::gas_oracle::MAX_HEADER_HISTORY;
 
 use super::{EthApiError, EthStateCache};
 
+/// The maximum number of `eth_feeHistory` results that are cached by the [`FeeHistoryCache`].
+pub const MAX_CACHED_FEE_HISTORY_RESULTS: u32 = 1000;
+
+/// How long an `eth_feeHistory` result is cached by the [`FeeHistoryCache`].
+///
+/// Cached results are cleared on reorgs, this bounds how long a result can be stale if a reorg
+/// below the head isn't announced, e.g. when the node unwinds during a pipeline sync.
+pub const FEE_HISTORY_RESULT_TTL: Duration = Duration::from_secs(5 * 60);
+
 /// Contains cached fee history entries for blocks.
 ///
 /// Purpose for this is to provide cached data for `eth_feeHistory`.
@@ -42,7 +56,11 @@ impl FeeHistoryCache {
             upper_bound: Default::default(),
             config,
             entries: Default::default(),
+            results: Mutex::new(FeeHistoryResultLruCache(LruMap::new(ByLength::new(
+                MAX_CACHED_FEE_HISTORY_RESULTS,
+            )))),
             eth_cache,
+            metrics: Default::default(),
         };
         Self { inner: Arc::new(inner) }
     }
@@ -158,6 +176,43 @@ impl FeeHistoryCache {
         }
     }
 
+    /// Returns the cached `eth_feeHistory` result for the given request, if any.
+    ///
+    /// See also [`FeeHistoryCache::insert_result`].
+    pub fn get_result(&self, key: &FeeHistoryResultKey) -> Option<FeeHistory> {
+        let mut results = self.inner.results.lock();
+        let result = match results.get(key) {
+            Some((cached_at, _)) if cached_at.elapsed() > FEE_HISTORY_RESULT_TTL => {
+                results.remove(key);
+                None
+            }
+            Some((_, result)) => Some(result.clone()),
+            None => None,
+        };
+        drop(results);
+
+        if result.is_some() {
+            self.inner.metrics.fee_history_cache_hits_total.increment(1);
+        } else {
+            self.inner.metrics.fee_history_cache_misses_total.increment(1);
+        }
+        result
+    }
+
+    /// Caches the `eth_feeHistory` result for the given request.
+    ///
+    /// The result must not change anymore, so only results for block ranges that are well below
+    /// the current head should be cached. The result expires after [`FEE_HISTORY_RESULT_TTL`], or
+    /// when a reorg is announced, see [`fee_history_cache_new_blocks_task`].
+    pub fn insert_result(&self, key: FeeHistoryResultKey, result: FeeHistory) {
+        self.inner.results.lock().insert(key, (Instant::now(), result));
+    }
+
+    /// Removes all cached `eth_feeHistory` results, e.g. because blocks were reorged.
+    pub fn clear_results(&self) {
+        self.inner.results.lock().clear();
+    }
+
     /// Generates predefined set of percentiles
     ///
     /// This returns 100 * resolution points
@@ -200,7 +255,59 @@ struct FeeHistoryCacheInner {
     config: FeeHistoryCacheConfig,
     /// Stores the entries of the cache
     entries: tokio::sync::RwLock<BTreeMap<u64, FeeHistoryEntry>>,
+    /// Stores the `eth_feeHistory` results for immutable block ranges
+    results: Mutex<FeeHistoryResultLruCache>,
     eth_cache: EthStateCache,
+    /// Metrics for the cached `eth_feeHistory` results
+    metrics: FeeHistoryCacheMetrics,
+}
+
+/// Identifies a cached `eth_feeHistory` result, see [`FeeHistoryCache::get_result`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FeeHistoryResultKey {
+    /// The oldest block of the requested range.
+    oldest_block: u64,
+    /// The number of blocks in the requested range.
+    block_count: u64,
+    /// The bit patterns of the requested reward percentiles.
+    reward_percentiles: Option<Vec<u64>>,
+}
+
+impl FeeHistoryResultKey {
+    /// Creates the key for the `eth_feeHistory` request of `block_count` blocks starting at
+    /// `oldest_block`, with the given reward percentiles.
+    pub fn new(oldest_block: u64, block_count: u64, reward_percentiles: Option<&[f64]>) -> Self {
+        Self {
+            oldest_block,
+            block_count,
+            reward_percentiles: reward_percentiles
+                .map(|percentiles| percentiles.iter().map(|p| p.to_bits()).collect()),
+        }
+    }
+}
+
+/// Wrapper struct for `LruMap`
+#[derive(Deref, DerefMut)]
+struct FeeHistoryResultLruCache(LruMap<FeeHistoryResultKey, (Instant, FeeHistory), ByLength>);
+
+impl Debug for FeeHistoryResultLruCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FeeHistoryResultLruCache")
+            .field("cache_length", &self.len())
+            .field("cache_memory_usage", &self.memory_usage())
+            .finish()
+    }
+}
+
+/// Metrics for the `eth_feeHistory` results cached by the [`FeeHistoryCache`].
+#[derive(Metrics)]
+#[metrics(scope = "eth")]
+struct FeeHistoryCacheMetrics {
+    /// The number of `eth_feeHistory` requests that were served from the cache.
+    fee_history_cache_hits_total: Counter,
+    /// The number of cacheable `eth_feeHistory` requests that were not cached yet, or whose
+    /// cached result expired.
+    fee_history_cache_misses_total: Counter,
 }
 
 /// Awaits for new chain events and directly inserts them into the cache so they're available
@@ -248,6 +355,11 @@ pub async fn fee_history_cache_new_blocks_task<St, Provider>(
                     break;
                 };
 
+                if event.reverted().is_some() {
+                    // cached results may include reverted blocks
+                    fee_history_cache.clear_results();
+                }
+
                 let committed = event .committed();
                 let (blocks, receipts): (Vec<_>, Vec<_>) = committed
                     .blocks_and_receipts()
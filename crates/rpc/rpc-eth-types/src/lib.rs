@@ -31,7 +31,9 @@ pub use cache::{
     EthStateCache,
 };
 pub use error::{EthApiError, EthResult, RevertError, RpcInvalidTransactionError, SignError};
-pub use fee_history::{FeeHistoryCache, FeeHistoryCacheConfig, FeeHistoryEntry};
+pub use fee_history::{
+    FeeHistoryCache, FeeHistoryCacheConfig, FeeHistoryEntry, FeeHistoryResultKey,
+};
 pub use gas_oracle::{
     GasCap, GasPriceOracle, GasPriceOracleConfig, GasPriceOracleResult, RPC_DEFAULT_GAS_CAP,
 };
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 
 use alloy_genesis::ChainConfig;
 use alloy_rpc_types_admin::{
@@ -12,31 +12,35 @@ use reth_network_api::{NetworkInfo, Peers};
 use reth_network_peers::{id2pk, AnyNode, NodeRecord};
 use reth_network_types::PeerKind;
 use reth_primitives::EthereumHardfork;
+use reth_provider::{TableSizeEstimate, TableSizeProvider};
 use reth_rpc_api::AdminApiServer;
 use reth_rpc_server_types::ToRpcResult;
 
 /// `admin` API implementation.
 ///
 /// This type provides the functionality for handling `admin` related requests.
-pub struct AdminApi<N, ChainSpec> {
+pub struct AdminApi<N, ChainSpec, Provider> {
     /// An interface to interact with the network
     network: N,
     /// The specification of the blockchain's configuration.
     chain_spec: Arc<ChainSpec>,
+    /// The provider used to report database statistics.
+    provider: Provider,
 }
 
-impl<N, ChainSpec> AdminApi<N, ChainSpec> {
+impl<N, ChainSpec, Provider> AdminApi<N, ChainSpec, Provider> {
     /// Creates a new instance of `AdminApi`.
-    pub const fn new(network: N, chain_spec: Arc<ChainSpec>) -> Self {
-        Self { network, chain_spec }
+    pub const fn new(network: N, chain_spec: Arc<ChainSpec>, provider: Provider) -> Self {
+        Self { network, chain_spec, provider }
     }
 }
 
 #[async_trait]
-impl<N, ChainSpec> AdminApiServer for AdminApi<N, ChainSpec>
+impl<N, ChainSpec, Provider> AdminApiServer for AdminApi<N, ChainSpec, Provider>
 where
     N: NetworkInfo + Peers + 'static,
     ChainSpec: EthChainSpec + EthereumHardforks + Send + Sync + 'static,
+    Provider: TableSizeProvider + 'static,
 {
     /// Handler for `admin_addPeer`
     fn add_peer(&self, record: NodeRecord) -> RpcResult<bool> {
@@ -179,6 +183,11 @@ where
         })
     }
 
+    /// Handler for `admin_dbStats`
+    fn db_stats(&self) -> RpcResult<BTreeMap<String, TableSizeEstimate>> {
+        self.provider.table_sizes().to_rpc_result()
+    }
+
     /// Handler for `admin_peerEvents`
     async fn subscribe_peer_events(
         &self,
@@ -188,7 +197,7 @@ where
     }
 }
 
-impl<N, ChainSpec> std::fmt::Debug for AdminApi<N, ChainSpec> {
+impl<N, ChainSpec, Provider> std::fmt::Debug for AdminApi<N, ChainSpec, Provider> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("AdminApi").finish_non_exhaustive()
     }
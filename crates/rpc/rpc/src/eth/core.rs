@@ -414,7 +414,7 @@ mod tests {
     };
     use reth_rpc_eth_api::EthApiServer;
     use reth_rpc_eth_types::{
-        EthStateCache, FeeHistoryCache, FeeHistoryCacheConfig, GasPriceOracle,
+        EthStateCache, FeeHistoryCache, FeeHistoryCacheConfig, FeeHistoryResultKey, GasPriceOracle,
     };
     use reth_rpc_server_types::constants::{
         DEFAULT_ETH_PROOF_WINDOW, DEFAULT_MAX_SIMULATE_BLOCKS, DEFAULT_PROOF_PERMITS,
@@ -703,4 +703,34 @@ mod tests {
             "all: no percentiles were requested, so there should be no rewards result"
         );
     }
+
+    /// Results for ranges below the head are cached
+    #[tokio::test]
+    async fn test_fee_history_cached_results() {
+        let block_count = 10;
+        let newest_block = 1337;
+        let oldest_block = None;
+
+        let (eth_api, _, _) =
+            prepare_eth_api(newest_block, oldest_block, block_count, MockEthProvider::default());
+
+        let fee_history =
+            eth_api.fee_history(U64::from(2), (newest_block - 2).into(), None).await.unwrap();
+        let key = FeeHistoryResultKey::new(newest_block - 3, 2, None);
+        assert_eq!(eth_api.fee_history_cache().get_result(&key), Some(fee_history));
+
+        // results with reward percentiles are cached separately
+        let key = FeeHistoryResultKey::new(newest_block - 3, 2, Some(&[10.0, 50.0]));
+        assert_eq!(eth_api.fee_history_cache().get_result(&key), None);
+
+        // ranges that include the blocks right below the head are not cached
+        eth_api.fee_history(U64::from(2), (newest_block - 1).into(), None).await.unwrap();
+        let key = FeeHistoryResultKey::new(newest_block - 2, 2, None);
+        assert_eq!(eth_api.fee_history_cache().get_result(&key), None);
+
+        // cached results are cleared on reorgs
+        eth_api.fee_history_cache().clear_results();
+        let key = FeeHistoryResultKey::new(newest_block - 3, 2, None);
+        assert_eq!(eth_api.fee_history_cache().get_result(&key), None);
+    }
 }
@@ -10,7 +10,7 @@ use jsonrpsee::core::RpcResult as Result;
 use reth_primitives::TransactionSignedEcRecovered;
 use reth_rpc_api::TxPoolApiServer;
 use reth_rpc_types_compat::{transaction::from_recovered, TransactionCompat};
-use reth_transaction_pool::{AllPoolTransactions, PoolTransaction, TransactionPool};
+use reth_transaction_pool::{AllPoolTransactions, PoolSnapshot, PoolTransaction, TransactionPool};
 use tracing::trace;
 
 /// `txpool` API implementation.
@@ -145,6 +145,14 @@ where
         trace!(target: "rpc::eth", "Serving txpool_content");
         Ok(self.content())
     }
+
+    /// Returns a snapshot of all transactions in the txpool.
+    ///
+    /// Handler for `txpool_snapshot`
+    async fn txpool_snapshot(&self) -> Result<PoolSnapshot> {
+        trace!(target: "rpc::eth", "Serving txpool_snapshot");
+        Ok(self.pool.export_snapshot())
+    }
 }
 
 impl<Pool, Eth> std::fmt::Debug for TxPoolApi<Pool, Eth> {
@@ -3,6 +3,7 @@ use crate::{
     transaction::{DbTx, DbTxMut},
     DatabaseError,
 };
+use serde::{Deserialize, Serialize};
 use std::{fmt::Debug, sync::Arc};
 
 /// Main Database trait that can open read-only and read-write transactions.
@@ -22,6 +23,16 @@ pub trait Database: Send + Sync + Debug {
     #[track_caller]
     fn tx_mut(&self) -> Result<Self::TXMut, DatabaseError>;
 
+    /// Returns an estimate of the storage size of the table with the given name.
+    ///
+    /// The estimate is read from the metadata the database keeps for the table and does not scan
+    /// the table.
+    ///
+    /// Returns an error by default, for databases that don't keep such metadata.
+    fn estimate_table_size(&self, table: &str) -> Result<TableSizeEstimate, DatabaseError> {
+        Err(DatabaseError::Other(format!("table size estimate of {table} is not supported")))
+    }
+
     /// Takes a function and passes a read-only transaction into it, making sure it's closed in the
     /// end of the execution.
     fn view<T, F>(&self, f: F) -> Result<T, DatabaseError>
@@ -62,6 +73,10 @@ impl<DB: Database> Database for Arc<DB> {
     fn tx_mut(&self) -> Result<Self::TXMut, DatabaseError> {
         <DB as Database>::tx_mut(self)
     }
+
+    fn estimate_table_size(&self, table: &str) -> Result<TableSizeEstimate, DatabaseError> {
+        <DB as Database>::estimate_table_size(self, table)
+    }
 }
 
 impl<DB: Database> Database for &DB {
@@ -75,4 +90,22 @@ impl<DB: Database> Database for &DB {
     fn tx_mut(&self) -> Result<Self::TXMut, DatabaseError> {
         <DB as Database>::tx_mut(self)
     }
+
+    fn estimate_table_size(&self, table: &str) -> Result<TableSizeEstimate, DatabaseError> {
+        <DB as Database>::estimate_table_size(self, table)
+    }
+}
+
+/// An estimate of the storage size of a database table, see [`Database::estimate_table_size`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableSizeEstimate {
+    /// The number of entries in the table.
+    pub row_count: u64,
+    /// The size of the pages that store the entries of the table, in bytes.
+    pub data_bytes: u64,
+    /// The size of the pages that index the entries of the table, in bytes.
+    pub index_bytes: u64,
+    /// The total size of the table, in bytes.
+    pub total_bytes: u64,
 }
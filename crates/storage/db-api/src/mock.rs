@@ -6,7 +6,7 @@ use crate::{
         DbCursorRO, DbCursorRW, DbDupCursorRO, DbDupCursorRW, DupWalker, RangeWalker,
         ReverseWalker, Walker,
     },
-    database::Database,
+    database::{Database, TableSizeEstimate},
     table::{DupSort, Table, TableImporter},
     transaction::{DbTx, DbTxMut},
     DatabaseError,
@@ -32,6 +32,10 @@ impl Database for DatabaseMock {
     fn tx_mut(&self) -> Result<Self::TXMut, DatabaseError> {
         Ok(TxMock::default())
     }
+
+    fn estimate_table_size(&self, _table: &str) -> Result<TableSizeEstimate, DatabaseError> {
+        Ok(TableSizeEstimate::default())
+    }
 }
 
 /// Mock read only tx
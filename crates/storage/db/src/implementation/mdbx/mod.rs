@@ -11,14 +11,14 @@ use eyre::Context;
 use metrics::{gauge, Label};
 use reth_db_api::{
     cursor::{DbCursorRO, DbCursorRW},
-    database::Database,
+    database::{Database, TableSizeEstimate},
     database_metrics::{DatabaseMetadata, DatabaseMetadataValue, DatabaseMetrics},
     models::ClientVersion,
     transaction::{DbTx, DbTxMut},
 };
 use reth_libmdbx::{
     ffi, DatabaseFlags, Environment, EnvironmentFlags, Geometry, HandleSlowReadersReturnCode,
-    MaxReadTransactionDuration, Mode, PageSize, SyncMode, RO, RW,
+    MaxReadTransactionDuration, Mode, PageSize, Stat, SyncMode, RO, RW,
 };
 use reth_storage_errors::db::LogLevel;
 use reth_tracing::tracing::error;
@@ -189,6 +189,28 @@ impl Database for DatabaseEnv {
         )
         .map_err(|e| DatabaseError::InitTx(e.into()))
     }
+
+    fn estimate_table_size(&self, table: &str) -> Result<TableSizeEstimate, DatabaseError> {
+        let tx = self.inner.begin_ro_txn().map_err(|e| DatabaseError::InitTx(e.into()))?;
+        let table_db = tx.open_db(Some(table)).map_err(|e| DatabaseError::Open(e.into()))?;
+        let stats = tx.db_stat(&table_db).map_err(|e| DatabaseError::Stats(e.into()))?;
+        Ok(table_size_estimate(&stats))
+    }
+}
+
+/// Computes the size of a table from its page stats.
+///
+/// Leaf and overflow pages count as data, branch pages count as index.
+fn table_size_estimate(stats: &Stat) -> TableSizeEstimate {
+    let page_size = stats.page_size() as u64;
+    let data_bytes = (stats.leaf_pages() + stats.overflow_pages()) as u64 * page_size;
+    let index_bytes = stats.branch_pages() as u64 * page_size;
+    TableSizeEstimate {
+        row_count: stats.entries() as u64,
+        data_bytes,
+        index_bytes,
+        total_bytes: data_bytes + index_bytes,
+    }
 }
 
 impl DatabaseMetrics for DatabaseEnv {
@@ -211,17 +233,14 @@ impl DatabaseMetrics for DatabaseEnv {
                         .db_stat(&table_db)
                         .wrap_err(format!("Could not find table: {table}"))?;
 
-                    let page_size = stats.page_size() as usize;
                     let leaf_pages = stats.leaf_pages();
                     let branch_pages = stats.branch_pages();
                     let overflow_pages = stats.overflow_pages();
-                    let num_pages = leaf_pages + branch_pages + overflow_pages;
-                    let table_size = page_size * num_pages;
-                    let entries = stats.entries();
+                    let estimate = table_size_estimate(&stats);
 
                     metrics.push((
                         "db.table_size",
-                        table_size as f64,
+                        estimate.total_bytes as f64,
                         vec![Label::new("table", table)],
                     ));
                     metrics.push((
@@ -241,7 +260,7 @@ impl DatabaseMetrics for DatabaseEnv {
                     ));
                     metrics.push((
                         "db.table_entries",
-                        entries as f64,
+                        estimate.row_count as f64,
                         vec![Label::new("table", table)],
                     ));
                 }
@@ -561,6 +580,25 @@ mod tests {
         tx.commit().expect(ERROR_COMMIT);
     }
 
+    #[test]
+    fn db_estimate_table_size() {
+        let env = create_test_db(DatabaseEnvKind::RW);
+
+        let estimate = env.estimate_table_size(Headers::NAME).unwrap();
+        assert_eq!(estimate, TableSizeEstimate::default());
+
+        let tx = env.tx_mut().expect(ERROR_INIT_TX);
+        for key in 0..10u64 {
+            tx.put::<Headers>(key, Header::default()).expect(ERROR_PUT);
+        }
+        tx.commit().expect(ERROR_COMMIT);
+
+        let estimate = env.estimate_table_size(Headers::NAME).unwrap();
+        assert_eq!(estimate.row_count, 10);
+        assert!(estimate.data_bytes > 0);
+        assert_eq!(estimate.total_bytes, estimate.data_bytes + estimate.index_bytes);
+    }
+
     #[test]
     fn db_dup_cursor_delete_first() {
         let db: Arc<DatabaseEnv> = create_test_db(DatabaseEnvKind::RW);
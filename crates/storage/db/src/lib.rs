@@ -46,7 +46,7 @@ pub mod test_utils {
     use crate::mdbx::DatabaseArguments;
     use parking_lot::RwLock;
     use reth_db_api::{
-        database::Database,
+        database::{Database, TableSizeEstimate},
         database_metrics::{DatabaseMetadata, DatabaseMetadataValue, DatabaseMetrics},
         models::ClientVersion,
     };
@@ -147,6 +147,10 @@ pub mod test_utils {
         fn tx_mut(&self) -> Result<Self::TXMut, DatabaseError> {
             self.db().tx_mut()
         }
+
+        fn estimate_table_size(&self, table: &str) -> Result<TableSizeEstimate, DatabaseError> {
+            self.db().estimate_table_size(table)
+        }
     }
 
     impl<DB: DatabaseMetrics> DatabaseMetrics for TempDatabase<DB> {
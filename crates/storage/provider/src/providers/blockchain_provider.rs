@@ -7,7 +7,8 @@ use crate::{
     DatabaseProviderFactory, EvmEnvProvider, FullProvider, HeaderProvider, ProviderError,
     ProviderFactory, PruneCheckpointReader, ReceiptProvider, ReceiptProviderIdExt,
     StageCheckpointReader, StateProviderBox, StateProviderFactory, StateReader,
-    StaticFileProviderFactory, TransactionVariant, TransactionsProvider, WithdrawalsProvider,
+    StaticFileProviderFactory, TableSizeProvider, TransactionVariant, TransactionsProvider,
+    WithdrawalsProvider,
 };
 use alloy_eips::{eip4895::Withdrawal, BlockHashOrNumber, BlockId, BlockNumHash, BlockNumberOrTag};
 use alloy_primitives::{Address, BlockHash, BlockNumber, Sealable, TxHash, TxNumber, B256, U256};
@@ -18,7 +19,10 @@ use reth_chain_state::{
 };
 use reth_chainspec::{ChainInfo, EthereumHardforks};
 use reth_db::{models::BlockNumberAddress, transaction::DbTx, Database};
-use reth_db_api::models::{AccountBeforeTx, StoredBlockBodyIndices};
+use reth_db_api::{
+    database::TableSizeEstimate,
+    models::{AccountBeforeTx, StoredBlockBodyIndices},
+};
 use reth_evm::ConfigureEvmEnv;
 use reth_execution_types::ExecutionOutcome;
 use reth_node_types::NodeTypesWithDB;
@@ -33,6 +37,7 @@ use reth_storage_api::{DBProvider, StorageChangeSetReader};
 use reth_storage_errors::provider::ProviderResult;
 use revm::primitives::{BlockEnv, CfgEnvWithHandlerCfg};
 use std::{
+    collections::BTreeMap,
     ops::{Add, RangeBounds, RangeInclusive, Sub},
     sync::Arc,
     time::Instant,
@@ -444,6 +449,12 @@ impl<N: ProviderNodeTypes> StageCheckpointReader for BlockchainProvider2<N> {
     }
 }
 
+impl<N: ProviderNodeTypes> TableSizeProvider for BlockchainProvider2<N> {
+    fn table_sizes(&self) -> ProviderResult<BTreeMap<String, TableSizeEstimate>> {
+        self.database.table_sizes()
+    }
+}
+
 impl<N: ProviderNodeTypes> EvmEnvProvider for BlockchainProvider2<N> {
     fn fill_env_at<EvmConfig>(
         &self,
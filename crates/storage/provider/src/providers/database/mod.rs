@@ -5,14 +5,17 @@ use crate::{
     BlockHashReader, BlockNumReader, BlockReader, ChainSpecProvider, DatabaseProviderFactory,
     EvmEnvProvider, HeaderProvider, HeaderSyncGap, HeaderSyncGapProvider, ProviderError,
     PruneCheckpointReader, StageCheckpointReader, StateProviderBox, StaticFileProviderFactory,
-    TransactionVariant, TransactionsProvider, WithdrawalsProvider,
+    TableSizeProvider, TransactionVariant, TransactionsProvider, WithdrawalsProvider,
 };
 use alloy_eips::{eip4895::Withdrawal, BlockHashOrNumber};
 use alloy_primitives::{Address, BlockHash, BlockNumber, TxHash, TxNumber, B256, U256};
 use core::fmt;
 use reth_chainspec::{ChainInfo, EthereumHardforks};
-use reth_db::{init_db, mdbx::DatabaseArguments, DatabaseEnv};
-use reth_db_api::{database::Database, models::StoredBlockBodyIndices};
+use reth_db::{init_db, mdbx::DatabaseArguments, DatabaseEnv, Tables};
+use reth_db_api::{
+    database::{Database, TableSizeEstimate},
+    models::StoredBlockBodyIndices,
+};
 use reth_errors::{RethError, RethResult};
 use reth_evm::ConfigureEvmEnv;
 use reth_node_types::NodeTypesWithDB;
@@ -26,6 +29,7 @@ use reth_storage_api::TryIntoHistoricalStateProvider;
 use reth_storage_errors::provider::ProviderResult;
 use revm::primitives::{BlockEnv, CfgEnvWithHandlerCfg};
 use std::{
+    collections::BTreeMap,
     ops::{RangeBounds, RangeInclusive},
     path::Path,
     sync::Arc,
@@ -531,6 +535,15 @@ impl<N: ProviderNodeTypes> StageCheckpointReader for ProviderFactory<N> {
     }
 }
 
+impl<N: ProviderNodeTypes> TableSizeProvider for ProviderFactory<N> {
+    fn table_sizes(&self) -> ProviderResult<BTreeMap<String, TableSizeEstimate>> {
+        Tables::ALL
+            .iter()
+            .map(|table| Ok((table.name().to_string(), self.db.estimate_table_size(table.name())?)))
+            .collect()
+    }
+}
+
 impl<N: ProviderNodeTypes> EvmEnvProvider for ProviderFactory<N> {
     fn fill_env_at<EvmConfig>(
         &self,
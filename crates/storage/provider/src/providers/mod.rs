@@ -5,7 +5,7 @@ use crate::{
     DatabaseProviderFactory, EvmEnvProvider, FullExecutionDataProvider, HeaderProvider,
     ProviderError, PruneCheckpointReader, ReceiptProvider, ReceiptProviderIdExt,
     StageCheckpointReader, StateProviderBox, StateProviderFactory, StaticFileProviderFactory,
-    TransactionVariant, TransactionsProvider, TreeViewer, WithdrawalsProvider,
+    TableSizeProvider, TransactionVariant, TransactionsProvider, TreeViewer, WithdrawalsProvider,
 };
 use alloy_eips::{eip4895::Withdrawal, BlockHashOrNumber, BlockId, BlockNumHash, BlockNumberOrTag};
 use alloy_primitives::{Address, BlockHash, BlockNumber, Sealable, TxHash, TxNumber, B256, U256};
@@ -16,7 +16,10 @@ use reth_blockchain_tree_api::{
 };
 use reth_chain_state::{ChainInfoTracker, ForkChoiceNotifications, ForkChoiceSubscriptions};
 use reth_chainspec::{ChainInfo, EthereumHardforks};
-use reth_db_api::models::{AccountBeforeTx, StoredBlockBodyIndices};
+use reth_db_api::{
+    database::TableSizeEstimate,
+    models::{AccountBeforeTx, StoredBlockBodyIndices},
+};
 use reth_evm::ConfigureEvmEnv;
 use reth_node_types::NodeTypesWithDB;
 use reth_primitives::{
@@ -520,6 +523,12 @@ impl<N: ProviderNodeTypes> StageCheckpointReader for BlockchainProvider<N> {
     }
 }
 
+impl<N: ProviderNodeTypes> TableSizeProvider for BlockchainProvider<N> {
+    fn table_sizes(&self) -> ProviderResult<BTreeMap<String, TableSizeEstimate>> {
+        self.database.table_sizes()
+    }
+}
+
 impl<N: ProviderNodeTypes> EvmEnvProvider for BlockchainProvider<N> {
     fn fill_env_at<EvmConfig>(
         &self,
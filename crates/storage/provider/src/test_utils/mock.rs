@@ -16,7 +16,10 @@ use alloy_primitives::{
 use parking_lot::Mutex;
 use reth_chainspec::{ChainInfo, ChainSpec};
 use reth_db::mock::{DatabaseMock, TxMock};
-use reth_db_api::models::{AccountBeforeTx, StoredBlockBodyIndices};
+use reth_db_api::{
+    database::TableSizeEstimate,
+    models::{AccountBeforeTx, StoredBlockBodyIndices},
+};
 use reth_evm::ConfigureEvmEnv;
 use reth_execution_types::ExecutionOutcome;
 use reth_node_types::NodeTypes;
@@ -28,6 +31,7 @@ use reth_primitives::{
 use reth_stages_types::{StageCheckpoint, StageId};
 use reth_storage_api::{
     DatabaseProviderFactory, StageCheckpointReader, StateProofProvider, StorageRootProvider,
+    TableSizeProvider,
 };
 use reth_storage_errors::provider::{ConsistentViewError, ProviderError, ProviderResult};
 use reth_trie::{
@@ -608,6 +612,12 @@ impl StageCheckpointReader for MockEthProvider {
     }
 }
 
+impl TableSizeProvider for MockEthProvider {
+    fn table_sizes(&self) -> ProviderResult<BTreeMap<String, TableSizeEstimate>> {
+        Ok(BTreeMap::new())
+    }
+}
+
 impl StateRootProvider for MockEthProvider {
     fn state_root(&self, _state: HashedPostState) -> ProviderResult<B256> {
         Ok(self.state_roots.lock().pop().unwrap_or_default())
@@ -1,4 +1,5 @@
 use std::{
+    collections::BTreeMap,
     ops::{RangeBounds, RangeInclusive},
     path::PathBuf,
     sync::Arc,
@@ -14,7 +15,10 @@ use reth_chain_state::{
     ForkChoiceSubscriptions,
 };
 use reth_chainspec::{ChainInfo, ChainSpec, MAINNET};
-use reth_db_api::models::{AccountBeforeTx, StoredBlockBodyIndices};
+use reth_db_api::{
+    database::TableSizeEstimate,
+    models::{AccountBeforeTx, StoredBlockBodyIndices},
+};
 use reth_errors::ProviderError;
 use reth_evm::ConfigureEvmEnv;
 use reth_primitives::{
@@ -24,7 +28,7 @@ use reth_primitives::{
 };
 use reth_prune_types::{PruneCheckpoint, PruneSegment};
 use reth_stages_types::{StageCheckpoint, StageId};
-use reth_storage_api::{StateProofProvider, StorageRootProvider};
+use reth_storage_api::{StateProofProvider, StorageRootProvider, TableSizeProvider};
 use reth_storage_errors::provider::ProviderResult;
 use reth_trie::{
     updates::TrieUpdates, AccountProof, HashedPostState, HashedStorage, MultiProof, TrieInput,
@@ -526,6 +530,12 @@ impl StageCheckpointReader for NoopProvider {
     }
 }
 
+impl TableSizeProvider for NoopProvider {
+    fn table_sizes(&self) -> ProviderResult<BTreeMap<String, TableSizeEstimate>> {
+        Ok(BTreeMap::new())
+    }
+}
+
 impl WithdrawalsProvider for NoopProvider {
     fn withdrawals_by_block(
         &self,
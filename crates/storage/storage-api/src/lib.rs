@@ -52,4 +52,7 @@ pub use withdrawals::*;
 mod database_provider;
 pub use database_provider::*;
 
+mod table_size;
+pub use table_size::*;
+
 pub mod noop;
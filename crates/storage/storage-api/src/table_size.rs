@@ -0,0 +1,13 @@
+use reth_storage_errors::provider::ProviderResult;
+use std::collections::BTreeMap;
+
+pub use reth_db_api::database::TableSizeEstimate;
+
+/// The trait for reporting the storage size of the database tables.
+#[auto_impl::auto_impl(&, Arc)]
+pub trait TableSizeProvider: Send + Sync {
+    /// Returns an estimate of the storage size of every database table, by table name.
+    ///
+    /// See [`Database::estimate_table_size`](reth_db_api::database::Database::estimate_table_size).
+    fn table_sizes(&self) -> ProviderResult<BTreeMap<String, TableSizeEstimate>>;
+}
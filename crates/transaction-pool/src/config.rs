@@ -27,6 +27,9 @@ pub const DEFAULT_PRICE_BUMP: u128 = 10;
 /// This enforces that a blob transaction requires a 100% price bump to be replaced
 pub const REPLACE_BLOB_PRICE_BUMP: u128 = 100;
 
+/// The default maximum number of pinned transactions.
+pub const DEFAULT_MAX_PINNED_TRANSACTIONS: usize = 100;
+
 /// Configuration options for the Transaction pool.
 #[derive(Debug, Clone)]
 pub struct PoolConfig {
@@ -53,6 +56,9 @@ pub struct PoolConfig {
     pub pending_tx_listener_buffer_size: usize,
     /// Bound on number of new transactions from `reth_network::TransactionsManager` to buffer.
     pub new_tx_listener_buffer_size: usize,
+    /// Max number of transactions that can be pinned, see
+    /// [`TransactionPool::pin_transaction`](crate::TransactionPool::pin_transaction).
+    pub max_pinned_transactions: usize,
 }
 
 impl PoolConfig {
@@ -80,6 +86,7 @@ impl Default for PoolConfig {
             local_transactions_config: Default::default(),
             pending_tx_listener_buffer_size: PENDING_TX_LISTENER_BUFFER_SIZE,
             new_tx_listener_buffer_size: NEW_TX_LISTENER_BUFFER_SIZE,
+            max_pinned_transactions: DEFAULT_MAX_PINNED_TRANSACTIONS,
         }
     }
 }
@@ -150,13 +150,16 @@
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 
-use crate::{identifier::TransactionId, pool::PoolInner};
+use crate::{
+    identifier::TransactionId,
+    pool::{BatchImport, PoolInner},
+};
 use alloy_eips::eip4844::{BlobAndProofV1, BlobTransactionSidecar};
 use alloy_primitives::{Address, TxHash, B256, U256};
 use aquamarine as _;
 use reth_eth_wire_types::HandleMempoolData;
 use reth_execution_types::ChangedAccount;
-use reth_primitives::PooledTransactionsElement;
+use reth_primitives::{PooledTransactionsElement, TransactionSigned};
 use reth_storage_api::StateProviderFactory;
 use std::{collections::HashSet, sync::Arc};
 use tokio::sync::mpsc::Receiver;
@@ -165,7 +168,8 @@ use tracing::{instrument, trace};
 pub use crate::{
     blobstore::{BlobStore, BlobStoreError},
     config::{
-        LocalTransactionConfig, PoolConfig, PriceBumpConfig, SubPoolLimit, DEFAULT_PRICE_BUMP,
+        LocalTransactionConfig, PoolConfig, PriceBumpConfig, SubPoolLimit,
+        DEFAULT_MAX_PINNED_TRANSACTIONS, DEFAULT_PRICE_BUMP,
         DEFAULT_TXPOOL_ADDITIONAL_VALIDATION_TASKS, REPLACE_BLOB_PRICE_BUMP,
         TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER, TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT,
         TXPOOL_SUBPOOL_MAX_TXS_DEFAULT,
@@ -174,7 +178,8 @@ pub use crate::{
     ordering::{CoinbaseTipOrdering, Priority, TransactionOrdering},
     pool::{
         blob_tx_priority, fee_delta, state::SubPool, AllTransactionsEvents, FullTransactionEvent,
-        TransactionEvent, TransactionEvents,
+        PoolSnapshot, QueuedReason, SenderSnapshot, TransactionEvent, TransactionEvents,
+        TransactionSnapshot,
     },
     traits::*,
     validate::{
@@ -370,6 +375,30 @@ where
         self.pool.add_transactions(origin, validated.into_iter().map(|(_, tx)| tx))
     }
 
+    async fn batch_import(
+        &self,
+        origin: TransactionOrigin,
+        transactions: Vec<TransactionSigned>,
+    ) -> Vec<PoolResult<TxHash>>
+    where
+        Self::Transaction:
+            PoolTransaction<TryFromConsensusError: core::error::Error + Send + Sync + 'static>,
+    {
+        if transactions.is_empty() {
+            return Vec::new()
+        }
+        let (batch, transactions) = BatchImport::recover(transactions);
+        let validated = self
+            .pool
+            .validator()
+            .validate_transactions(transactions.into_iter().map(|tx| (origin, tx)).collect())
+            .await;
+
+        // insert all transactions at once instead of locking the pool for every transaction
+        let imported = self.pool.import_transactions(origin, validated);
+        batch.into_results(imported)
+    }
+
     fn transaction_event_listener(&self, tx_hash: TxHash) -> Option<TransactionEvents> {
         self.pool.add_transaction_event_listener(tx_hash)
     }
@@ -449,6 +478,10 @@ where
         self.pool.all_transactions()
     }
 
+    fn export_snapshot(&self) -> PoolSnapshot {
+        self.pool.export_snapshot()
+    }
+
     fn remove_transactions(
         &self,
         hashes: Vec<TxHash>,
@@ -470,6 +503,18 @@ where
         self.pool.remove_transactions_by_sender(sender)
     }
 
+    fn drain_stale(&self, state_changes: &[ChangedAccount]) -> usize {
+        self.pool.drain_stale(state_changes)
+    }
+
+    fn pin_transaction(&self, hash: TxHash) -> bool {
+        self.pool.pin_transaction(hash)
+    }
+
+    fn unpin_transaction(&self, hash: TxHash) {
+        self.pool.unpin_transaction(hash)
+    }
+
     fn retain_unknown<A>(&self, announcement: &mut A)
     where
         A: HandleMempoolData,
@@ -510,6 +555,13 @@ where
         self.pool.get_pending_transactions_by_sender(sender)
     }
 
+    fn pending_by_sender(
+        &self,
+        sender: Address,
+    ) -> impl Iterator<Item = Arc<ValidPoolTransaction<Self::Transaction>>> {
+        self.pool.get_pending_transactions_by_sender(sender).into_iter()
+    }
+
     fn get_queued_transactions_by_sender(
         &self,
         sender: Address,
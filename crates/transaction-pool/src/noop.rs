@@ -12,7 +12,7 @@ use crate::{
     },
     validate::ValidTransaction,
     AllPoolTransactions, AllTransactionsEvents, BestTransactions, BlockInfo, EthPoolTransaction,
-    EthPooledTransaction, NewTransactionEvent, PoolResult, PoolSize, PoolTransaction,
+    EthPooledTransaction, NewTransactionEvent, PoolResult, PoolSize, PoolSnapshot, PoolTransaction,
     PooledTransactionsElement, PropagatedTransactions, TransactionEvents, TransactionOrigin,
     TransactionPool, TransactionValidationOutcome, TransactionValidator, ValidPoolTransaction,
 };
@@ -22,6 +22,7 @@ use alloy_eips::{
 };
 use alloy_primitives::{Address, TxHash, B256, U256};
 use reth_eth_wire_types::HandleMempoolData;
+use reth_execution_types::ChangedAccount;
 use std::{collections::HashSet, marker::PhantomData, sync::Arc};
 use tokio::sync::{mpsc, mpsc::Receiver};
 
@@ -171,6 +172,10 @@ impl TransactionPool for NoopTransactionPool {
         AllPoolTransactions::default()
     }
 
+    fn export_snapshot(&self) -> PoolSnapshot {
+        PoolSnapshot::default()
+    }
+
     fn remove_transactions(
         &self,
         _hashes: Vec<TxHash>,
@@ -192,6 +197,16 @@ impl TransactionPool for NoopTransactionPool {
         vec![]
     }
 
+    fn drain_stale(&self, _state_changes: &[ChangedAccount]) -> usize {
+        0
+    }
+
+    fn pin_transaction(&self, _hash: TxHash) -> bool {
+        false
+    }
+
+    fn unpin_transaction(&self, _hash: TxHash) {}
+
     fn retain_unknown<A>(&self, _announcement: &mut A)
     where
         A: HandleMempoolData,
@@ -229,6 +244,13 @@ impl TransactionPool for NoopTransactionPool {
         vec![]
     }
 
+    fn pending_by_sender(
+        &self,
+        _sender: Address,
+    ) -> impl Iterator<Item = Arc<ValidPoolTransaction<Self::Transaction>>> {
+        core::iter::empty()
+    }
+
     fn get_queued_transactions_by_sender(
         &self,
         _sender: Address,
@@ -1,6 +1,8 @@
 use super::txpool::PendingFees;
 use crate::{
-    identifier::TransactionId, pool::size::SizeTracker, traits::BestTransactionsAttributes,
+    identifier::TransactionId,
+    pool::{pinned::PinnedTransactions, size::SizeTracker},
+    traits::BestTransactionsAttributes,
     PoolTransaction, SubPoolLimit, ValidPoolTransaction,
 };
 use std::{
@@ -216,15 +218,22 @@ impl<T: PoolTransaction> BlobTransactions<T> {
     /// This is done by removing transactions according to their ordering in the pool, defined by
     /// the [`BlobOrd`] struct.
     ///
+    /// Transactions that are protected by the [`PinnedTransactions`] are never removed, so the
+    /// pool can still exceed the limits afterwards, if only protected transactions are left.
+    ///
     /// Removed transactions are returned in the order they were removed.
-    pub(crate) fn truncate_pool(
+    pub(crate) fn truncate_unpinned(
         &mut self,
         limit: SubPoolLimit,
+        pinned: &PinnedTransactions,
     ) -> Vec<Arc<ValidPoolTransaction<T>>> {
         let mut removed = Vec::new();
 
         while self.exceeds(&limit) {
-            let tx = self.all.last().expect("pool is not empty");
+            let Some(tx) = self.all.iter().rev().find(|tx| !pinned.protects(tx.transaction.id()))
+            else {
+                break
+            };
             let id = *tx.transaction.id();
             removed.push(self.remove_transaction(&id).expect("transaction exists"));
         }
@@ -0,0 +1,76 @@
+//! Bulk import of signed transactions, see
+//! [`TransactionPool::batch_import`](crate::TransactionPool::batch_import).
+
+use crate::{
+    error::{PoolError, PoolResult},
+    traits::PoolTransaction,
+};
+use alloy_primitives::TxHash;
+use reth_primitives::{TransactionSigned, TransactionSignedEcRecovered};
+
+/// Tracks the results of a batch of signed transactions that is imported into the pool.
+#[derive(Debug)]
+pub(crate) struct BatchImport {
+    /// The index in the batch of each recovered transaction.
+    indices: Vec<usize>,
+    /// The result of each transaction in the batch, `None` if it's not imported yet.
+    results: Vec<Option<PoolResult<TxHash>>>,
+}
+
+impl BatchImport {
+    /// Recovers the senders of the transactions and converts them into pool transactions.
+    ///
+    /// The senders are recovered in parallel for large batches. The returned transactions are
+    /// ordered by sender and nonce, so that the transactions of a sender are inserted in the order
+    /// in which they can be executed. Transactions that can't be recovered or converted are
+    /// rejected.
+    pub(crate) fn recover<T>(transactions: Vec<TransactionSigned>) -> (Self, Vec<T>)
+    where
+        T: PoolTransaction<
+            Consensus: From<TransactionSignedEcRecovered>,
+            TryFromConsensusError: core::error::Error + Send + Sync + 'static,
+        >,
+    {
+        let senders = match TransactionSigned::recover_signers(&transactions, transactions.len()) {
+            Some(senders) => senders.into_iter().map(Some).collect(),
+            // at least one signature is invalid, recover the senders one by one to find out which
+            None => transactions.iter().map(|tx| tx.recover_signer()).collect::<Vec<_>>(),
+        };
+
+        let mut results = Vec::with_capacity(transactions.len());
+        let mut recovered = Vec::with_capacity(transactions.len());
+        for (index, (transaction, sender)) in transactions.into_iter().zip(senders).enumerate() {
+            let hash = transaction.hash();
+            let Some(sender) = sender else {
+                results.push(Some(Err(PoolError::other(hash, "invalid transaction signature"))));
+                continue
+            };
+            match T::try_from_consensus(transaction.with_signer(sender).into()) {
+                Ok(transaction) => {
+                    results.push(None);
+                    recovered.push((index, transaction));
+                }
+                Err(err) => results.push(Some(Err(PoolError::other(hash, err)))),
+            }
+        }
+
+        recovered.sort_by_key(|(_, transaction)| (transaction.sender(), transaction.nonce()));
+        let (indices, transactions) = recovered.into_iter().unzip();
+        (Self { indices, results }, transactions)
+    }
+
+    /// Returns the results of all transactions in the order of the batch, given the results of the
+    /// recovered transactions in the order returned by [`Self::recover`].
+    pub(crate) fn into_results(
+        mut self,
+        imported: Vec<PoolResult<TxHash>>,
+    ) -> Vec<PoolResult<TxHash>> {
+        for (index, result) in self.indices.into_iter().zip(imported) {
+            self.results[index] = Some(result);
+        }
+        self.results
+            .into_iter()
+            .map(|result| result.expect("all recovered transactions are imported"))
+            .collect()
+    }
+}
@@ -74,13 +74,13 @@ use crate::{
         txpool::{SenderInfo, TxPool},
     },
     traits::{
-        AllPoolTransactions, BestTransactionsAttributes, BlockInfo, NewTransactionEvent, PoolSize,
-        PoolTransaction, PropagatedTransactions, TransactionOrigin,
+        AllPoolTransactions, BestTransactionsAttributes, BlockInfo, EthPoolTransaction,
+        NewTransactionEvent, PoolSize, PoolTransaction, PropagatedTransactions, TransactionOrigin,
     },
     validate::{TransactionValidationOutcome, ValidPoolTransaction},
     CanonicalStateUpdate, PoolConfig, TransactionOrdering, TransactionValidator,
 };
-use alloy_primitives::{Address, TxHash, B256};
+use alloy_primitives::{Address, TxHash, B256, U256};
 use best::BestTransactions;
 use parking_lot::{Mutex, RwLock, RwLockReadGuard};
 use reth_eth_wire_types::HandleMempoolData;
@@ -109,16 +109,21 @@ use crate::{
 pub use best::{BestTransactionFilter, BestTransactionsWithPrioritizedSenders};
 pub use blob::{blob_tx_priority, fee_delta};
 pub use events::{FullTransactionEvent, TransactionEvent};
+pub(crate) use import::BatchImport;
 pub use listener::{AllTransactionsEvents, TransactionEvents};
 pub use parked::{BasefeeOrd, ParkedOrd, ParkedPool, QueuedOrd};
 pub use pending::PendingPool;
+pub use snapshot::{PoolSnapshot, QueuedReason, SenderSnapshot, TransactionSnapshot};
 
 mod best;
 mod blob;
+mod import;
 mod listener;
 mod parked;
 pub(crate) mod pending;
+pub(crate) mod pinned;
 pub(crate) mod size;
+mod snapshot;
 pub(crate) mod state;
 pub mod txpool;
 mod update;
@@ -436,6 +441,20 @@ where
         origin: TransactionOrigin,
         tx: TransactionValidationOutcome<T::Transaction>,
     ) -> PoolResult<TxHash> {
+        let ValidatedTransaction { transaction, balance, state_nonce, sidecar } =
+            self.prepare_insert(origin, tx)?;
+        let added = self.pool.write().add_transaction(transaction, balance, state_nonce)?;
+        Ok(self.on_added_transaction(added, sidecar))
+    }
+
+    /// Turns the validation outcome into a transaction that can be inserted into the pool.
+    ///
+    /// Invalid transactions are reported as discarded to the event listeners.
+    fn prepare_insert(
+        &self,
+        origin: TransactionOrigin,
+        tx: TransactionValidationOutcome<T::Transaction>,
+    ) -> PoolResult<ValidatedTransaction<T::Transaction>> {
         match tx {
             TransactionValidationOutcome::Valid {
                 balance,
@@ -447,7 +466,7 @@ where
                 let transaction_id = TransactionId::new(sender_id, transaction.nonce());
 
                 // split the valid transaction and the blob sidecar if it has any
-                let (transaction, maybe_sidecar) = match transaction {
+                let (transaction, sidecar) = match transaction {
                     ValidTransaction::Valid(tx) => (tx, None),
                     ValidTransaction::ValidWithSidecar { transaction, sidecar } => {
                         debug_assert!(
@@ -458,7 +477,7 @@ where
                     }
                 };
 
-                let tx = ValidPoolTransaction {
+                let transaction = ValidPoolTransaction {
                     transaction,
                     transaction_id,
                     propagate,
@@ -466,39 +485,7 @@ where
                     origin,
                 };
 
-                let added = self.pool.write().add_transaction(tx, balance, state_nonce)?;
-                let hash = *added.hash();
-
-                // transaction was successfully inserted into the pool
-                if let Some(sidecar) = maybe_sidecar {
-                    // notify blob sidecar listeners
-                    self.on_new_blob_sidecar(&hash, &sidecar);
-                    // store the sidecar in the blob store
-                    self.insert_blob(hash, sidecar);
-                }
-
-                if let Some(replaced) = added.replaced_blob_transaction() {
-                    debug!(target: "txpool", "[{:?}] delete replaced blob sidecar", replaced);
-                    // delete the replaced transaction from the blob store
-                    self.delete_blob(replaced);
-                }
-
-                // Notify about new pending transactions
-                if let Some(pending) = added.as_pending() {
-                    self.on_new_pending_transaction(pending);
-                }
-
-                // Notify tx event listeners
-                self.notify_event_listeners(&added);
-
-                if let Some(discarded) = added.discarded_transactions() {
-                    self.delete_discarded_blobs(discarded.iter());
-                }
-
-                // Notify listeners for _all_ transactions
-                self.on_new_transaction(added.into_new_transaction_event());
-
-                Ok(hash)
+                Ok(ValidatedTransaction { transaction, balance, state_nonce, sidecar })
             }
             TransactionValidationOutcome::Invalid(tx, err) => {
                 let mut listener = self.event_listener.write();
@@ -513,6 +500,46 @@ where
         }
     }
 
+    /// Stores the blob sidecar of the transaction that was successfully inserted into the pool and
+    /// notifies all listeners.
+    fn on_added_transaction(
+        &self,
+        added: AddedTransaction<T::Transaction>,
+        maybe_sidecar: Option<BlobTransactionSidecar>,
+    ) -> TxHash {
+        let hash = *added.hash();
+
+        if let Some(sidecar) = maybe_sidecar {
+            // notify blob sidecar listeners
+            self.on_new_blob_sidecar(&hash, &sidecar);
+            // store the sidecar in the blob store
+            self.insert_blob(hash, sidecar);
+        }
+
+        if let Some(replaced) = added.replaced_blob_transaction() {
+            debug!(target: "txpool", "[{:?}] delete replaced blob sidecar", replaced);
+            // delete the replaced transaction from the blob store
+            self.delete_blob(replaced);
+        }
+
+        // Notify about new pending transactions
+        if let Some(pending) = added.as_pending() {
+            self.on_new_pending_transaction(pending);
+        }
+
+        // Notify tx event listeners
+        self.notify_event_listeners(&added);
+
+        if let Some(discarded) = added.discarded_transactions() {
+            self.delete_discarded_blobs(discarded.iter());
+        }
+
+        // Notify listeners for _all_ transactions
+        self.on_new_transaction(added.into_new_transaction_event());
+
+        hash
+    }
+
     pub(crate) fn add_transaction_and_subscribe(
         &self,
         origin: TransactionOrigin,
@@ -533,9 +560,47 @@ where
         origin: TransactionOrigin,
         transactions: impl IntoIterator<Item = TransactionValidationOutcome<T::Transaction>>,
     ) -> Vec<PoolResult<TxHash>> {
-        let mut added =
+        let added =
             transactions.into_iter().map(|tx| self.add_transaction(origin, tx)).collect::<Vec<_>>();
+        self.discard_worst_added(added)
+    }
 
+    /// Adds all transactions in the iterator to the pool while acquiring the lock of the pool only
+    /// once, returning a list of results.
+    ///
+    /// Listeners are notified after all transactions are inserted. This is intended for large
+    /// batches, see [`TransactionPool::batch_import`](crate::TransactionPool::batch_import).
+    pub fn import_transactions(
+        &self,
+        origin: TransactionOrigin,
+        transactions: impl IntoIterator<Item = TransactionValidationOutcome<T::Transaction>>,
+    ) -> Vec<PoolResult<TxHash>> {
+        let prepared =
+            transactions.into_iter().map(|tx| self.prepare_insert(origin, tx)).collect::<Vec<_>>();
+
+        let inserted = {
+            let mut pool = self.pool.write();
+            prepared
+                .into_iter()
+                .map(|tx| -> PoolResult<_> {
+                    let ValidatedTransaction { transaction, balance, state_nonce, sidecar } = tx?;
+                    Ok((pool.add_transaction(transaction, balance, state_nonce)?, sidecar))
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let added = inserted
+            .into_iter()
+            .map(|res| res.map(|(added, sidecar)| self.on_added_transaction(added, sidecar)))
+            .collect();
+        self.discard_worst_added(added)
+    }
+
+    /// Enforces the pool size limits after the given transactions were added.
+    ///
+    /// The results of added transactions that are discarded right away are replaced with
+    /// [`PoolErrorKind::DiscardedOnInsert`].
+    fn discard_worst_added(&self, mut added: Vec<PoolResult<TxHash>>) -> Vec<PoolResult<TxHash>> {
         // If at least one transaction was added successfully, then we enforce the pool size limits.
         let discarded =
             if added.iter().any(Result::is_ok) { self.discard_worst() } else { Default::default() };
@@ -700,6 +765,14 @@ where
         }
     }
 
+    /// Returns a snapshot of all transactions in the pool.
+    pub(crate) fn export_snapshot(&self) -> PoolSnapshot
+    where
+        T::Transaction: EthPoolTransaction,
+    {
+        self.get_pool_data().snapshot()
+    }
+
     /// Removes and returns all matching transactions from the pool.
     pub(crate) fn remove_transactions(
         &self,
@@ -749,6 +822,38 @@ where
         removed
     }
 
+    /// Pins the transaction with the given hash, see
+    /// [`TransactionPool::pin_transaction`](crate::TransactionPool::pin_transaction).
+    pub(crate) fn pin_transaction(&self, hash: TxHash) -> bool {
+        self.pool.write().pin_transaction(hash)
+    }
+
+    /// Unpins the transaction with the given hash.
+    pub(crate) fn unpin_transaction(&self, hash: TxHash) {
+        self.pool.write().unpin_transaction(&hash)
+    }
+
+    /// Removes all transactions of the changed accounts with a nonce lower than the account's
+    /// nonce and returns the number of removed transactions.
+    ///
+    /// Remaining transactions of the accounts that became executable are promoted.
+    pub(crate) fn drain_stale(&self, accounts: &[ChangedAccount]) -> usize {
+        if accounts.is_empty() {
+            return 0
+        }
+        let changed_senders = self.changed_senders(accounts.iter().copied());
+        let UpdateOutcome { promoted, discarded } = self.pool.write().drain_stale(changed_senders);
+
+        let mut listener = self.event_listener.write();
+
+        promoted.iter().for_each(|tx| listener.pending(tx.hash(), None));
+        discarded.iter().for_each(|tx| listener.discarded(tx.hash()));
+
+        self.delete_discarded_blobs(discarded.iter());
+
+        discarded.len()
+    }
+
     /// Removes and returns all transactions that are present in the pool.
     pub(crate) fn retain_unknown<A>(&self, announcement: &mut A)
     where
@@ -784,7 +889,7 @@ where
         sender: Address,
     ) -> Vec<Arc<ValidPoolTransaction<T::Transaction>>> {
         let sender_id = self.get_sender_id(sender);
-        self.get_pool_data().pending_txs_by_sender(sender_id)
+        self.get_pool_data().queued_txs_by_sender(sender_id)
     }
 
     /// Returns all pending transactions filtered by predicate
@@ -795,13 +900,13 @@ where
         self.get_pool_data().pending_transactions_with_predicate(predicate)
     }
 
-    /// Returns all pending transactions of the address by sender
+    /// Returns all pending transactions of the address by sender, ordered by nonce
     pub(crate) fn get_pending_transactions_by_sender(
         &self,
         sender: Address,
     ) -> Vec<Arc<ValidPoolTransaction<T::Transaction>>> {
         let sender_id = self.get_sender_id(sender);
-        self.get_pool_data().queued_txs_by_sender(sender_id)
+        self.get_pool_data().pending_txs_by_sender(sender_id)
     }
 
     /// Returns the highest transaction of the address
@@ -1111,6 +1216,18 @@ where
     }
 }
 
+/// A validated transaction that is ready to be inserted into the pool.
+struct ValidatedTransaction<T: PoolTransaction> {
+    /// The transaction to insert.
+    transaction: ValidPoolTransaction<T>,
+    /// The on-chain balance of the sender.
+    balance: U256,
+    /// The on-chain nonce of the sender.
+    state_nonce: u64,
+    /// The blob sidecar of the transaction, if it has one.
+    sidecar: Option<BlobTransactionSidecar>,
+}
+
 /// Represents a transaction that was added into the pool and its state
 #[derive(Debug, Clone)]
 pub enum AddedTransaction<T: PoolTransaction> {
@@ -1329,4 +1446,38 @@ mod tests {
         // Assert that the pool's blob store matches the expected blob store.
         assert_eq!(*test_pool.blob_store(), blob_store);
     }
+
+    #[test]
+    fn test_get_transactions_by_sender() {
+        let test_pool = &TestPoolBuilder::default().pool;
+
+        let tx_0 = MockTransaction::eip1559().set_gas_price(100).inc_limit();
+        let tx_1 = tx_0.next();
+        // nonce gapped, so it is queued
+        let tx_3 = tx_1.next().next();
+        let other = MockTransaction::eip1559().set_gas_price(100).inc_limit();
+        let sender = tx_0.get_sender();
+
+        let results = test_pool.add_transactions(
+            TransactionOrigin::External,
+            [tx_3.clone(), tx_0.clone(), other, tx_1.clone()].into_iter().map(|tx| {
+                TransactionValidationOutcome::Valid {
+                    balance: U256::from(10_000),
+                    state_nonce: 0,
+                    transaction: ValidTransaction::Valid(tx),
+                    propagate: true,
+                }
+            }),
+        );
+        assert!(results.iter().all(|res| res.is_ok()));
+
+        let pending = test_pool.get_pending_transactions_by_sender(sender);
+        assert_eq!(
+            pending.iter().map(|tx| *tx.hash()).collect::<Vec<_>>(),
+            vec![tx_0.get_hash(), tx_1.get_hash()]
+        );
+
+        let queued = test_pool.get_queued_transactions_by_sender(sender);
+        assert_eq!(queued.iter().map(|tx| *tx.hash()).collect::<Vec<_>>(), vec![tx_3.get_hash()]);
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{
     identifier::{SenderId, TransactionId},
-    pool::size::SizeTracker,
+    pool::{pinned::PinnedTransactions, size::SizeTracker},
     PoolTransaction, SubPoolLimit, ValidPoolTransaction, TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER,
 };
 use rustc_hash::FxHashMap;
@@ -187,6 +187,18 @@ impl<T: ParkedOrd> ParkedPool<T> {
     pub fn truncate_pool(
         &mut self,
         limit: SubPoolLimit,
+    ) -> Vec<Arc<ValidPoolTransaction<T::Transaction>>> {
+        self.truncate_unpinned(limit, &PinnedTransactions::default())
+    }
+
+    /// Truncates the pool like [`truncate_pool`](ParkedPool::truncate_pool), but never removes
+    /// transactions that are protected by the [`PinnedTransactions`].
+    ///
+    /// The pool can still exceed the limits afterwards, if only protected transactions are left.
+    pub(crate) fn truncate_unpinned(
+        &mut self,
+        limit: SubPoolLimit,
+        pinned: &PinnedTransactions,
     ) -> Vec<Arc<ValidPoolTransaction<T::Transaction>>> {
         if !self.exceeds(&limit) {
             // if we are below the limits, we don't need to drop anything
@@ -195,20 +207,24 @@ impl<T: ParkedOrd> ParkedPool<T> {
 
         let mut removed = Vec::new();
 
-        while limit.is_exceeded(self.len(), self.size()) && !self.last_sender_submission.is_empty()
-        {
-            // NOTE: This will not panic due to `!last_sender_transaction.is_empty()`
-            let sender_id = self.last_sender_submission.last().expect("not empty").sender_id;
+        let senders: Vec<_> =
+            self.last_sender_submission.iter().rev().map(|sender| sender.sender_id).collect();
+        'senders: for sender_id in senders {
             let list = self.get_txs_by_sender(sender_id);
 
             // Drop transactions from this sender until the pool is under limits
             for txid in list.into_iter().rev() {
+                if pinned.protects(&txid) {
+                    // all remaining transactions of this sender are protected
+                    break
+                }
+
                 if let Some(tx) = self.remove_transaction(&txid) {
                     removed.push(tx);
                 }
 
                 if !self.exceeds(&limit) {
-                    break
+                    break 'senders
                 }
             }
         }
@@ -2,6 +2,7 @@ use crate::{
     identifier::{SenderId, TransactionId},
     pool::{
         best::{BestTransactions, BestTransactionsWithFees},
+        pinned::PinnedTransactions,
         size::SizeTracker,
     },
     Priority, SubPoolLimit, TransactionOrdering, ValidPoolTransaction,
@@ -357,11 +358,15 @@ impl<T: TransactionOrdering> PendingPool<T> {
     /// Otherwise, if the `remove_locals` flag is set, transactions will be removed per-sender
     /// until the pool is under the given limits.
     ///
+    /// Senders whose highest-nonce transaction is protected by the [`PinnedTransactions`] are
+    /// skipped like local transactions, regardless of the `remove_locals` flag.
+    ///
     /// Any removed transactions will be added to the `end_removed` vector.
     pub fn remove_to_limit(
         &mut self,
         limit: &SubPoolLimit,
         remove_locals: bool,
+        pinned: &PinnedTransactions,
         end_removed: &mut Vec<Arc<ValidPoolTransaction<T::Transaction>>>,
     ) {
         // This serves as a termination condition for the loop - it represents the number of
@@ -414,7 +419,9 @@ impl<T: TransactionOrdering> PendingPool<T> {
                     return
                 }
 
-                if !remove_locals && tx.transaction.is_local() {
+                if (!remove_locals && tx.transaction.is_local()) ||
+                    pinned.protects(tx.transaction.id())
+                {
                     non_local_senders -= 1;
                     continue
                 }
@@ -452,6 +459,18 @@ impl<T: TransactionOrdering> PendingPool<T> {
     pub fn truncate_pool(
         &mut self,
         limit: SubPoolLimit,
+    ) -> Vec<Arc<ValidPoolTransaction<T::Transaction>>> {
+        self.truncate_unpinned(limit, &PinnedTransactions::default())
+    }
+
+    /// Truncates the pool like [`truncate_pool`](PendingPool::truncate_pool), but never removes
+    /// transactions that are protected by the [`PinnedTransactions`].
+    ///
+    /// The pool can still exceed the limits afterwards, if only protected transactions are left.
+    pub(crate) fn truncate_unpinned(
+        &mut self,
+        limit: SubPoolLimit,
+        pinned: &PinnedTransactions,
     ) -> Vec<Arc<ValidPoolTransaction<T::Transaction>>> {
         let mut removed = Vec::new();
         // return early if the pool is already under the limits
@@ -460,14 +479,14 @@ impl<T: TransactionOrdering> PendingPool<T> {
         }
 
         // first truncate only non-local transactions, returning if the pool end up under the limit
-        self.remove_to_limit(&limit, false, &mut removed);
+        self.remove_to_limit(&limit, false, pinned, &mut removed);
         if !self.exceeds(&limit) {
             return removed
         }
 
         // now repeat for local transactions, since local transactions must be removed now for the
         // pool to be under the limit
-        self.remove_to_limit(&limit, true, &mut removed);
+        self.remove_to_limit(&limit, true, pinned, &mut removed);
 
         removed
     }
@@ -508,6 +527,17 @@ impl<T: TransactionOrdering> PendingPool<T> {
             .collect()
     }
 
+    /// Returns an iterator over all transactions of the given sender, ordered by nonce.
+    pub(crate) fn txs_by_sender(
+        &self,
+        sender: SenderId,
+    ) -> impl Iterator<Item = Arc<ValidPoolTransaction<T::Transaction>>> + '_ {
+        self.by_id
+            .range((sender.start_bound(), Unbounded))
+            .take_while(move |(other, _)| sender == other.sender)
+            .map(|(_, tx)| tx.transaction.clone())
+    }
+
     /// Retrieves a transaction with the given ID from the pool, if it exists.
     fn get(&self, id: &TransactionId) -> Option<&PendingTransaction<T>> {
         self.by_id.get(id)
@@ -0,0 +1,89 @@
+//! Tracks pinned transactions.
+
+use crate::identifier::TransactionId;
+use alloy_primitives::TxHash;
+use std::collections::{BTreeSet, HashMap};
+
+/// Transactions that are excluded from eviction, see
+/// [`TransactionPool::pin_transaction`](crate::TransactionPool::pin_transaction).
+///
+/// A pinned transaction also protects the transactions of the same sender with lower nonces,
+/// because the pinned transaction can't be executed without them.
+#[derive(Debug, Clone, Default)]
+pub struct PinnedTransactions {
+    /// The identifiers of all pinned transactions.
+    by_hash: HashMap<TxHash, TransactionId>,
+    /// All pinned transactions ordered by sender and nonce.
+    by_id: BTreeSet<TransactionId>,
+}
+
+impl PinnedTransactions {
+    /// Number of pinned transactions.
+    pub(crate) fn len(&self) -> usize {
+        self.by_hash.len()
+    }
+
+    /// Pins the transaction, unless `max_pinned` transactions are already pinned.
+    ///
+    /// Returns `true` if the transaction is pinned.
+    pub(crate) fn insert(&mut self, hash: TxHash, id: TransactionId, max_pinned: usize) -> bool {
+        if self.by_hash.contains_key(&hash) {
+            return true
+        }
+        if self.len() >= max_pinned {
+            return false
+        }
+        self.by_hash.insert(hash, id);
+        self.by_id.insert(id);
+        true
+    }
+
+    /// Unpins the transaction with the given hash.
+    pub(crate) fn remove(&mut self, hash: &TxHash) {
+        if let Some(id) = self.by_hash.remove(hash) {
+            self.by_id.remove(&id);
+        }
+    }
+
+    /// Retains only the pinned transactions for which the predicate returns `true`.
+    pub(crate) fn retain(&mut self, mut f: impl FnMut(&TxHash) -> bool) {
+        let Self { by_hash, by_id } = self;
+        by_hash.retain(|hash, id| {
+            let retain = f(hash);
+            if !retain {
+                by_id.remove(id);
+            }
+            retain
+        });
+    }
+
+    /// Returns `true` if the transaction with the given id must not be evicted, because it or a
+    /// transaction of the same sender with a higher nonce is pinned.
+    pub(crate) fn protects(&self, id: &TransactionId) -> bool {
+        self.by_id.range(id..).next().is_some_and(|pinned| pinned.sender == id.sender)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identifier::SenderId;
+
+    #[test]
+    fn protects_lower_nonces() {
+        let sender = SenderId::from(1);
+        let mut pinned = PinnedTransactions::default();
+        assert!(pinned.insert(TxHash::with_last_byte(1), TransactionId::new(sender, 2), 1));
+        // the cap is reached
+        assert!(!pinned.insert(TxHash::with_last_byte(2), TransactionId::new(sender, 3), 1));
+
+        assert!(pinned.protects(&TransactionId::new(sender, 0)));
+        assert!(pinned.protects(&TransactionId::new(sender, 2)));
+        assert!(!pinned.protects(&TransactionId::new(sender, 3)));
+        assert!(!pinned.protects(&TransactionId::new(SenderId::from(0), 2)));
+
+        pinned.remove(&TxHash::with_last_byte(1));
+        assert_eq!(pinned.len(), 0);
+        assert!(!pinned.protects(&TransactionId::new(sender, 0)));
+    }
+}
@@ -0,0 +1,113 @@
+//! Serializable snapshot of the pool's state.
+
+use crate::{
+    pool::state::{SubPool, TxState},
+    BlockInfo, TransactionOrigin,
+};
+use alloy_primitives::{Address, Bytes, TxHash, U256};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The state of all transactions in the pool at a point in time, see
+/// [`TransactionPool::export_snapshot`](crate::TransactionPool::export_snapshot).
+///
+/// This is intended for debugging, e.g. to reproduce ordering or eviction issues by re-importing
+/// the transactions into a pool.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PoolSnapshot {
+    /// The block the pool was tracking when the snapshot was taken.
+    pub block_info: BlockInfo,
+    /// The transactions in the pool, grouped by sender.
+    pub senders: BTreeMap<Address, SenderSnapshot>,
+}
+
+impl PoolSnapshot {
+    /// Returns the total number of transactions in the snapshot.
+    pub fn len(&self) -> usize {
+        self.senders.values().map(|sender| sender.transactions.len()).sum()
+    }
+
+    /// Returns `true` if the snapshot contains no transactions.
+    pub fn is_empty(&self) -> bool {
+        self.senders.is_empty()
+    }
+}
+
+/// The transactions of a single sender in a [`PoolSnapshot`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SenderSnapshot {
+    /// The on-chain nonce of the sender, as known to the pool.
+    pub state_nonce: u64,
+    /// The on-chain balance of the sender, as known to the pool.
+    pub balance: U256,
+    /// The transactions of the sender, ordered by nonce.
+    pub transactions: Vec<TransactionSnapshot>,
+}
+
+/// A transaction in a [`PoolSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TransactionSnapshot {
+    /// Hash of the transaction.
+    pub hash: TxHash,
+    /// Nonce of the transaction.
+    pub nonce: u64,
+    /// The sub-pool that contains the transaction.
+    pub subpool: SubPool,
+    /// Where the transaction originated from.
+    pub origin: TransactionOrigin,
+    /// The effective tip per gas of the transaction for the pending block, if the transaction's
+    /// fee cap covers the pending base fee.
+    ///
+    /// This is the priority of the transaction with the default
+    /// [`CoinbaseTipOrdering`](crate::CoinbaseTipOrdering).
+    pub effective_tip_per_gas: Option<u128>,
+    /// The reasons why the transaction is not pending, empty if it is pending.
+    pub queued_reasons: Vec<QueuedReason>,
+    /// The EIP-2718 encoded transaction.
+    ///
+    /// Blob transactions are encoded without their sidecar.
+    pub raw: Bytes,
+}
+
+/// The reason why a transaction in the pool is not pending.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum QueuedReason {
+    /// A transaction of the same sender with a lower nonce is not pending.
+    ParkedAncestor,
+    /// A transaction of the same sender with a lower nonce is missing.
+    NonceGap,
+    /// The sender's balance can't cover the cost of the transaction and its ancestors.
+    InsufficientBalance,
+    /// The gas limit of the transaction exceeds the block gas limit.
+    ExceedsBlockGasLimit,
+    /// The fee cap of the transaction is below the pending base fee.
+    InsufficientBaseFee,
+    /// The blob fee cap of the transaction is below the pending blob fee.
+    InsufficientBlobFee,
+}
+
+impl QueuedReason {
+    /// Returns the reasons why a transaction with the given state is not pending.
+    pub(crate) fn from_state(state: TxState) -> Vec<Self> {
+        if state.is_pending() {
+            return Vec::new()
+        }
+        [
+            (TxState::NO_PARKED_ANCESTORS, Self::ParkedAncestor),
+            (TxState::NO_NONCE_GAPS, Self::NonceGap),
+            (TxState::ENOUGH_BALANCE, Self::InsufficientBalance),
+            (TxState::NOT_TOO_MUCH_GAS, Self::ExceedsBlockGasLimit),
+            (TxState::ENOUGH_FEE_CAP_BLOCK, Self::InsufficientBaseFee),
+            (TxState::ENOUGH_BLOB_FEE_CAP_BLOCK, Self::InsufficientBlobFee),
+        ]
+        .into_iter()
+        .filter(|(bit, _)| !state.contains(*bit))
+        .map(|(_, reason)| reason)
+        .collect()
+    }
+}
@@ -10,11 +10,13 @@ use crate::{
         blob::BlobTransactions,
         parked::{BasefeeOrd, ParkedPool, QueuedOrd},
         pending::PendingPool,
+        pinned::PinnedTransactions,
+        snapshot::{PoolSnapshot, QueuedReason, SenderSnapshot, TransactionSnapshot},
         state::{SubPool, TxState},
         update::{Destination, PoolUpdate},
         AddedPendingTransaction, AddedTransaction, OnNewCanonicalStateOutcome,
     },
-    traits::{BestTransactionsAttributes, BlockInfo, PoolSize},
+    traits::{BestTransactionsAttributes, BlockInfo, EthPoolTransaction, PoolSize},
     PoolConfig, PoolResult, PoolTransaction, PriceBumpConfig, TransactionOrdering,
     ValidPoolTransaction, U256,
 };
@@ -24,9 +26,11 @@ use alloy_consensus::constants::{
 };
 use alloy_eips::{
     eip1559::{ETHEREUM_BLOCK_GAS_LIMIT, MIN_PROTOCOL_BASE_FEE},
+    eip2718::Encodable2718,
     eip4844::BLOB_TX_MIN_BLOB_GASPRICE,
 };
 use alloy_primitives::{Address, TxHash, B256};
+use reth_primitives::TransactionSignedEcRecovered;
 use rustc_hash::FxHashMap;
 use smallvec::SmallVec;
 use std::{
@@ -74,6 +78,8 @@ pub struct TxPool<T: TransactionOrdering> {
     blob_pool: BlobTransactions<T::Transaction>,
     /// All transactions in the pool.
     all_transactions: AllTransactions<T::Transaction>,
+    /// Transactions that are excluded from eviction.
+    pinned: PinnedTransactions,
     /// Transaction pool metrics
     metrics: TxPoolMetrics,
 }
@@ -90,6 +96,7 @@ impl<T: TransactionOrdering> TxPool<T> {
             basefee_pool: Default::default(),
             blob_pool: Default::default(),
             all_transactions: AllTransactions::new(&config),
+            pinned: Default::default(),
             config,
             metrics: Default::default(),
         }
@@ -174,6 +181,38 @@ impl<T: TransactionOrdering> TxPool<T> {
         }
     }
 
+    /// Returns a snapshot of all transactions in the pool.
+    pub(crate) fn snapshot(&self) -> PoolSnapshot
+    where
+        T::Transaction: EthPoolTransaction,
+    {
+        let base_fee = self.all_transactions.pending_fees.base_fee;
+        let mut snapshot = PoolSnapshot { block_info: self.block_info(), ..Default::default() };
+        for (id, tx) in &self.all_transactions.txs {
+            let transaction = &tx.transaction;
+            let sender = snapshot.senders.entry(transaction.sender()).or_insert_with(|| {
+                let info = self.sender_info.get(&id.sender).cloned().unwrap_or_default();
+                SenderSnapshot {
+                    state_nonce: info.state_nonce,
+                    balance: info.balance,
+                    transactions: Vec::new(),
+                }
+            });
+            let recovered: TransactionSignedEcRecovered =
+                transaction.transaction.clone().into_consensus().into();
+            sender.transactions.push(TransactionSnapshot {
+                hash: *transaction.hash(),
+                nonce: transaction.nonce(),
+                subpool: tx.subpool,
+                origin: transaction.origin,
+                effective_tip_per_gas: transaction.effective_tip_per_gas(base_fee),
+                queued_reasons: QueuedReason::from_state(tx.state),
+                raw: recovered.into_signed().encoded_2718().into(),
+            });
+        }
+        snapshot
+    }
+
     /// Updates the tracked blob fee
     fn update_blob_fee(&mut self, mut pending_blob_fee: u128, base_fee_update: Ordering) {
         std::mem::swap(&mut self.all_transactions.pending_fees.blob_fee, &mut pending_blob_fee);
@@ -372,12 +411,12 @@ impl<T: TransactionOrdering> TxPool<T> {
         self.pending_transactions_iter().filter(|tx| predicate(tx)).collect()
     }
 
-    /// Returns all pending transactions for the specified sender
+    /// Returns all pending transactions for the specified sender, ordered by nonce
     pub(crate) fn pending_txs_by_sender(
         &self,
         sender: SenderId,
     ) -> Vec<Arc<ValidPoolTransaction<T::Transaction>>> {
-        self.pending_transactions_iter().filter(|tx| tx.sender_id() == sender).collect()
+        self.pending_pool.txs_by_sender(sender).collect()
     }
 
     /// Returns all transactions from parked pools
@@ -413,6 +452,31 @@ impl<T: TransactionOrdering> TxPool<T> {
         self.all_transactions.contains(tx_hash)
     }
 
+    /// Pins the transaction with the given hash, so that it is not evicted by
+    /// [`TxPool::discard_worst`].
+    ///
+    /// Returns `false` if the transaction is not in the pool or if the configured maximum number of
+    /// pinned transactions is reached.
+    pub(crate) fn pin_transaction(&mut self, hash: TxHash) -> bool {
+        let Some(id) = self.all_transactions.by_hash.get(&hash).map(|tx| *tx.id()) else {
+            return false
+        };
+        self.remove_stale_pins();
+        self.pinned.insert(hash, id, self.config.max_pinned_transactions)
+    }
+
+    /// Unpins the transaction with the given hash.
+    pub(crate) fn unpin_transaction(&mut self, hash: &TxHash) {
+        self.pinned.remove(hash)
+    }
+
+    /// Unpins all transactions that are no longer in the pool, e.g. because they were mined or
+    /// replaced.
+    fn remove_stale_pins(&mut self) {
+        let all_transactions = &self.all_transactions;
+        self.pinned.retain(|hash| all_transactions.contains(hash));
+    }
+
     /// Returns `true` if the transaction with the given id is already included in the given subpool
     #[cfg(test)]
     pub(crate) fn subpool_contains(&self, subpool: SubPool, id: &TransactionId) -> bool {
@@ -756,6 +820,18 @@ impl<T: TransactionOrdering> TxPool<T> {
         removed
     }
 
+    /// Removes all transactions of the changed senders with a nonce lower than the sender's state
+    /// nonce.
+    ///
+    /// The stale transactions are removed by [`Self::update_accounts`], which also records the new
+    /// sender infos and promotes the remaining transactions of the senders that became executable.
+    pub(crate) fn drain_stale(
+        &mut self,
+        changed_senders: HashMap<SenderId, SenderInfo>,
+    ) -> UpdateOutcome<T::Transaction> {
+        self.update_accounts(changed_senders)
+    }
+
     /// Remove the transaction from the __entire__ pool.
     ///
     /// This includes the total set of transaction and the subpool it currently resides in.
@@ -904,11 +980,13 @@ impl<T: TransactionOrdering> TxPool<T> {
     /// Ensures that the transactions in the sub-pools are within the given bounds.
     ///
     /// If the current size exceeds the given bounds, the worst transactions are evicted from the
-    /// pool and returned.
+    /// pool and returned. Pinned transactions are never evicted, see
+    /// [`TxPool::pin_transaction`].
     ///
     /// This returns all transactions that were removed from the entire pool.
     pub(crate) fn discard_worst(&mut self) -> Vec<Arc<ValidPoolTransaction<T::Transaction>>> {
         let mut removed = Vec::new();
+        self.remove_stale_pins();
 
         // Helper macro that discards the worst transactions for the pools
         macro_rules! discard_worst {
@@ -926,7 +1004,11 @@ impl<T: TransactionOrdering> TxPool<T> {
                         );
 
                         // 1. first remove the worst transaction from the subpool
-                        let removed_from_subpool = $this.$pool.truncate_pool($this.config.$limit.clone());
+                        let removed_from_subpool = $this.$pool.truncate_unpinned($this.config.$limit.clone(), &$this.pinned);
+                        if removed_from_subpool.is_empty() {
+                            // only pinned transactions are left
+                            break
+                        }
 
                         trace!(
                             target: "txpool",
@@ -2322,6 +2404,52 @@ mod tests {
         assert_eq!(inserted.subpool, SubPool::Pending);
     }
 
+    #[test]
+    fn test_snapshot() {
+        let on_chain_balance = U256::MAX;
+        let on_chain_nonce = 0;
+        let mut f = MockTransactionFactory::default();
+        let mut pool = TxPool::new(MockOrdering::default(), Default::default());
+
+        let tx = MockTransaction::eip1559().inc_price().inc_limit();
+        let gapped = tx.skip(1);
+        let v0 = f.validated(tx.clone());
+        let v2 = f.validated(gapped.clone());
+        pool.add_transaction(v0, on_chain_balance, on_chain_nonce).unwrap();
+        pool.add_transaction(v2, on_chain_balance, on_chain_nonce).unwrap();
+
+        let snapshot = pool.snapshot();
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot.block_info, pool.block_info());
+        let sender = &snapshot.senders[&tx.sender()];
+        assert_eq!(sender.state_nonce, on_chain_nonce);
+        assert_eq!(sender.balance, on_chain_balance);
+
+        let [pending, queued] = sender.transactions.as_slice() else {
+            panic!("expected two transactions: {:?}", sender.transactions)
+        };
+        assert_eq!(pending.hash, *tx.hash());
+        assert_eq!(pending.subpool, SubPool::Pending);
+        assert!(pending.queued_reasons.is_empty());
+        assert_eq!(
+            pending.effective_tip_per_gas,
+            tx.effective_tip_per_gas(pool.block_info().pending_basefee)
+        );
+        assert_eq!(queued.hash, *gapped.hash());
+        assert_eq!(queued.nonce, 2);
+        assert_eq!(queued.subpool, SubPool::Queued);
+        assert!(queued.queued_reasons.contains(&QueuedReason::NonceGap));
+
+        let recovered: TransactionSignedEcRecovered = gapped.into_consensus().into();
+        assert_eq!(queued.raw, recovered.into_signed().encoded_2718());
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_string(&snapshot).unwrap();
+            assert_eq!(serde_json::from_str::<PoolSnapshot>(&json).unwrap(), snapshot);
+        }
+    }
+
     #[test]
     fn test_simple_insert() {
         let on_chain_balance = U256::ZERO;
@@ -2952,6 +3080,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn discard_keeps_pinned_transactions() {
+        let mut f = MockTransactionFactory::default();
+        let queued_limit = SubPoolLimit::new(0, usize::MAX);
+        let config = PoolConfig { queued_limit, max_pinned_transactions: 1, ..Default::default() };
+        let mut pool = TxPool::new(MockOrdering::default(), config);
+
+        let pinned = f.validated(MockTransaction::eip1559().inc_nonce());
+        let other = f.validated(MockTransaction::eip1559().inc_nonce());
+        let pinned_hash = *pinned.hash();
+        let other_hash = *other.hash();
+        pool.add_transaction(pinned, U256::from(1_000), 0).unwrap();
+        pool.add_transaction(other, U256::from(1_000), 0).unwrap();
+
+        assert!(!pool.pin_transaction(B256::random()));
+        assert!(pool.pin_transaction(pinned_hash));
+        // the maximum number of pinned transactions is reached
+        assert!(!pool.pin_transaction(other_hash));
+
+        // the pinned transaction is kept, even though the pool still exceeds its limit
+        let removed = pool.discard_worst();
+        assert_eq!(removed.len(), 1);
+        assert_eq!(*removed[0].hash(), other_hash);
+        assert!(pool.contains(&pinned_hash));
+        pool.assert_invariants();
+
+        pool.unpin_transaction(&pinned_hash);
+        let removed = pool.discard_worst();
+        assert_eq!(removed.len(), 1);
+        assert_eq!(*removed[0].hash(), pinned_hash);
+    }
+
     #[test]
     fn discard_blobs_at_capacity() {
         let mut f = MockTransactionFactory::default();
@@ -3197,6 +3357,91 @@ mod tests {
         assert!(pool.contains(v0.hash()));
         assert!(pool.contains(v1.hash()));
     }
+
+    #[test]
+    fn test_drain_stale() {
+        let on_chain_balance = U256::from(10_000);
+        let on_chain_nonce = 0;
+        let mut f = MockTransactionFactory::default();
+        let mut pool = TxPool::new(MockOrdering::default(), Default::default());
+
+        let tx_0 = MockTransaction::eip1559().set_gas_price(100).inc_limit();
+        let tx_1 = tx_0.next();
+        let tx_2 = tx_1.next();
+        let tx_3 = tx_2.next();
+        let other = MockTransaction::eip1559().set_gas_price(100).inc_limit();
+
+        let v0 = f.validated(tx_0);
+        let v2 = f.validated(tx_2);
+        let v3 = f.validated(tx_3);
+        let other = f.validated(other);
+
+        // the transactions after the missing nonce 1 are queued
+        let _res = pool.add_transaction(v0.clone(), on_chain_balance, on_chain_nonce).unwrap();
+        let _res = pool.add_transaction(v2.clone(), on_chain_balance, on_chain_nonce).unwrap();
+        let _res = pool.add_transaction(v3.clone(), on_chain_balance, on_chain_nonce).unwrap();
+        let _res = pool.add_transaction(other.clone(), on_chain_balance, on_chain_nonce).unwrap();
+        assert_eq!(2, pool.pending_transactions().len());
+        assert_eq!(2, pool.queued_transactions().len());
+
+        // the first two nonces of the sender were mined
+        let mut changed_senders = HashMap::default();
+        changed_senders
+            .insert(v0.sender_id(), SenderInfo { state_nonce: 2, balance: on_chain_balance });
+
+        let UpdateOutcome { promoted, discarded } = pool.drain_stale(changed_senders);
+        assert_eq!(discarded.len(), 1);
+        assert_eq!(discarded[0].hash(), v0.hash());
+        assert!(!pool.contains(v0.hash()));
+        assert!(pool.contains(v2.hash()));
+        assert!(pool.contains(other.hash()));
+
+        // the remaining transactions of the sender are executable now
+        let mut promoted: Vec<_> = promoted.iter().map(|tx| *tx.hash()).collect();
+        promoted.sort_unstable();
+        let mut expected = vec![*v2.hash(), *v3.hash()];
+        expected.sort_unstable();
+        assert_eq!(promoted, expected);
+        assert_eq!(3, pool.pending_transactions().len());
+        assert_eq!(0, pool.queued_transactions().len());
+        assert_eq!(pool.sender_info.get(&v0.sender_id()).unwrap().state_nonce, 2);
+    }
+
+    #[test]
+    fn pending_txs_by_sender_ordered() {
+        let on_chain_balance = U256::from(10_000);
+        let on_chain_nonce = 0;
+        let mut f = MockTransactionFactory::default();
+        let mut pool = TxPool::new(MockOrdering::default(), Default::default());
+
+        let tx_0 = MockTransaction::eip1559().set_gas_price(100).inc_limit();
+        let tx_1 = tx_0.next();
+        let tx_3 = tx_1.next().next();
+        let other = MockTransaction::eip1559().set_gas_price(100).inc_limit();
+
+        let v0 = f.validated(tx_0);
+        let v1 = f.validated(tx_1);
+        let v3 = f.validated(tx_3);
+        let other = f.validated(other);
+
+        // add out of order, the last transaction is nonce gapped
+        let _res = pool.add_transaction(v1.clone(), on_chain_balance, on_chain_nonce).unwrap();
+        let _res = pool.add_transaction(other, on_chain_balance, on_chain_nonce).unwrap();
+        let _res = pool.add_transaction(v3.clone(), on_chain_balance, on_chain_nonce).unwrap();
+        let _res = pool.add_transaction(v0.clone(), on_chain_balance, on_chain_nonce).unwrap();
+        assert_eq!(3, pool.pending_transactions().len());
+        assert_eq!(1, pool.queued_transactions().len());
+
+        let pending = pool.pending_txs_by_sender(v0.sender_id());
+        assert_eq!(
+            pending.iter().map(|tx| *tx.hash()).collect::<Vec<_>>(),
+            vec![*v0.hash(), *v1.hash()]
+        );
+
+        let queued = pool.queued_txs_by_sender(v0.sender_id());
+        assert_eq!(queued.iter().map(|tx| *tx.hash()).collect::<Vec<_>>(), vec![*v3.hash()]);
+    }
+
     #[test]
     fn wrong_best_order_of_transactions() {
         let on_chain_balance = U256::from(10_000);
@@ -3,7 +3,7 @@
 use crate::{
     blobstore::BlobStoreError,
     error::{InvalidPoolTransactionError, PoolResult},
-    pool::{state::SubPool, BestTransactionFilter, TransactionEvents},
+    pool::{state::SubPool, BatchImport, BestTransactionFilter, PoolSnapshot, TransactionEvents},
     validate::ValidPoolTransaction,
     AllTransactionsEvents,
 };
@@ -22,7 +22,8 @@ use reth_eth_wire_types::HandleMempoolData;
 use reth_execution_types::ChangedAccount;
 use reth_primitives::{
     kzg::KzgSettings, transaction::TryFromRecoveredTransactionError, PooledTransactionsElement,
-    PooledTransactionsElementEcRecovered, SealedBlock, Transaction, TransactionSignedEcRecovered,
+    PooledTransactionsElementEcRecovered, SealedBlock, Transaction, TransactionSigned,
+    TransactionSignedEcRecovered,
 };
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -115,6 +116,31 @@ pub trait TransactionPool: Send + Sync + Clone {
         transactions: Vec<Self::Transaction>,
     ) -> impl Future<Output = Vec<PoolResult<TxHash>>> + Send;
 
+    /// Imports a batch of signed transactions, e.g. to reload the pool from a persisted snapshot
+    /// after a restart.
+    ///
+    /// The senders of the transactions are recovered in parallel and the transactions of each
+    /// sender are imported in nonce order. Transactions with an invalid signature are rejected.
+    ///
+    /// Returns a list of results in the order of `transactions`.
+    ///
+    /// Consumer: Utility
+    fn batch_import(
+        &self,
+        origin: TransactionOrigin,
+        transactions: Vec<TransactionSigned>,
+    ) -> impl Future<Output = Vec<PoolResult<TxHash>>> + Send
+    where
+        Self::Transaction:
+            PoolTransaction<TryFromConsensusError: core::error::Error + Send + Sync + 'static>,
+    {
+        async move {
+            let (batch, transactions) = BatchImport::recover(transactions);
+            let imported = self.add_transactions(origin, transactions).await;
+            batch.into_results(imported)
+        }
+    }
+
     /// Returns a new transaction change event stream for the given transaction.
     ///
     /// Returns `None` if the transaction is not in the pool.
@@ -286,6 +312,14 @@ pub trait TransactionPool: Send + Sync + Clone {
     /// Consumer: RPC
     fn all_transactions(&self) -> AllPoolTransactions<Self::Transaction>;
 
+    /// Returns a serializable snapshot of all transactions in the pool, grouped by sender.
+    ///
+    /// The snapshot records the sub-pool of every transaction and why it isn't pending, which is
+    /// useful to debug ordering or eviction issues.
+    ///
+    /// Consumer: RPC
+    fn export_snapshot(&self) -> PoolSnapshot;
+
     /// Removes all transactions corresponding to the given hashes.
     ///
     /// Consumer: Utility
@@ -312,6 +346,36 @@ pub trait TransactionPool: Send + Sync + Clone {
         sender: Address,
     ) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>>;
 
+    /// Removes all transactions of the changed accounts that are invalidated by the account's new
+    /// nonce and returns the number of removed transactions.
+    ///
+    /// For every changed account this removes all transactions with a nonce lower than the
+    /// account's current nonce. The account's remaining transactions that became executable are
+    /// promoted to pending.
+    ///
+    /// Consumer: Utility
+    fn drain_stale(&self, state_changes: &[ChangedAccount]) -> usize;
+
+    /// Pins the transaction with the given hash, so that it is not evicted from the pool when the
+    /// pool exceeds its size limits.
+    ///
+    /// This also keeps the transactions of the same sender with lower nonces, because the pinned
+    /// transaction depends on them. A pinned transaction is still removed once it is mined or
+    /// replaced. At most
+    /// [`PoolConfig::max_pinned_transactions`](crate::PoolConfig::max_pinned_transactions)
+    /// transactions can be pinned.
+    ///
+    /// Returns `false` if the transaction is not in the pool or if the maximum number of pinned
+    /// transactions is reached.
+    ///
+    /// Consumer: Utility
+    fn pin_transaction(&self, hash: TxHash) -> bool;
+
+    /// Unpins the transaction with the given hash, see [`TransactionPool::pin_transaction`].
+    ///
+    /// Consumer: Utility
+    fn unpin_transaction(&self, hash: TxHash);
+
     /// Retains only those hashes that are unknown to the pool.
     /// In other words, removes all transactions from the given set that are currently present in
     /// the pool. Returns hashes already known to the pool.
@@ -357,6 +421,15 @@ pub trait TransactionPool: Send + Sync + Clone {
         sender: Address,
     ) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>>;
 
+    /// Returns an iterator over the pending transactions sent by a given user, ordered by nonce.
+    ///
+    /// Only includes transactions of the pending sub-pool, i.e. transactions that are ready to be
+    /// included in the next block, but not queued transactions.
+    fn pending_by_sender(
+        &self,
+        sender: Address,
+    ) -> impl Iterator<Item = Arc<ValidPoolTransaction<Self::Transaction>>>;
+
     /// Returns all queued transactions sent by a given user
     fn get_queued_transactions_by_sender(
         &self,
@@ -637,6 +710,7 @@ pub struct NewBlobSidecar {
 /// Depending on where the transaction was picked up, it affects how the transaction is handled
 /// internally, e.g. limits for simultaneous transaction of one sender.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TransactionOrigin {
     /// Transaction is coming from a local source.
     #[default]
@@ -1412,6 +1486,7 @@ impl PoolSize {
 
 /// Represents the current status of the pool.
 #[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BlockInfo {
     /// Hash for the currently tracked block.
     pub last_seen_block_hash: B256,
@@ -0,0 +1,33 @@
+use alloy_primitives::{PrimitiveSignature, U256};
+use reth_primitives::TransactionSigned;
+use reth_transaction_pool::{
+    test_utils::{TestPoolBuilder, TransactionGenerator},
+    TransactionOrigin, TransactionPool,
+};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn txpool_batch_import() {
+    let txpool = TestPoolBuilder::default();
+    let mut gen = TransactionGenerator::with_num_signers(rand::thread_rng(), 1);
+
+    // the transactions of the sender are not in nonce order
+    let mut transactions =
+        [2, 0, 1].map(|nonce| gen.transaction().nonce(nonce).into_eip1559()).to_vec();
+    // a transaction with a signature that doesn't recover to a sender
+    let invalid = TransactionSigned::from_transaction_and_signature(
+        gen.transaction().nonce(3).into_eip1559().transaction,
+        PrimitiveSignature::new(U256::ZERO, U256::ZERO, false),
+    );
+    transactions.push(invalid.clone());
+
+    let results = txpool.batch_import(TransactionOrigin::External, transactions.clone()).await;
+    assert_eq!(results.len(), transactions.len());
+    for (result, transaction) in results[..3].iter().zip(&transactions) {
+        assert_eq!(result.as_ref().unwrap(), &transaction.hash());
+    }
+    assert_eq!(results[3].as_ref().unwrap_err().hash, invalid.hash());
+
+    // the transactions are pending, regardless of the order in which they were imported
+    assert_eq!(txpool.pending_transactions().len(), 3);
+    assert!(txpool.queued_transactions().is_empty());
+}